@@ -1,26 +1,70 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use core::iter::IntoIterator;
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, rc::Rc, sync::Arc};
 
 use anyhow::anyhow;
 use binius_core::{
+	composition::SumComposition,
 	constraint_system::{
-		channel::{ChannelId, Flush, FlushDirection},
+		channel::{Boundary, ChannelId, Flush, FlushDirection},
 		ConstraintSystem,
 	},
 	oracle::{
-		ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet, OracleId,
-		ProjectionVariant, ShiftVariant,
+		ConstraintPredicate, ConstraintSetBuilder, Error as OracleError, MultilinearOracleSet,
+		MultilinearPolyOracle, OracleId, ProjectionVariant, ShiftVariant,
 	},
 	polynomial::MultivariatePoly,
 	witness::MultilinearExtensionIndex,
 };
-use binius_field::{as_packed_field::PackScalar, underlier::UnderlierType, TowerField};
-use binius_math::ArithExpr;
+use binius_field::{
+	as_packed_field::PackScalar,
+	packed::{get_packed_slice, set_packed_slice},
+	underlier::UnderlierType,
+	BinaryField1b, ExtensionField, TowerField,
+};
+use binius_macros::arith_expr;
+use binius_math::{ArithExpr, CompositionPoly};
 use binius_utils::bail;
+use bytemuck::Pod;
+
+use crate::{builder::witness, plain_lookup::plain_lookup, transparent};
+
+/// Structural size of a constraint system, as reported by [`ConstraintSystemBuilder::dry_run`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostEstimate {
+	/// Total number of bits in all committed oracles.
+	pub committed_bits: usize,
+	/// Maximum degree of the constraint compositions.
+	pub max_constraint_degree: usize,
+	/// Number of channel flushes.
+	pub n_flushes: usize,
+}
+
+/// Ids of a gadget embedded by [`ConstraintSystemBuilder::instantiate_gadget`], in the numbering
+/// of the builder.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GadgetInstance {
+	/// The copies of the gadget output oracles, in order.
+	pub outputs: Vec<OracleId>,
+	/// The copy of every gadget oracle, indexed by its id in the gadget.
+	pub oracles: Vec<OracleId>,
+	/// The channel of every gadget channel, indexed by its id in the gadget. This is empty when
+	/// the gadget has no flushes.
+	pub channels: Vec<ChannelId>,
+}
+
+/// Metadata key designating an oracle of a gadget as an input, see
+/// [`ConstraintSystemBuilder::instantiate_gadget`]. The value is the position among the inputs.
+pub const GADGET_INPUT_KEY: &str = "gadget_input";
+
+/// Metadata key designating an oracle of a gadget as an output, see
+/// [`ConstraintSystemBuilder::instantiate_gadget`]. The value is the position among the outputs.
+pub const GADGET_OUTPUT_KEY: &str = "gadget_output";
 
-use crate::builder::witness;
+/// Deferred computation of a witness column, run when the witness is taken.
+type ComputedColumn<'arena, U, F> =
+	Box<dyn FnOnce(&witness::Builder<'arena, U, F>) -> Result<(), anyhow::Error> + 'arena>;
 
 #[derive(Default)]
 pub struct ConstraintSystemBuilder<'arena, U, F>
@@ -33,6 +77,7 @@ where
 	non_zero_oracle_ids: Vec<OracleId>,
 	flushes: Vec<Flush>,
 	witness: Option<witness::Builder<'arena, U, F>>,
+	computed_columns: Vec<ComputedColumn<'arena, U, F>>,
 	next_channel_id: ChannelId,
 	namespace_path: Vec<String>,
 }
@@ -76,18 +121,71 @@ where
 		})
 	}
 
+	/// Validates the structure of the constraint system and estimates its size, without requiring
+	/// any witness data.
+	///
+	/// This checks that constraints, flushes and non-zero assertions only reference existing
+	/// oracles and that all flushes into the same channel have the same number of oracles.
+	pub fn dry_run(&self) -> Result<CostEstimate, anyhow::Error> {
+		let oracles = self.oracles.borrow();
+
+		let table_constraints = self.constraints.clone().build(&oracles)?;
+
+		for &oracle_id in self
+			.flushes
+			.iter()
+			.flat_map(|flush| &flush.oracles)
+			.chain(&self.non_zero_oracle_ids)
+		{
+			if !oracles.is_valid_oracle_id(oracle_id) {
+				bail!(OracleError::InvalidOracleId(oracle_id));
+			}
+		}
+
+		let mut channel_widths = HashMap::new();
+		for flush in &self.flushes {
+			let width = *channel_widths
+				.entry(flush.channel_id)
+				.or_insert(flush.oracles.len());
+			if width != flush.oracles.len() {
+				bail!(anyhow!(
+					"dry_run: flushes into channel {} have {} and {} oracles",
+					flush.channel_id,
+					width,
+					flush.oracles.len()
+				));
+			}
+		}
+
+		let max_constraint_degree = table_constraints
+			.iter()
+			.flat_map(|constraint_set| &constraint_set.constraints)
+			.map(|constraint| constraint.composition.degree())
+			.max()
+			.unwrap_or(0);
+
+		Ok(CostEstimate {
+			committed_bits: oracles.total_committed_bits(),
+			max_constraint_degree,
+			n_flushes: self.flushes.len(),
+		})
+	}
+
 	pub fn witness(&mut self) -> Option<&mut witness::Builder<'arena, U, F>> {
 		self.witness.as_mut()
 	}
 
+	/// Takes the witness, after filling the columns added with [`Self::add_computed`].
 	pub fn take_witness(
 		&mut self,
 	) -> Result<MultilinearExtensionIndex<'arena, U, F>, anyhow::Error> {
-		Option::take(&mut self.witness)
-			.ok_or_else(|| {
-				anyhow!("Witness is missing. Are you in verifier mode, or have you already extraced the witness?")
-			})?
-			.build()
+		let witness = Option::take(&mut self.witness).ok_or_else(|| {
+			anyhow!("Witness is missing. Are you in verifier mode, or have you already extraced the witness?")
+		})?;
+		for compute in self.computed_columns.drain(..) {
+			compute(&witness)?;
+		}
+		witness.build()
 	}
 
 	pub fn flush(
@@ -151,6 +249,36 @@ where
 			.add_zerocheck(name, oracle_ids, composition);
 	}
 
+	/// Asserts that two compositions of the same oracles agree on the hypercube.
+	///
+	/// This asserts that $a - b$ is zero, where the difference is the [`SumComposition`] of the
+	/// two sides since negation is the identity in characteristic 2.
+	pub fn assert_compositions_equal<FC, A, B>(
+		&mut self,
+		name: impl ToString,
+		oracle_ids: impl IntoIterator<Item = OracleId>,
+		a: A,
+		b: B,
+	) -> Result<(), anyhow::Error>
+	where
+		FC: TowerField,
+		F: ExtensionField<FC>,
+		A: CompositionPoly<FC>,
+		B: CompositionPoly<FC>,
+	{
+		let oracle_ids = oracle_ids.into_iter().collect::<Vec<_>>();
+		let difference = SumComposition::new(a, b)?;
+		if difference.n_vars() != oracle_ids.len() {
+			bail!(anyhow!(
+				"compositions have {} variables, but {} oracles were given",
+				difference.n_vars(),
+				oracle_ids.len()
+			));
+		}
+		self.assert_zero(name, oracle_ids, difference.expression());
+		Ok(())
+	}
+
 	pub fn assert_not_zero(&mut self, oracle_id: OracleId) {
 		self.non_zero_oracle_ids.push(oracle_id);
 	}
@@ -173,6 +301,105 @@ where
 			.committed(n_vars, tower_level)
 	}
 
+	/// Adds a committed oracle whose witness is computed from other columns.
+	///
+	/// When the witness is taken, `compute` is called with the hypercube values of the `deps`
+	/// columns, embedded into `F`, and must return the `2^n_vars` values of the new column. The
+	/// computations run in the order they were added, so a computed column may depend on columns
+	/// computed before it. In verifier mode `compute` is never called.
+	pub fn add_computed<FS>(
+		&mut self,
+		name: impl ToString,
+		n_vars: usize,
+		deps: &[OracleId],
+		compute: impl FnOnce(&[&[F]]) -> Vec<FS> + 'arena,
+	) -> OracleId
+	where
+		U: PackScalar<FS>,
+		FS: TowerField,
+		F: ExtensionField<FS>,
+	{
+		let id = self.add_committed(name, n_vars, FS::TOWER_LEVEL);
+		if self.witness.is_some() {
+			let deps = deps.to_vec();
+			self.computed_columns.push(Box::new(move |witness| {
+				let dep_values = deps
+					.iter()
+					.map(|&dep| witness.get_scalars(dep))
+					.collect::<Result<Vec<_>, _>>()?;
+				let dep_values = dep_values.iter().map(Vec::as_slice).collect::<Vec<_>>();
+				let values = compute(&dep_values);
+				if values.len() != 1 << n_vars {
+					bail!(anyhow!(
+						"computed column {id} has {} values, expected {}",
+						values.len(),
+						1 << n_vars
+					));
+				}
+
+				let mut column = witness.new_column::<FS>(id);
+				let packed = column.packed();
+				for (i, value) in values.into_iter().enumerate() {
+					set_packed_slice(packed, i, value);
+				}
+				Ok(())
+			}));
+		}
+		id
+	}
+
+	/// Adds a column holding the elementwise product of the `a` and `b` columns.
+	///
+	/// The product is a committed column computed with [`Self::add_computed`] and constrained to
+	/// equal `a * b` on every row. The oracle set has no product oracle to derive it from `a` and
+	/// `b` without a commitment, because the product of two multilinears is not multilinear. The
+	/// two columns must have the same number of rows and values in `FS`.
+	pub fn add_product<FS>(
+		&mut self,
+		name: impl ToString,
+		a: OracleId,
+		b: OracleId,
+	) -> Result<OracleId, anyhow::Error>
+	where
+		U: PackScalar<FS>,
+		FS: TowerField,
+		F: ExtensionField<FS>,
+	{
+		let name = name.to_string();
+		let log_rows = self.log_rows([a, b])?;
+		{
+			let oracles = self.oracles.borrow();
+			if let Some(id) = [a, b]
+				.into_iter()
+				.find(|&id| oracles.tower_level(id) > FS::TOWER_LEVEL)
+			{
+				bail!(anyhow!(
+					"add_product: column {id} has tower level {}, expected at most {}",
+					oracles.tower_level(id),
+					FS::TOWER_LEVEL
+				));
+			}
+		}
+
+		let product = self.add_computed::<FS>(name.clone(), log_rows, &[a, b], |columns| {
+			columns[0]
+				.iter()
+				.zip(columns[1])
+				.map(|(&a, &b)| {
+					TryInto::<FS>::try_into(a * b)
+						.ok()
+						.expect("the operands are in FS, so their product is")
+				})
+				.collect()
+		});
+		self.assert_zero(
+			name,
+			[a, b, product],
+			ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2),
+		);
+		Ok(product)
+	}
+
 	pub fn add_committed_multiple<const N: usize>(
 		&mut self,
 		name: impl ToString,
@@ -284,6 +511,275 @@ where
 			.zero_padded(id, n_vars)
 	}
 
+	/// Adds a copy of an existing oracle with the same kind and shape under a new name.
+	///
+	/// Derived oracles, such as shifted or linear combination oracles, reference the same inner
+	/// oracles as the source. This is convenient when building several gadgets of the same shape.
+	pub fn clone_oracle(
+		&mut self,
+		src: OracleId,
+		new_name: impl ToString,
+	) -> Result<OracleId, OracleError> {
+		self.oracles
+			.borrow_mut()
+			.add_named(self.scoped_name(new_name))
+			.cloned(src)
+	}
+
+	/// Tags an oracle with a key-value pair, such as `group: hash_round`, for downstream tooling.
+	///
+	/// The metadata is carried over to the built [`ConstraintSystem`] and can be read back with
+	/// [`MultilinearOracleSet::oracle_metadata`]. Setting a key again replaces its value.
+	pub fn set_oracle_metadata(
+		&mut self,
+		id: OracleId,
+		key: &str,
+		value: String,
+	) -> Result<(), OracleError> {
+		self.oracles
+			.borrow_mut()
+			.set_oracle_metadata(id, key, value)
+	}
+
+	/// Embeds a copy of a pre-built gadget constraint system and returns the ids of the copy.
+	///
+	/// The gadget designates its inputs and outputs by tagging oracles with the
+	/// [`GADGET_INPUT_KEY`] and [`GADGET_OUTPUT_KEY`] metadata, whose values are the positions
+	/// `0, 1, ...` of the oracles among the inputs or outputs. All oracles, constraints, non-zero
+	/// assertions and flushes of the gadget are copied with fresh oracle ids, and its channels are
+	/// mapped to fresh channels of this builder, so a gadget can be instantiated several times.
+	/// Each copied input oracle is constrained to equal the matching oracle of `inputs`. The ids
+	/// of the copied outputs, of all copied oracles and of the mapped channels are returned, so
+	/// that the caller can attach boundaries to the gadget channels.
+	///
+	/// A constraint system does not carry the procedure that generates its witness, so gadgets
+	/// can only be instantiated in a builder without a witness.
+	pub fn instantiate_gadget(
+		&mut self,
+		gadget: &ConstraintSystem<F>,
+		inputs: &[OracleId],
+	) -> Result<GadgetInstance, anyhow::Error> {
+		if self.witness.is_some() {
+			bail!(anyhow!("cannot fill the witness of a gadget instance"));
+		}
+		let gadget_inputs = designated_oracles(&gadget.oracles, GADGET_INPUT_KEY)?;
+		let gadget_outputs = designated_oracles(&gadget.oracles, GADGET_OUTPUT_KEY)?;
+		if inputs.len() != gadget_inputs.len() {
+			bail!(anyhow!(
+				"gadget has {} inputs, but {} were given",
+				gadget_inputs.len(),
+				inputs.len()
+			));
+		}
+		for (&input, &gadget_input) in inputs.iter().zip(&gadget_inputs) {
+			let n_vars = self.oracles.borrow().n_vars(input);
+			if n_vars != gadget.oracles.n_vars(gadget_input) {
+				bail!(anyhow!(
+					"input oracle {input} has {n_vars} variables, but the gadget input {} has {}",
+					gadget.oracles.label(gadget_input),
+					gadget.oracles.n_vars(gadget_input)
+				));
+			}
+		}
+
+		// Oracles only reference oracles with smaller ids, so they can be copied in order.
+		let mut id_map = Vec::with_capacity(gadget.oracles.size());
+		for oracle in gadget.oracles.iter() {
+			let name = oracle
+				.name()
+				.map_or_else(|| format!("gadget_oracle_{}", oracle.id()), str::to_owned);
+			let id = match oracle {
+				MultilinearPolyOracle::Transparent { inner, .. } => {
+					self.add_transparent(name, SharedTransparent(inner.poly().clone()))?
+				}
+				MultilinearPolyOracle::Committed {
+					n_vars,
+					tower_level,
+					..
+				} => self.add_committed(name, n_vars, tower_level),
+				MultilinearPolyOracle::Repeating {
+					inner, log_count, ..
+				} => self.add_repeating(name, id_map[inner.id()], log_count)?,
+				MultilinearPolyOracle::Projected { projected, .. } => self.add_projected(
+					name,
+					id_map[projected.inner().id()],
+					projected.values().clone(),
+					projected.projection_variant(),
+				)?,
+				MultilinearPolyOracle::Shifted { shifted, .. } => self.add_shifted(
+					name,
+					id_map[shifted.inner().id()],
+					shifted.shift_offset(),
+					shifted.block_size(),
+					shifted.shift_variant(),
+				)?,
+				MultilinearPolyOracle::Packed { packed, .. } => {
+					self.add_packed(name, id_map[packed.inner().id()], packed.log_degree())?
+				}
+				MultilinearPolyOracle::LinearCombination {
+					linear_combination, ..
+				} => self.add_linear_combination_with_offset(
+					name,
+					linear_combination.n_vars(),
+					linear_combination.offset(),
+					linear_combination
+						.polys()
+						.map(|poly| id_map[poly.id()])
+						.zip(linear_combination.coefficients())
+						.collect::<Vec<_>>(),
+				)?,
+				MultilinearPolyOracle::ZeroPadded { inner, n_vars, .. } => {
+					self.add_zero_padded(name, id_map[inner.id()], n_vars)?
+				}
+			};
+			id_map.push(id);
+		}
+
+		for constraint_set in &gadget.table_constraints {
+			let oracle_ids = constraint_set
+				.oracle_ids
+				.iter()
+				.map(|&id| id_map[id])
+				.collect::<Vec<_>>();
+			for constraint in &constraint_set.constraints {
+				match constraint.predicate {
+					ConstraintPredicate::Zero => self.assert_zero(
+						&*constraint.name,
+						oracle_ids.clone(),
+						constraint.composition.clone(),
+					),
+					ConstraintPredicate::Sum(sum) => self.constraints.add_sumcheck(
+						oracle_ids.clone(),
+						constraint.composition.clone(),
+						sum,
+					),
+				}
+			}
+		}
+
+		for &id in &gadget.non_zero_oracle_ids {
+			self.assert_not_zero(id_map[id]);
+		}
+
+		let mut channels = Vec::new();
+		if !gadget.flushes.is_empty() {
+			channels = (0..=gadget.max_channel_id)
+				.map(|_| self.add_channel())
+				.collect();
+			for flush in &gadget.flushes {
+				self.flushes.push(Flush {
+					oracles: flush.oracles.iter().map(|&id| id_map[id]).collect(),
+					channel_id: channels[flush.channel_id],
+					..flush.clone()
+				});
+			}
+		}
+
+		for (i, (&input, &gadget_input)) in inputs.iter().zip(&gadget_inputs).enumerate() {
+			self.assert_zero(
+				format!("gadget_input_{i}"),
+				[input, id_map[gadget_input]],
+				arith_expr!(F[input, copy] = input - copy),
+			);
+		}
+
+		Ok(GadgetInstance {
+			outputs: gadget_outputs.into_iter().map(|id| id_map[id]).collect(),
+			oracles: id_map,
+			channels,
+		})
+	}
+
+	/// Adds an accumulator column holding the running sum of the `input` column.
+	///
+	/// The accumulator is constrained to start at zero and to satisfy `acc[i + 1] = acc[i] +
+	/// input[i]`, so row `i` holds the sum of the first `i` input rows. The accumulator is filled
+	/// in when the builder has a witness.
+	pub fn add_running_sum<FS>(
+		&mut self,
+		name: impl ToString,
+		input: OracleId,
+	) -> Result<OracleId, anyhow::Error>
+	where
+		U: PackScalar<FS> + PackScalar<BinaryField1b> + Pod,
+		F: ExtensionField<FS>,
+		FS: TowerField,
+	{
+		self.push_namespace(name);
+		let log_rows = self.log_rows([input])?;
+		let acc = self.add_committed("acc", log_rows, FS::TOWER_LEVEL);
+		let acc_next =
+			self.add_shifted("acc_next", acc, 1, log_rows, ShiftVariant::LogicalRight)?;
+		let first = transparent::step_down(self, "first", log_rows, 1)?;
+		let enabled = transparent::step_down(self, "enabled", log_rows, (1 << log_rows) - 1)?;
+
+		if let Some(witness) = self.witness() {
+			let input = witness.get::<FS>(input)?.packed();
+			let mut acc = witness.new_column::<FS>(acc);
+			let mut acc_next = witness.new_column::<FS>(acc_next);
+			let acc = acc.packed();
+			let acc_next = acc_next.packed();
+
+			let mut sum = FS::ZERO;
+			for i in 0..1 << log_rows {
+				set_packed_slice(acc, i, sum);
+				if i > 0 {
+					set_packed_slice(acc_next, i - 1, sum);
+				}
+				sum += get_packed_slice(input, i);
+			}
+		}
+
+		self.assert_zero(
+			"step",
+			[acc_next, acc, input, enabled],
+			arith_expr!(F[acc_next, acc, input, enabled] = (acc_next - acc - input) * enabled),
+		);
+		self.assert_zero("starts_at_zero", [acc, first], arith_expr!(F[acc, first] = acc * first));
+
+		self.pop_namespace();
+		Ok(acc)
+	}
+
+	/// Asserts that every row of the `value` column appears in a fixed `table`.
+	///
+	/// The table is added as a transparent oracle and the lookup is enforced over a channel with
+	/// [`plain_lookup`], so table values must be distinct and each of them can be looked up less
+	/// than `1 << LOG_MAX_MULTIPLICITY` times. The returned boundary balances the channel and must
+	/// be included in the statement.
+	pub fn add_lookup<FS, const LOG_MAX_MULTIPLICITY: usize>(
+		&mut self,
+		name: impl ToString,
+		value: OracleId,
+		table: &[FS],
+	) -> Result<Boundary<F>, anyhow::Error>
+	where
+		U: PackScalar<FS> + PackScalar<BinaryField1b> + Pod,
+		F: ExtensionField<FS>,
+		FS: TowerField + Pod,
+	{
+		let Some(&balancer_value) = table.first() else {
+			bail!(anyhow!("add_lookup: the table must not be empty"));
+		};
+
+		self.push_namespace(name);
+		let log_rows = self.log_rows([value])?;
+		let mut table_values = table.to_vec();
+		table_values.resize(table.len().next_power_of_two(), FS::ZERO);
+		let table_id = transparent::make_transparent(self, "table", &table_values)?;
+		let boundary = plain_lookup::<U, F, FS, LOG_MAX_MULTIPLICITY>(
+			self,
+			table_id,
+			table.len(),
+			balancer_value,
+			value,
+			1 << log_rows,
+		)?;
+		self.pop_namespace();
+
+		Ok(boundary)
+	}
+
 	fn scoped_name(&self, name: impl ToString) -> String {
 		let name = name.to_string();
 		if self.namespace_path.is_empty() {
@@ -347,3 +843,50 @@ where
 		Ok(log_rows)
 	}
 }
+
+/// A transparent polynomial shared with the oracle set of another constraint system.
+#[derive(Debug)]
+struct SharedTransparent<F: TowerField>(Arc<dyn MultivariatePoly<F>>);
+
+impl<F: TowerField> MultivariatePoly<F> for SharedTransparent<F> {
+	fn n_vars(&self) -> usize {
+		self.0.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.0.degree()
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, binius_core::polynomial::Error> {
+		self.0.evaluate(query)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.0.binary_tower_level()
+	}
+}
+
+/// Returns the oracles tagged with `key`, ordered by the position in the tag value.
+fn designated_oracles<F: TowerField>(
+	oracles: &MultilinearOracleSet<F>,
+	key: &str,
+) -> Result<Vec<OracleId>, anyhow::Error> {
+	let mut designated = Vec::new();
+	for id in 0..oracles.size() {
+		if let Some(position) = oracles.oracle_metadata(id).get(key) {
+			let position = position
+				.parse::<usize>()
+				.map_err(|_| anyhow!("oracle {id} has invalid {key} position {position:?}"))?;
+			designated.push((position, id));
+		}
+	}
+	designated.sort_unstable();
+	if designated
+		.iter()
+		.enumerate()
+		.any(|(i, &(position, _))| position != i)
+	{
+		bail!(anyhow!("{key} positions must be 0, 1, ... without gaps or repetitions"));
+	}
+	Ok(designated.into_iter().map(|(_, id)| id).collect())
+}
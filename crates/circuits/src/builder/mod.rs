@@ -3,4 +3,6 @@
 pub mod constraint_system;
 pub mod witness;
 
-pub use constraint_system::ConstraintSystemBuilder;
+pub use constraint_system::{
+	ConstraintSystemBuilder, CostEstimate, GadgetInstance, GADGET_INPUT_KEY, GADGET_OUTPUT_KEY,
+};
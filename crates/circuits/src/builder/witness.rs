@@ -12,7 +12,7 @@ use binius_field::{
 	underlier::WithUnderlier,
 	ExtensionField, Field, PackedField, TowerField,
 };
-use binius_math::MultilinearExtension;
+use binius_math::{MultilinearExtension, MultilinearPoly};
 use binius_utils::bail;
 use bytemuck::{must_cast_slice, must_cast_slice_mut, Pod};
 
@@ -119,6 +119,28 @@ where
 		})
 	}
 
+	/// Returns the hypercube values of an already filled column, embedded into `FW`.
+	pub fn get_scalars(&self, id: OracleId) -> Result<Vec<FW>, Error> {
+		let entries = self.entries.borrow();
+		let oracles = self.oracles.borrow();
+		if !oracles.is_valid_oracle_id(id) {
+			bail!(anyhow!("OracleId {id} does not exist in MultilinearOracleSet"));
+		}
+		let entry = entries
+			.get(id)
+			.and_then(|entry| entry.as_ref())
+			.ok_or_else(|| anyhow!("Witness for {} is missing", oracles.label(id)))?;
+		let witness = entry
+			.witness
+			.as_ref()
+			.map_err(|err| anyhow!("Witness for {} is invalid: {err}", oracles.label(id)))?;
+
+		let values = (0..1 << witness.n_vars())
+			.map(|index| witness.evaluate_on_hypercube(index))
+			.collect::<Result<_, _>>()?;
+		Ok(values)
+	}
+
 	pub fn set<FS: TowerField>(
 		&self,
 		id: OracleId,
@@ -145,6 +167,44 @@ where
 		Ok(())
 	}
 
+	/// Fills the column `complement_id` with `1 - s`, where `s` is the already filled column
+	/// `selector_id`.
+	///
+	/// Both oracles must have the same number of variables.
+	pub fn fill_complement<FS: TowerField>(
+		&self,
+		complement_id: OracleId,
+		selector_id: OracleId,
+	) -> Result<(), Error>
+	where
+		U: PackScalar<FS>,
+		FW: ExtensionField<FS>,
+	{
+		let selector = self.get::<FS>(selector_id)?;
+		{
+			let oracles = self.oracles.borrow();
+			if !oracles.is_valid_oracle_id(complement_id) {
+				bail!(anyhow!("OracleId {complement_id} does not exist in MultilinearOracleSet"));
+			}
+			if oracles.n_vars(complement_id) != selector.log_rows {
+				bail!(anyhow!(
+					"{} has {} variables, but selector {} has {}",
+					oracles.label(complement_id),
+					oracles.n_vars(complement_id),
+					oracles.label(selector_id),
+					selector.log_rows
+				));
+			}
+		}
+
+		let mut complement = self.new_column::<FS>(complement_id);
+		let one = PackedType::<U, FS>::one();
+		for (dst, &src) in complement.packed().iter_mut().zip(selector.packed()) {
+			*dst = one - src;
+		}
+		Ok(())
+	}
+
 	pub fn build(self) -> Result<MultilinearExtensionIndex<'arena, U, FW>, Error> {
 		let mut result = MultilinearExtensionIndex::new();
 		let entries = Rc::into_inner(self.entries)
@@ -26,16 +26,17 @@ pub mod vision;
 
 #[cfg(test)]
 mod tests {
-	use std::array;
+	use std::{array, cell::RefCell, rc::Rc};
 
 	use binius_core::{
 		constraint_system::{
 			self,
-			channel::{Boundary, FlushDirection},
-			validate::validate_witness,
+			channel::{boundaries_from_endpoints, Boundary, FlushDirection},
+			validate::{validate_witness, validate_witness_par, ConstraintViolation},
 		},
 		fiat_shamir::HasherChallenger,
-		oracle::OracleId,
+		oracle::{OracleId, ShiftVariant},
+		polynomial::ArithCircuitPoly,
 		tower::CanonicalTowerFamily,
 	};
 	use binius_field::{
@@ -44,18 +45,20 @@ mod tests {
 		tower_levels::{TowerLevel1, TowerLevel16, TowerLevel2, TowerLevel4, TowerLevel8},
 		underlier::WithUnderlier,
 		AESTowerField16b, BinaryField128b, BinaryField1b, BinaryField32b, BinaryField64b,
-		BinaryField8b, Field, TowerField,
+		BinaryField8b, Field, PackedBits, TowerField,
 	};
 	use binius_hal::make_portable_backend;
 	use binius_hash::compress::Groestl256ByteCompression;
-	use binius_math::DefaultEvaluationDomainFactory;
+	use binius_math::{ArithExpr, DefaultEvaluationDomainFactory};
 	use groestl_crypto::Groestl256;
 	use rand::{rngs::StdRng, Rng, SeedableRng};
 	use sha2::{compress256, digest::generic_array::GenericArray};
 
 	use crate::{
 		arithmetic, bitwise,
-		builder::ConstraintSystemBuilder,
+		builder::{
+			witness, ConstraintSystemBuilder, CostEstimate, GADGET_INPUT_KEY, GADGET_OUTPUT_KEY,
+		},
 		groestl::groestl_p_permutation,
 		keccakf::{keccakf, KeccakfState},
 		lasso::{
@@ -71,6 +74,7 @@ mod tests {
 		},
 		plain_lookup,
 		sha256::sha256,
+		transparent,
 		u32fib::u32fib,
 		unconstrained::unconstrained,
 		vision::vision_permutation,
@@ -339,6 +343,571 @@ mod tests {
 		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
 	}
 
+	#[test]
+	fn test_dry_run() {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let log_size = 4;
+		let a = builder.add_committed("a", log_size, BinaryField1b::TOWER_LEVEL);
+		let b = builder.add_committed("b", log_size, BinaryField32b::TOWER_LEVEL);
+		builder.assert_zero("a_cubed_is_b", [a, b], ArithExpr::Var(0).pow(3) - ArithExpr::Var(1));
+		let channel = builder.add_channel();
+		builder.send(channel, 1 << log_size, [a, b]);
+		builder.receive(channel, 1 << log_size, [b, a]);
+
+		let estimate = builder.dry_run().unwrap();
+		assert_eq!(
+			estimate,
+			CostEstimate {
+				committed_bits: (1 << log_size) * (1 + 32),
+				max_constraint_degree: 3,
+				n_flushes: 2,
+			}
+		);
+
+		builder.send(channel, 1 << log_size, [a]);
+		assert!(builder.dry_run().is_err());
+
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let a = builder.add_committed("a", log_size, BinaryField1b::TOWER_LEVEL);
+		let channel = builder.add_channel();
+		builder.send(channel, 1 << log_size, [a, a + 1]);
+		assert!(builder.dry_run().is_err());
+	}
+
+	#[test]
+	fn test_oracle_metadata() {
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let a = builder.add_committed("a", 5, BinaryField1b::TOWER_LEVEL);
+		let b = builder.add_committed("b", 5, BinaryField1b::TOWER_LEVEL);
+		builder
+			.set_oracle_metadata(a, "group", "hash_round".to_string())
+			.unwrap();
+		builder
+			.set_oracle_metadata(a, "phase", "1".to_string())
+			.unwrap();
+		builder
+			.set_oracle_metadata(a, "phase", "2".to_string())
+			.unwrap();
+		assert!(builder
+			.set_oracle_metadata(b + 1, "phase", "2".to_string())
+			.is_err());
+
+		let constraint_system = builder.build().unwrap();
+		let metadata = constraint_system.oracles.oracle_metadata(a);
+		assert_eq!(metadata.len(), 2);
+		assert_eq!(metadata["group"], "hash_round");
+		assert_eq!(metadata["phase"], "2");
+		assert!(constraint_system.oracles.oracle_metadata(b).is_empty());
+	}
+
+	#[test]
+	fn test_instantiate_gadget() {
+		let log_size = 5;
+
+		let mut gadget_builder = ConstraintSystemBuilder::<U, F>::new();
+		let x = gadget_builder.add_committed("x", log_size, BinaryField1b::TOWER_LEVEL);
+		let y = gadget_builder.add_committed("y", log_size, BinaryField1b::TOWER_LEVEL);
+		let z =
+			arithmetic::u32::add(&mut gadget_builder, "add", x, y, arithmetic::Flags::Unchecked)
+				.unwrap();
+		gadget_builder
+			.set_oracle_metadata(x, GADGET_INPUT_KEY, "0".to_string())
+			.unwrap();
+		gadget_builder
+			.set_oracle_metadata(y, GADGET_INPUT_KEY, "1".to_string())
+			.unwrap();
+		gadget_builder
+			.set_oracle_metadata(z, GADGET_OUTPUT_KEY, "0".to_string())
+			.unwrap();
+		let gadget = gadget_builder.build().unwrap();
+		let n_gadget_constraints = gadget.constraints().count();
+
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let [a, b, c] = builder.add_committed_multiple("in", log_size, BinaryField1b::TOWER_LEVEL);
+		let sum_ab = builder
+			.instantiate_gadget(&gadget, &[a, b])
+			.unwrap()
+			.outputs;
+		assert_eq!(sum_ab.len(), 1);
+		let sum_abc = builder
+			.instantiate_gadget(&gadget, &[sum_ab[0], c])
+			.unwrap()
+			.outputs;
+		assert_eq!(sum_abc.len(), 1);
+		assert!(builder.instantiate_gadget(&gadget, &[a]).is_err());
+		builder.dry_run().unwrap();
+
+		let constraint_system = builder.build().unwrap();
+		assert_eq!(constraint_system.oracles.size(), 3 + 2 * gadget.oracles.size());
+		assert_eq!(constraint_system.constraints().count(), 2 * n_gadget_constraints + 4);
+		assert_eq!(sum_ab[0], 3 + z);
+		assert_eq!(sum_abc[0], 3 + gadget.oracles.size() + z);
+		assert_eq!(
+			constraint_system.oracles.oracle(sum_abc[0]).name(),
+			gadget.oracles.oracle(z).name()
+		);
+		assert_eq!(constraint_system.oracles.n_vars(sum_abc[0]), log_size);
+	}
+
+	#[test]
+	fn test_instantiate_gadget_validates() {
+		type FC = BinaryField32b;
+		let log_size = 4;
+
+		// A gadget adding two columns, which sends the first row of the sum over a channel.
+		let mut gadget_builder = ConstraintSystemBuilder::<U, F>::new();
+		let [x, y, z] = gadget_builder.add_committed_multiple("xyz", log_size, FC::TOWER_LEVEL);
+		gadget_builder.assert_zero(
+			"add",
+			[x, y, z],
+			binius_macros::arith_expr!([x, y, z] = x + y - z).convert_field(),
+		);
+		let channel = gadget_builder.add_channel();
+		gadget_builder.send(channel, 1, [z]);
+		for (key, id) in [
+			(GADGET_INPUT_KEY, x),
+			(GADGET_INPUT_KEY, y),
+			(GADGET_OUTPUT_KEY, z),
+		] {
+			let position = if id == y { "1" } else { "0" };
+			gadget_builder
+				.set_oracle_metadata(id, key, position.to_string())
+				.unwrap();
+		}
+		let gadget = gadget_builder.build().unwrap();
+
+		let allocator = bumpalo::Bump::new();
+		let mut witness_builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		assert!(witness_builder.instantiate_gadget(&gadget, &[]).is_err());
+
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let [a, b, c] = builder.add_committed_multiple("abc", log_size, FC::TOWER_LEVEL);
+		let first = builder.instantiate_gadget(&gadget, &[a, b]).unwrap();
+		let second = builder
+			.instantiate_gadget(&gadget, &[first.outputs[0], c])
+			.unwrap();
+		assert_eq!(first.channels.len(), 1);
+		assert_eq!(second.channels.len(), 1);
+		assert_ne!(first.channels[0], second.channels[0]);
+		let constraint_system = builder.build().unwrap();
+
+		let witness = witness::Builder::<U, F>::new(
+			&allocator,
+			Rc::new(RefCell::new(constraint_system.oracles.clone())),
+		);
+		let mut rng = StdRng::seed_from_u64(0);
+		let [a_values, b_values, c_values] = array::from_fn(|_| {
+			(0..1 << log_size)
+				.map(|_| FC::random(&mut rng))
+				.collect::<Vec<_>>()
+		});
+		let ab_values = a_values
+			.iter()
+			.zip(&b_values)
+			.map(|(&a, &b)| a + b)
+			.collect::<Vec<_>>();
+		let abc_values = ab_values
+			.iter()
+			.zip(&c_values)
+			.map(|(&ab, &c)| ab + c)
+			.collect::<Vec<_>>();
+		for (id, values) in [
+			(a, &a_values),
+			(b, &b_values),
+			(c, &c_values),
+			(first.oracles[x], &a_values),
+			(first.oracles[y], &b_values),
+			(first.oracles[z], &ab_values),
+			(second.oracles[x], &ab_values),
+			(second.oracles[y], &c_values),
+			(second.oracles[z], &abc_values),
+		] {
+			witness
+				.new_column::<FC>(id)
+				.as_mut_slice::<FC>()
+				.copy_from_slice(values);
+		}
+		let witness = witness.build().unwrap();
+
+		let boundary = |channel_id, value: FC| Boundary {
+			values: vec![value.into()],
+			channel_id,
+			direction: FlushDirection::Pull,
+			multiplicity: 1,
+		};
+		let mut boundaries = vec![
+			boundary(first.channels[0], ab_values[0]),
+			boundary(second.channels[0], abc_values[0]),
+		];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+
+		boundaries[1] = boundary(second.channels[0], ab_values[0]);
+		assert!(validate_witness(&constraint_system, &boundaries, &witness).is_err());
+	}
+
+	#[test]
+	fn test_running_sum() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 8;
+		let input = unconstrained::<_, _, BinaryField8b>(&mut builder, "input", log_size).unwrap();
+		let acc = builder
+			.add_running_sum::<BinaryField8b>("running_sum", input)
+			.unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let input_poly = witness.get_multilin_poly(input).unwrap();
+		let acc_poly = witness.get_multilin_poly(acc).unwrap();
+		let mut sum = F::ZERO;
+		for i in 0..1 << log_size {
+			assert_eq!(acc_poly.evaluate_on_hypercube(i).unwrap(), sum);
+			sum += input_poly.evaluate_on_hypercube(i).unwrap();
+		}
+
+		let constraint_system = builder.build().unwrap();
+		let boundaries = vec![];
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+	}
+
+	#[test]
+	fn test_plain_lookup_partial_table() {
+		// Only the first `table_count` rows of the table are valid, the remaining rows of the
+		// multiplicity components must still satisfy the lookup constraints.
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 8;
+		let table_count = 200;
+		let table_values = (0..1 << log_size)
+			.map(|i| BinaryField8b::new(i as u8))
+			.collect::<Vec<_>>();
+		let table = transparent::make_transparent(&mut builder, "table", &table_values).unwrap();
+
+		let lookup_values = builder.add_committed("lookup_values", log_size, 3);
+		if let Some(witness) = builder.witness() {
+			let mut lookup_values = witness.new_column::<BinaryField8b>(lookup_values);
+			for (i, value) in lookup_values.as_mut_slice::<u8>().iter_mut().enumerate() {
+				*value = (i * 7 % table_count) as u8;
+			}
+		}
+
+		let boundary = plain_lookup::plain_lookup::<U, F, BinaryField8b, 4>(
+			&mut builder,
+			table,
+			table_count,
+			table_values[1],
+			lookup_values,
+			1 << log_size,
+		)
+		.unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[boundary], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_add_lookup() {
+		let sbox = (0..100u8).map(|i| BinaryField8b::new(i.wrapping_mul(37) ^ 0x63));
+		let table = sbox.collect::<Vec<_>>();
+
+		for (values, is_valid) in [([3, 7, 7, 99], true), ([3, 7, 100, 99], false)] {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+			let log_size = 6;
+			let value = builder.add_committed("value", log_size, BinaryField8b::TOWER_LEVEL);
+			if let Some(witness) = builder.witness() {
+				let mut value = witness.new_column::<BinaryField8b>(value);
+				for (i, dst) in value.as_mut_slice::<BinaryField8b>().iter_mut().enumerate() {
+					let index = values[i % values.len()];
+					*dst = if index < table.len() {
+						table[index]
+					} else {
+						BinaryField8b::new(0xff)
+					};
+				}
+			}
+			let boundary = builder
+				.add_lookup::<BinaryField8b, 8>("sbox", value, &table)
+				.unwrap();
+
+			let witness = builder.take_witness().unwrap();
+			let constraint_system = builder.build().unwrap();
+			let result = validate_witness(&constraint_system, &[boundary], &witness);
+			assert_eq!(result.is_ok(), is_valid);
+		}
+
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let value = builder.add_committed("value", 6, BinaryField8b::TOWER_LEVEL);
+		assert!(builder
+			.add_lookup::<BinaryField8b, 8>("empty", value, &[])
+			.is_err());
+	}
+
+	#[test]
+	fn test_fill_complement() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 8;
+		let selector = builder.add_committed("selector", log_size, BinaryField1b::TOWER_LEVEL);
+		let complement = builder.add_committed("complement", log_size, BinaryField1b::TOWER_LEVEL);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let selector_bits = (0..1 << log_size)
+			.map(|_| rng.gen::<bool>())
+			.collect::<Vec<_>>();
+		if let Some(witness) = builder.witness() {
+			let mut column = witness.new_column::<BinaryField1b>(selector);
+			let width = PackedType::<U, BinaryField1b>::WIDTH;
+			for (dst, bits) in column.packed().iter_mut().zip(selector_bits.chunks(width)) {
+				*dst = PackedBits::from_bools(bits);
+			}
+		}
+
+		let witness = builder.witness().unwrap();
+		witness
+			.fill_complement::<BinaryField1b>(complement, selector)
+			.unwrap();
+		let complement_bits = witness
+			.get::<BinaryField1b>(complement)
+			.unwrap()
+			.packed()
+			.iter()
+			.flat_map(PackedBits::to_bools)
+			.collect::<Vec<_>>();
+		let expected_bits = selector_bits.iter().map(|&bit| !bit).collect::<Vec<_>>();
+		assert_eq!(complement_bits, expected_bits);
+		assert!(witness
+			.fill_complement::<BinaryField8b>(complement, selector)
+			.is_err());
+
+		builder.assert_zero(
+			"complement",
+			[selector, complement],
+			ArithExpr::Var(0) + ArithExpr::Var(1) - ArithExpr::one(),
+		);
+		builder.assert_zero(
+			"exclusive",
+			[selector, complement],
+			ArithExpr::Var(0) * ArithExpr::Var(1),
+		);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_add_computed() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 6;
+		let a = builder.add_committed("a", log_size, BinaryField8b::TOWER_LEVEL);
+		let b = builder.add_committed("b", log_size, BinaryField8b::TOWER_LEVEL);
+		let product = builder.add_computed("product", log_size, &[a, b], |columns| {
+			columns[0]
+				.iter()
+				.zip(columns[1])
+				.map(|(&a, &b)| BinaryField8b::try_from(a * b).unwrap())
+				.collect()
+		});
+		let sum = builder.add_computed("sum", log_size, &[product, a], |columns| {
+			columns[0]
+				.iter()
+				.zip(columns[1])
+				.map(|(&product, &a)| BinaryField8b::try_from(product + a).unwrap())
+				.collect()
+		});
+
+		// The dependencies are filled after the computed columns are declared.
+		let mut rng = StdRng::seed_from_u64(0);
+		if let Some(witness) = builder.witness() {
+			for id in [a, b] {
+				let mut column = witness.new_column::<BinaryField8b>(id);
+				for value in column.as_mut_slice::<BinaryField8b>() {
+					*value = BinaryField8b::new(rng.gen());
+				}
+			}
+		}
+
+		builder.assert_zero(
+			"product",
+			[a, b, product],
+			ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2),
+		);
+		builder.assert_zero(
+			"sum",
+			[product, a, sum],
+			ArithExpr::Var(0) + ArithExpr::Var(1) - ArithExpr::Var(2),
+		);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		// The computed column must have exactly 2^n_vars values.
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		builder.add_computed("short", log_size, &[], |_| vec![BinaryField8b::ONE]);
+		assert!(builder.take_witness().is_err());
+	}
+
+	#[test]
+	fn test_add_product() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 6;
+		let a = unconstrained::<_, _, BinaryField8b>(&mut builder, "a", log_size).unwrap();
+		let b = unconstrained::<_, _, BinaryField8b>(&mut builder, "b", log_size).unwrap();
+		let product = builder
+			.add_product::<BinaryField8b>("product", a, b)
+			.unwrap();
+
+		let witness = builder.take_witness().unwrap();
+		let a_poly = witness.get_multilin_poly(a).unwrap();
+		let b_poly = witness.get_multilin_poly(b).unwrap();
+		let product_poly = witness.get_multilin_poly(product).unwrap();
+		for i in 0..1 << log_size {
+			assert_eq!(
+				product_poly.evaluate_on_hypercube(i).unwrap(),
+				a_poly.evaluate_on_hypercube(i).unwrap() * b_poly.evaluate_on_hypercube(i).unwrap()
+			);
+		}
+
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		let mut builder = ConstraintSystemBuilder::<U, F>::new();
+		let a = builder.add_committed("a", log_size, BinaryField8b::TOWER_LEVEL);
+		let short = builder.add_committed("short", log_size - 1, BinaryField8b::TOWER_LEVEL);
+		let wide = builder.add_committed("wide", log_size, BinaryField32b::TOWER_LEVEL);
+		assert!(builder
+			.add_product::<BinaryField8b>("product", a, short)
+			.is_err());
+		assert!(builder
+			.add_product::<BinaryField8b>("product", a, wide)
+			.is_err());
+	}
+
+	#[test]
+	fn test_assert_compositions_equal() {
+		type FC = BinaryField8b;
+
+		let product = ArithCircuitPoly::<FC>::new(ArithExpr::Var(0) * ArithExpr::Var(1));
+		let output = ArithCircuitPoly::<FC>::with_n_vars(3, ArithExpr::Var(2)).unwrap();
+		let three_var_product =
+			ArithCircuitPoly::<FC>::with_n_vars(3, ArithExpr::Var(0) * ArithExpr::Var(1)).unwrap();
+		let three_var_sum =
+			ArithCircuitPoly::<FC>::with_n_vars(3, ArithExpr::Var(0) + ArithExpr::Var(1)).unwrap();
+
+		for (lhs, is_valid) in [(three_var_product, true), (three_var_sum, false)] {
+			let allocator = bumpalo::Bump::new();
+			let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+			let log_size = 6;
+			let [a, b, c] = builder.add_committed_multiple("abc", log_size, FC::TOWER_LEVEL);
+
+			let mut rng = StdRng::seed_from_u64(0);
+			if let Some(witness) = builder.witness() {
+				let mut a = witness.new_column::<FC>(a);
+				let mut b = witness.new_column::<FC>(b);
+				let mut c = witness.new_column::<FC>(c);
+				for ((a, b), c) in a
+					.as_mut_slice::<FC>()
+					.iter_mut()
+					.zip(b.as_mut_slice::<FC>())
+					.zip(c.as_mut_slice::<FC>())
+				{
+					*a = FC::new(rng.gen());
+					*b = FC::new(rng.gen());
+					*c = *a * *b;
+				}
+			}
+
+			builder
+				.assert_compositions_equal("product", [a, b, c], lhs, output.clone())
+				.unwrap();
+			assert!(builder
+				.assert_compositions_equal("mismatch", [a, b, c], product.clone(), output.clone())
+				.is_err());
+			assert!(builder
+				.assert_compositions_equal("too_few", [a, b], output.clone(), output.clone())
+				.is_err());
+
+			let witness = builder.take_witness().unwrap();
+			let constraint_system = builder.build().unwrap();
+			let result = validate_witness(&constraint_system, &[], &witness);
+			assert_eq!(result.is_ok(), is_valid);
+		}
+	}
+
+	#[test]
+	fn test_validate_witness_par() {
+		type FC = BinaryField8b;
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = 4;
+		let [a, b, c] = builder.add_committed_multiple("abc", log_size, FC::TOWER_LEVEL);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		if let Some(witness) = builder.witness() {
+			let mut a = witness.new_column::<FC>(a);
+			let mut b = witness.new_column::<FC>(b);
+			let mut c = witness.new_column::<FC>(c);
+			for (i, ((a, b), c)) in a
+				.as_mut_slice::<FC>()
+				.iter_mut()
+				.zip(b.as_mut_slice::<FC>())
+				.zip(c.as_mut_slice::<FC>())
+				.enumerate()
+			{
+				*a = FC::new(rng.gen());
+				*b = if i == 2 || i == 9 {
+					FC::new(rng.gen_range(1..=255))
+				} else {
+					FC::ZERO
+				};
+				*c = *a * *b;
+				if i == 3 || i == 5 {
+					*c += FC::ONE;
+				}
+			}
+		}
+
+		let [x0, x1, x2] = [0, 1, 2].map(ArithExpr::<F>::Var);
+		builder.assert_zero("product", [a, b, c], x0.clone() * x1.clone() - x2);
+		builder.assert_zero("double", [a, b, c], x0.clone() + x0);
+		builder.assert_zero("b_zero", [a, b, c], x1);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let Err(constraint_system::error::Error::ConstraintViolations { violations }) =
+			validate_witness_par(&constraint_system, &[], &witness)
+		else {
+			panic!("expected constraint violations");
+		};
+		let expected = [("product", 3), ("product", 5), ("b_zero", 2), ("b_zero", 9)].map(
+			|(name, vertex_index)| ConstraintViolation {
+				name: name.to_string(),
+				vertex_index,
+			},
+		);
+		assert_eq!(violations, expected);
+
+		let Err(constraint_system::error::Error::Constraint(
+			binius_core::protocols::sumcheck::Error::ZerocheckNaiveValidationFailure {
+				composition_name,
+				vertex_index,
+			},
+		)) = validate_witness(&constraint_system, &[], &witness)
+		else {
+			panic!("expected a zerocheck failure");
+		};
+		assert!(violations.contains(&ConstraintViolation {
+			name: composition_name,
+			vertex_index,
+		}));
+	}
+
 	#[test]
 	fn test_u32fib() {
 		let allocator = bumpalo::Bump::new();
@@ -624,6 +1193,161 @@ mod tests {
 		.unwrap();
 	}
 
+	#[test]
+	fn test_check_boundary() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+
+		let log_size = PackedType::<U, BinaryField8b>::LOG_WIDTH;
+		let channel_id = builder.add_channel();
+		let state = builder.add_committed("state", log_size, 3);
+		let next = builder.add_committed("next", log_size, 3);
+
+		// Counts down from 5 to 1, one step per row.
+		let count = 4;
+		if let Some(witness) = builder.witness() {
+			let mut state = witness.new_column::<BinaryField8b>(state);
+			let mut next = witness.new_column::<BinaryField8b>(next);
+			let state_u8 = state.as_mut_slice::<u8>();
+			let next_u8 = next.as_mut_slice::<u8>();
+			for i in 0..count {
+				state_u8[i] = 5 - i as u8;
+				next_u8[i] = 4 - i as u8;
+			}
+		}
+
+		builder.flush(FlushDirection::Pull, channel_id, count, [state]);
+		builder.flush(FlushDirection::Push, channel_id, count, [next]);
+
+		let witness = builder
+			.take_witness()
+			.expect("builder created with witness");
+		let constraint_system = builder.build().unwrap();
+
+		let boundary = |direction, value| Boundary {
+			values: vec![F::from_underlier(value)],
+			channel_id,
+			direction,
+			multiplicity: 1,
+		};
+
+		constraint_system
+			.check_boundary(&witness, &boundary(FlushDirection::Push, 5), &[state])
+			.unwrap();
+		constraint_system
+			.check_boundary(&witness, &boundary(FlushDirection::Pull, 1), &[next])
+			.unwrap();
+
+		// 4 is pulled, but not by the first row.
+		assert!(matches!(
+			constraint_system.check_boundary(
+				&witness,
+				&boundary(FlushDirection::Push, 4),
+				&[state]
+			),
+			Err(constraint_system::error::Error::BoundaryValuesNotFlushed { .. })
+		));
+		// 2 is pushed, but not by the last row.
+		assert!(matches!(
+			constraint_system.check_boundary(&witness, &boundary(FlushDirection::Pull, 2), &[next]),
+			Err(constraint_system::error::Error::BoundaryValuesNotFlushed { .. })
+		));
+		// `next` is never pulled.
+		assert!(matches!(
+			constraint_system.check_boundary(&witness, &boundary(FlushDirection::Push, 5), &[next]),
+			Err(constraint_system::error::Error::BoundaryFlushNotFound { .. })
+		));
+	}
+
+	#[test]
+	fn test_define_table() {
+		binius_macros::define_table! {
+			crate = crate;
+			struct Mul {
+				a: BinaryField32b[log_size],
+				b: BinaryField32b[log_size],
+				product: BinaryField32b[log_size],
+				flags: BinaryField1b[10],
+			}
+		}
+
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+		let log_size = PackedType::<U, BinaryField32b>::LOG_WIDTH + 2;
+		let table = Mul::new(&mut builder, log_size);
+		assert_eq!(builder.log_rows([table.a, table.b, table.product]).unwrap(), log_size);
+		assert_eq!(builder.log_rows([table.flags]).unwrap(), 10);
+
+		if let Some(witness) = builder.witness() {
+			let mut rng = StdRng::seed_from_u64(0);
+			let mut columns = table.columns(witness);
+			let a = columns.a.as_mut_slice::<BinaryField32b>();
+			let b = columns.b.as_mut_slice::<BinaryField32b>();
+			let product = columns.product.as_mut_slice::<BinaryField32b>();
+			for ((a, b), product) in a.iter_mut().zip(b.iter_mut()).zip(product) {
+				*a = BinaryField32b::new(rng.gen());
+				*b = BinaryField32b::new(rng.gen());
+				*product = *a * *b;
+			}
+		}
+		builder.assert_zero(
+			"mul",
+			[table.a, table.b, table.product],
+			binius_macros::arith_expr!([a, b, product] = a * b - product).convert_field(),
+		);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+		validate_witness(&constraint_system, &[], &witness).unwrap();
+	}
+
+	#[test]
+	fn test_boundaries_from_endpoints() {
+		let allocator = bumpalo::Bump::new();
+		let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+
+		let log_size = PackedType::<U, BinaryField8b>::LOG_WIDTH + 1;
+		let count = 1 << log_size;
+		let channel_id = builder.add_channel();
+
+		// A table that pulls each state and pushes the next one.
+		let state = builder.add_committed("state", log_size, BinaryField8b::TOWER_LEVEL);
+		let next = builder
+			.add_shifted("next", state, 1, log_size, ShiftVariant::LogicalRight)
+			.unwrap();
+		if let Some(witness) = builder.witness() {
+			let mut state = witness.new_column::<BinaryField8b>(state);
+			let state = state.as_mut_slice::<u8>();
+			for (i, state) in state.iter_mut().enumerate() {
+				*state = (i as u8).wrapping_mul(3).wrapping_add(1);
+			}
+			let mut next = witness.new_column::<BinaryField8b>(next);
+			next.as_mut_slice::<u8>()[..count - 1].copy_from_slice(&state[1..]);
+		}
+		builder.flush(FlushDirection::Pull, channel_id, count - 1, [state]);
+		builder.flush(FlushDirection::Push, channel_id, count - 1, [next]);
+
+		let witness = builder.take_witness().unwrap();
+		let constraint_system = builder.build().unwrap();
+
+		let boundaries = boundaries_from_endpoints(&witness, channel_id, &[state], count).unwrap();
+		let expected = [
+			(FlushDirection::Push, 1),
+			(FlushDirection::Pull, ((count - 1) as u8).wrapping_mul(3).wrapping_add(1)),
+		];
+		assert_eq!(boundaries.len(), expected.len());
+		for (boundary, (direction, value)) in boundaries.iter().zip(expected) {
+			assert_eq!(boundary.values, vec![F::from_underlier(value as u128)]);
+			assert_eq!(boundary.channel_id, channel_id);
+			assert_eq!(boundary.direction, direction);
+			assert_eq!(boundary.multiplicity, 1);
+		}
+		validate_witness(&constraint_system, &boundaries, &witness).unwrap();
+
+		assert!(boundaries_from_endpoints(&witness, channel_id, &[state], 0).is_err());
+		assert!(boundaries_from_endpoints(&witness, channel_id, &[state], count + 1).is_err());
+	}
+
 	#[test]
 	fn test_plain_u8_mul_lookup() {
 		const MAX_LOG_MULTIPLICITY: usize = 18;
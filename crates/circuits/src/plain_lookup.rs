@@ -188,6 +188,13 @@ where
 				}
 			},
 		);
+
+		// rows past `table_count` have all bits unset, so their components hold the balancer
+		for i in table_count..1 << n_vars {
+			for packed_component_col in packed_component_cols.iter_mut() {
+				set_packed_slice(packed_component_col, i, balancer_value);
+			}
+		}
 	}
 
 	let expression = {
@@ -187,6 +187,38 @@ fn benchmark_evaluate(c: &mut Criterion) {
 		});
 	});
 	group.finish();
+
+	// Evaluates independent queries, as opposed to the columns passed to `batch_evaluate`
+	let queries1x128b = (0..BATCH_SIZE)
+		.map(|i| {
+			query1x128b
+				.iter()
+				.map(|column| column[i])
+				.collect::<Vec<_>>()
+		})
+		.collect::<Vec<_>>();
+	let queries1x128b = queries1x128b
+		.iter()
+		.map(|query| query.as_slice())
+		.collect::<Vec<_>>();
+
+	let mut group = c.benchmark_group("evaluate_batch");
+	group.throughput(Throughput::Elements(BATCH_SIZE as _));
+	group.bench_function("serial_1x128b", |bench| {
+		bench.iter(|| {
+			for (query, result) in queries1x128b.iter().zip(results1x128b.iter_mut()) {
+				*result = arith_circuit_poly.evaluate(query).unwrap();
+			}
+		});
+	});
+	group.bench_function("parallel_1x128b", |bench| {
+		bench.iter(|| {
+			arith_circuit_poly
+				.evaluate_batch(&queries1x128b, &mut results1x128b)
+				.unwrap();
+		});
+	});
+	group.finish();
 }
 
 criterion_main!(composition_poly);
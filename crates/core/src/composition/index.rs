@@ -57,11 +57,16 @@ impl<P: PackedField, C: CompositionPolyOS<P>, const N: usize> CompositionPolyOS<
 					Box::new(map_variables(index_map, a)),
 					Box::new(map_variables(index_map, b)),
 				),
+				ArithExpr::Sub(a, b) => ArithExpr::Sub(
+					Box::new(map_variables(index_map, a)),
+					Box::new(map_variables(index_map, b)),
+				),
 				ArithExpr::Mul(a, b) => ArithExpr::Mul(
 					Box::new(map_variables(index_map, a)),
 					Box::new(map_variables(index_map, b)),
 				),
 				ArithExpr::Pow(a, n) => ArithExpr::Pow(Box::new(map_variables(index_map, a)), *n),
+				ArithExpr::Inv(a) => ArithExpr::Inv(Box::new(map_variables(index_map, a))),
 			}
 		}
 
@@ -160,13 +165,8 @@ mod tests {
 
 		assert_eq!(
 			(&composition as &dyn CompositionPolyOS<BinaryField1b>).expression(),
-			ArithExpr::Add(
-				Box::new(ArithExpr::Var(1)),
-				Box::new(ArithExpr::Mul(
-					Box::new(ArithExpr::Var(2)),
-					Box::new(ArithExpr::Const(BinaryField1b::ONE)),
-				)),
-			)
+			// The multiplication by one is folded away by `ArithCircuitPoly::new`
+			ArithExpr::Add(Box::new(ArithExpr::Var(1)), Box::new(ArithExpr::Var(2)))
 		);
 	}
 }
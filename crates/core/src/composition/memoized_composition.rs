@@ -0,0 +1,130 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Mutex,
+};
+
+use binius_field::{ExtensionField, PackedField, TowerField};
+use binius_math::{ArithExpr, CompositionPolyOS};
+
+use crate::polynomial::ArithCircuitPoly;
+
+/// An [`ArithCircuitPoly`] that remembers the result of its last evaluation.
+///
+/// Evaluating at the same query twice in a row returns the cached result without running the
+/// circuit again. Only a single `query -> result` pair is kept, so the memory overhead is one copy
+/// of the query, but calls alternating between two queries never hit the cache. Each lookup
+/// compares the whole query and takes a lock, which costs more than it saves unless the circuit is
+/// large or identical queries are common, as is the case in some verifier code.
+///
+/// Batch evaluation is not memoized.
+#[derive(Debug)]
+pub struct MemoizedComposition<F: TowerField, P: PackedField> {
+	inner: ArithCircuitPoly<F>,
+	last: Mutex<Option<(Vec<P>, P)>>,
+	n_evaluations: AtomicUsize,
+}
+
+impl<F: TowerField, P: PackedField> MemoizedComposition<F, P> {
+	pub fn new(inner: ArithCircuitPoly<F>) -> Self {
+		Self {
+			inner,
+			last: Mutex::new(None),
+			n_evaluations: AtomicUsize::new(0),
+		}
+	}
+
+	pub fn inner(&self) -> &ArithCircuitPoly<F> {
+		&self.inner
+	}
+
+	/// The number of times the wrapped circuit has been evaluated, that is, the number of misses.
+	pub fn n_evaluations(&self) -> usize {
+		self.n_evaluations.load(Ordering::Relaxed)
+	}
+}
+
+impl<F, P> CompositionPolyOS<P> for MemoizedComposition<F, P>
+where
+	F: TowerField,
+	P: PackedField<Scalar: ExtensionField<F>>,
+{
+	fn n_vars(&self) -> usize {
+		CompositionPolyOS::<P>::n_vars(&self.inner)
+	}
+
+	fn degree(&self) -> usize {
+		CompositionPolyOS::<P>::degree(&self.inner)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		CompositionPolyOS::<P>::binary_tower_level(&self.inner)
+	}
+
+	fn expression(&self) -> ArithExpr<P::Scalar> {
+		CompositionPolyOS::<P>::expression(&self.inner)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		let mut last = self.last.lock().expect("mutex is not poisoned");
+		if let Some((last_query, result)) = last.as_ref() {
+			if last_query.as_slice() == query {
+				return Ok(*result);
+			}
+		}
+
+		let result = CompositionPolyOS::evaluate(&self.inner, query)?;
+		self.n_evaluations.fetch_add(1, Ordering::Relaxed);
+		*last = Some((query.to_vec(), result));
+		Ok(result)
+	}
+
+	fn batch_evaluate(
+		&self,
+		batch_query: &[&[P]],
+		evals: &mut [P],
+	) -> Result<(), binius_math::Error> {
+		CompositionPolyOS::batch_evaluate(&self.inner, batch_query, evals)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField16b, BinaryField8b, PackedBinaryField8x16b};
+	use binius_utils::felts;
+
+	use super::*;
+
+	#[test]
+	fn test_repeated_query_hits_cache() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0^2 * x1 + x1
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(2) * ArithExpr::Var(1) + ArithExpr::Var(1),
+		);
+		let memoized = MemoizedComposition::<F, P>::new(circuit.clone());
+
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+			P::from_scalars(felts!(BinaryField16b[100, 101, 102, 103, 104, 105, 106, 107])),
+		];
+		let other_query = [query[1], query[0]];
+		let expected = CompositionPolyOS::evaluate(&circuit, &query).unwrap();
+
+		assert_eq!(memoized.evaluate(&query).unwrap(), expected);
+		assert_eq!(memoized.n_evaluations(), 1);
+		assert_eq!(memoized.evaluate(&query).unwrap(), expected);
+		assert_eq!(memoized.n_evaluations(), 1);
+
+		assert_eq!(
+			memoized.evaluate(&other_query).unwrap(),
+			CompositionPolyOS::evaluate(&circuit, &other_query).unwrap()
+		);
+		assert_eq!(memoized.n_evaluations(), 2);
+		assert_eq!(memoized.evaluate(&query).unwrap(), expected);
+		assert_eq!(memoized.n_evaluations(), 3);
+	}
+}
@@ -3,7 +3,19 @@
 //! Commonly used composition polynomials.
 
 pub mod index;
+pub mod memoized_composition;
+pub mod mul_composition;
 pub mod product_composition;
+pub mod registry;
+pub mod scaled_composition;
+pub mod sum_composition;
+pub mod table_composition;
 
 pub use index::*;
+pub use memoized_composition::*;
+pub use mul_composition::*;
 pub use product_composition::*;
+pub use registry::*;
+pub use scaled_composition::*;
+pub use sum_composition::*;
+pub use table_composition::*;
@@ -0,0 +1,192 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::{cmp::max, marker::PhantomData};
+
+use binius_field::{ExtensionField, Field, PackedField};
+use binius_math::{ArithExpr, CompositionPoly, CompositionPolyOS};
+use binius_utils::bail;
+
+use crate::polynomial::Error;
+
+/// The product $a(X) \cdot b(X)$ of two compositions over the same variables.
+///
+/// Unlike [`ProductComposition`](super::ProductComposition), which multiplies the variables
+/// themselves, this multiplies the values of two arbitrary compositions.
+#[derive(Debug, Clone)]
+pub struct MulComposition<F: Field, A: CompositionPoly<F>, B: CompositionPoly<F>> {
+	a: A,
+	b: B,
+	_marker: PhantomData<F>,
+}
+
+impl<F: Field, A: CompositionPoly<F>, B: CompositionPoly<F>> MulComposition<F, A, B> {
+	pub fn new(a: A, b: B) -> Result<Self, Error> {
+		if a.n_vars() != b.n_vars() {
+			bail!(Error::IncorrectNumberOfVariables {
+				expected: a.n_vars(),
+				actual: b.n_vars(),
+			});
+		}
+
+		Ok(Self {
+			a,
+			b,
+			_marker: PhantomData,
+		})
+	}
+}
+
+impl<F: Field, A: CompositionPoly<F>, B: CompositionPoly<F>> CompositionPoly<F>
+	for MulComposition<F, A, B>
+{
+	fn n_vars(&self) -> usize {
+		self.a.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.a.degree().saturating_add(self.b.degree())
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		max(self.a.binary_tower_level(), self.b.binary_tower_level())
+	}
+
+	fn expression<FE: ExtensionField<F>>(&self) -> ArithExpr<FE> {
+		self.a.expression() * self.b.expression()
+	}
+
+	fn evaluate<P: PackedField<Scalar: ExtensionField<F>>>(
+		&self,
+		query: &[P],
+	) -> Result<P, binius_math::Error> {
+		Ok(self.a.evaluate(query)? * self.b.evaluate(query)?)
+	}
+
+	fn batch_evaluate<P: PackedField<Scalar: ExtensionField<F>>>(
+		&self,
+		batch_query: &[&[P]],
+		evals: &mut [P],
+	) -> Result<(), binius_math::Error> {
+		let mut b_evals = vec![P::zero(); evals.len()];
+		self.a.batch_evaluate(batch_query, evals)?;
+		self.b.batch_evaluate(batch_query, &mut b_evals)?;
+		for (eval, b_eval) in evals.iter_mut().zip(b_evals) {
+			*eval *= b_eval;
+		}
+		Ok(())
+	}
+}
+
+impl<F, A, B, P> CompositionPolyOS<P> for MulComposition<F, A, B>
+where
+	F: Field,
+	A: CompositionPoly<F>,
+	B: CompositionPoly<F>,
+	P: PackedField<Scalar: ExtensionField<F>>,
+{
+	fn n_vars(&self) -> usize {
+		CompositionPoly::n_vars(self)
+	}
+
+	fn degree(&self) -> usize {
+		CompositionPoly::degree(self)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		CompositionPoly::binary_tower_level(self)
+	}
+
+	fn expression(&self) -> ArithExpr<P::Scalar> {
+		CompositionPoly::expression(self)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		CompositionPoly::evaluate(self, query)
+	}
+
+	fn batch_evaluate(
+		&self,
+		batch_query: &[&[P]],
+		evals: &mut [P],
+	) -> Result<(), binius_math::Error> {
+		CompositionPoly::batch_evaluate(self, batch_query, evals)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField16b, BinaryField8b, PackedBinaryField8x16b};
+	use binius_utils::felts;
+
+	use super::*;
+	use crate::polynomial::ArithCircuitPoly;
+
+	#[test]
+	fn test_mul_lowers_to_single_circuit() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0^2 * x1
+		let a = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).pow(2) * ArithExpr::Var(1));
+		// x1 + 123
+		let b = ArithCircuitPoly::<F>::with_n_vars(
+			2,
+			ArithExpr::Var(1) + ArithExpr::Const(F::new(123)),
+		)
+		.unwrap();
+
+		let product = MulComposition::new(a, b).unwrap();
+		let lowered = ArithCircuitPoly::<F>::with_n_vars(
+			CompositionPoly::n_vars(&product),
+			CompositionPoly::expression(&product),
+		)
+		.unwrap();
+
+		assert_eq!(CompositionPoly::n_vars(&lowered), 2);
+		assert_eq!(CompositionPoly::degree(&lowered), 4);
+
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+			P::from_scalars(felts!(BinaryField16b[100, 101, 102, 103, 104, 105, 106, 107])),
+		];
+		assert_eq!(
+			CompositionPoly::evaluate(&lowered, &query).unwrap(),
+			CompositionPoly::evaluate(&product, &query).unwrap()
+		);
+
+		let batch_query = query.map(|q| vec![q; 2]);
+		let batch_query = batch_query.each_ref().map(|q| q.as_slice());
+		let mut evals = [P::zero(); 2];
+		CompositionPoly::batch_evaluate(&product, &batch_query, &mut evals).unwrap();
+		assert_eq!(evals, [CompositionPoly::evaluate(&lowered, &query).unwrap(); 2]);
+	}
+
+	#[test]
+	fn test_mul_by_inverse_keeps_degree_sentinel() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0 * x1
+		let a = ArithCircuitPoly::<F>::new(ArithExpr::Var(0) * ArithExpr::Var(1));
+		// 1 / x0, which has no degree
+		let b = ArithCircuitPoly::<F>::with_n_vars(2, ArithExpr::Var(0).inv()).unwrap();
+		assert_eq!(CompositionPoly::degree(&b), usize::MAX);
+
+		let product = MulComposition::new(a, b).unwrap();
+		assert_eq!(CompositionPoly::degree(&product), usize::MAX);
+
+		let inverse_squared = MulComposition::new(
+			ArithCircuitPoly::<F>::new(ArithExpr::Var(0).inv()),
+			ArithCircuitPoly::<F>::new(ArithExpr::Var(0).inv()),
+		)
+		.unwrap();
+		assert_eq!(CompositionPoly::degree(&inverse_squared), usize::MAX);
+
+		// x0 * x1 / x0 = x1 wherever x0 is non-zero
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[1, 2, 3, 4, 5, 6, 7, 8])),
+			P::from_scalars(felts!(BinaryField16b[100, 101, 102, 103, 104, 105, 106, 107])),
+		];
+		assert_eq!(CompositionPoly::evaluate(&product, &query).unwrap(), query[1]);
+	}
+}
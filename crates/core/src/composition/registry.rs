@@ -0,0 +1,206 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::{
+	any::TypeId,
+	collections::{hash_map::Entry, HashMap},
+};
+
+use binius_field::TowerField;
+use binius_math::CompositionPoly;
+use binius_utils::{
+	bail,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
+use bytes::{Buf, BufMut};
+
+use crate::polynomial::{ArithCircuitPoly, Error};
+
+/// Tag under which [`ArithCircuitPoly`] is registered in every [`CompositionRegistry`].
+pub const ARITH_CIRCUIT_TAG: u32 = 0;
+
+/// Registry of the composition types that can be serialized.
+///
+/// Each registered type is identified by a tag. A composition is serialized as its tag followed by
+/// its [`ArithCircuitPoly`] form, so any composition that lowers to a circuit can be stored.
+///
+/// Deserialization always yields an [`ArithCircuitPoly`], whatever type was written. The tag is
+/// informational only: it is checked against the registry and returned to the caller, but it does
+/// not select the type that is reconstructed. Streams carrying an unregistered tag are rejected.
+#[derive(Debug, Clone)]
+pub struct CompositionRegistry {
+	tags: HashMap<TypeId, u32>,
+	types: HashMap<u32, TypeId>,
+}
+
+impl CompositionRegistry {
+	/// Creates a registry containing only [`ArithCircuitPoly`], under [`ARITH_CIRCUIT_TAG`].
+	pub fn new<F: TowerField>() -> Self {
+		let mut registry = Self {
+			tags: HashMap::new(),
+			types: HashMap::new(),
+		};
+		registry
+			.register::<ArithCircuitPoly<F>>(ARITH_CIRCUIT_TAG)
+			.expect("the registry is empty");
+		registry
+	}
+
+	/// Registers the composition type `C` under `tag`.
+	///
+	/// Each type and each tag can be registered only once.
+	pub fn register<C: 'static>(&mut self, tag: u32) -> Result<(), Error> {
+		let type_entry = match self.tags.entry(TypeId::of::<C>()) {
+			Entry::Occupied(entry) => bail!(Error::DuplicateCompositionType { tag: *entry.get() }),
+			Entry::Vacant(entry) => entry,
+		};
+		let Entry::Vacant(tag_entry) = self.types.entry(tag) else {
+			bail!(Error::DuplicateCompositionTag { tag });
+		};
+		type_entry.insert(tag);
+		tag_entry.insert(TypeId::of::<C>());
+		Ok(())
+	}
+
+	/// Writes the tag of `C` followed by the circuit form of `composition`.
+	pub fn serialize<F, C>(&self, composition: &C, mut write_buf: impl BufMut) -> Result<(), Error>
+	where
+		F: TowerField,
+		C: CompositionPoly<F> + 'static,
+	{
+		let tag = *self
+			.tags
+			.get(&TypeId::of::<C>())
+			.ok_or(Error::UnregisteredComposition)?;
+		let circuit =
+			ArithCircuitPoly::<F>::with_n_vars(composition.n_vars(), composition.expression())?;
+
+		if write_buf.remaining_mut() < size_of::<u32>() {
+			bail!(SerializationError::WriteBufferFull);
+		}
+		write_buf.put_u32_le(tag);
+		circuit.serialize(write_buf)?;
+		Ok(())
+	}
+
+	/// Reads a composition written by [`Self::serialize`], returning its tag and circuit form.
+	pub fn deserialize<F: TowerField>(
+		&self,
+		mut read_buf: impl Buf,
+	) -> Result<(u32, ArithCircuitPoly<F>), Error> {
+		if read_buf.remaining() < size_of::<u32>() {
+			bail!(SerializationError::NotEnoughBytes);
+		}
+		let tag = read_buf.get_u32_le();
+		if !self.types.contains_key(&tag) {
+			bail!(Error::UnknownCompositionTag { tag });
+		}
+		Ok((tag, ArithCircuitPoly::deserialize(read_buf)?))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::iter::repeat_with;
+
+	use binius_field::{BinaryField8b, Field};
+	use binius_math::{ArithExpr, CompositionPoly};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::composition::{ProductComposition, SumComposition};
+
+	type F = BinaryField8b;
+
+	#[test]
+	fn test_register_rejects_duplicates() {
+		type Sum = SumComposition<F, ArithCircuitPoly<F>, ArithCircuitPoly<F>>;
+
+		let mut registry = CompositionRegistry::new::<F>();
+		registry.register::<Sum>(1).unwrap();
+
+		assert!(matches!(
+			registry.register::<ProductComposition<2>>(1),
+			Err(Error::DuplicateCompositionTag { tag: 1 })
+		));
+		assert!(matches!(
+			registry.register::<Sum>(2),
+			Err(Error::DuplicateCompositionType { tag: 1 })
+		));
+		assert!(matches!(
+			registry.register::<ArithCircuitPoly<F>>(2),
+			Err(Error::DuplicateCompositionType {
+				tag: ARITH_CIRCUIT_TAG
+			})
+		));
+
+		// The failed registrations leave no stale entries behind.
+		assert!(matches!(
+			registry.deserialize::<F>(&2u32.to_le_bytes()[..]),
+			Err(Error::UnknownCompositionTag { tag: 2 })
+		));
+		let sum = Sum::new(
+			ArithCircuitPoly::new(ArithExpr::Var(0)),
+			ArithCircuitPoly::new(ArithExpr::Var(0).pow(2)),
+		)
+		.unwrap();
+		let mut bytes = Vec::new();
+		registry.serialize(&sum, &mut bytes).unwrap();
+		assert_eq!(bytes[..4], 1u32.to_le_bytes());
+	}
+
+	#[test]
+	fn test_serialize_sum_composition() {
+		type Sum = SumComposition<F, ArithCircuitPoly<F>, ArithCircuitPoly<F>>;
+		const SUM_TAG: u32 = 1;
+
+		let mut registry = CompositionRegistry::new::<F>();
+		registry.register::<Sum>(SUM_TAG).unwrap();
+
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(2) * ArithExpr::Var(1) + ArithExpr::Const(F::new(7)),
+		);
+		let sum = Sum::new(
+			ArithCircuitPoly::new(ArithExpr::Var(0) * ArithExpr::Var(1)),
+			ArithCircuitPoly::with_n_vars(2, ArithExpr::Var(1).pow(3)).unwrap(),
+		)
+		.unwrap();
+
+		let mut bytes = Vec::new();
+		registry.serialize(&circuit, &mut bytes).unwrap();
+		registry.serialize(&sum, &mut bytes).unwrap();
+
+		let mut read_buf = bytes.as_slice();
+		let (tag, read_circuit) = registry.deserialize::<F>(&mut read_buf).unwrap();
+		assert_eq!(tag, ARITH_CIRCUIT_TAG);
+		let (tag, read_sum) = registry.deserialize::<F>(&mut read_buf).unwrap();
+		assert_eq!(tag, SUM_TAG);
+		assert!(read_buf.is_empty());
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let query = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(2)
+			.collect::<Vec<_>>();
+		assert_eq!(
+			CompositionPoly::evaluate(&read_circuit, &query).unwrap(),
+			CompositionPoly::evaluate(&circuit, &query).unwrap()
+		);
+		assert_eq!(
+			CompositionPoly::evaluate(&read_sum, &query).unwrap(),
+			CompositionPoly::evaluate(&sum, &query).unwrap()
+		);
+
+		// Unregistered types can't be written, and unknown tags can't be read.
+		let other_registry = CompositionRegistry::new::<F>();
+		assert!(matches!(
+			other_registry.serialize(&sum, Vec::new()),
+			Err(Error::UnregisteredComposition)
+		));
+		assert!(matches!(other_registry.deserialize::<F>(&bytes[..]), Ok((ARITH_CIRCUIT_TAG, _))));
+		let mut sum_bytes = Vec::new();
+		registry.serialize(&sum, &mut sum_bytes).unwrap();
+		assert!(matches!(
+			other_registry.deserialize::<F>(&sum_bytes[..]),
+			Err(Error::UnknownCompositionTag { tag: SUM_TAG })
+		));
+	}
+}
@@ -0,0 +1,130 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::cmp::max;
+
+use binius_field::{ExtensionField, PackedField, TowerField};
+use binius_math::{ArithExpr, CompositionPoly, CompositionPolyOS};
+
+/// The composition $c \cdot a(X)$, scaling another composition by a constant.
+#[derive(Debug, Clone)]
+pub struct ScaledComposition<F: TowerField, C: CompositionPoly<F>> {
+	inner: C,
+	scalar: F,
+}
+
+impl<F: TowerField, C: CompositionPoly<F>> ScaledComposition<F, C> {
+	pub const fn new(inner: C, scalar: F) -> Self {
+		Self { inner, scalar }
+	}
+}
+
+impl<F: TowerField, C: CompositionPoly<F>> CompositionPoly<F> for ScaledComposition<F, C> {
+	fn n_vars(&self) -> usize {
+		self.inner.n_vars()
+	}
+
+	fn degree(&self) -> usize {
+		self.inner.degree()
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		max(self.inner.binary_tower_level(), self.scalar.min_tower_level())
+	}
+
+	fn expression<FE: ExtensionField<F>>(&self) -> ArithExpr<FE> {
+		self.inner.expression() * ArithExpr::Const(self.scalar.into())
+	}
+
+	fn evaluate<P: PackedField<Scalar: ExtensionField<F>>>(
+		&self,
+		query: &[P],
+	) -> Result<P, binius_math::Error> {
+		Ok(self.inner.evaluate(query)? * P::Scalar::from(self.scalar))
+	}
+
+	fn batch_evaluate<P: PackedField<Scalar: ExtensionField<F>>>(
+		&self,
+		batch_query: &[&[P]],
+		evals: &mut [P],
+	) -> Result<(), binius_math::Error> {
+		self.inner.batch_evaluate(batch_query, evals)?;
+		let scalar = P::Scalar::from(self.scalar);
+		for eval in evals.iter_mut() {
+			*eval *= scalar;
+		}
+		Ok(())
+	}
+}
+
+impl<F, C, P> CompositionPolyOS<P> for ScaledComposition<F, C>
+where
+	F: TowerField,
+	C: CompositionPoly<F>,
+	P: PackedField<Scalar: ExtensionField<F>>,
+{
+	fn n_vars(&self) -> usize {
+		CompositionPoly::n_vars(self)
+	}
+
+	fn degree(&self) -> usize {
+		CompositionPoly::degree(self)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		CompositionPoly::binary_tower_level(self)
+	}
+
+	fn expression(&self) -> ArithExpr<P::Scalar> {
+		CompositionPoly::expression(self)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		CompositionPoly::evaluate(self, query)
+	}
+
+	fn batch_evaluate(
+		&self,
+		batch_query: &[&[P]],
+		evals: &mut [P],
+	) -> Result<(), binius_math::Error> {
+		CompositionPoly::batch_evaluate(self, batch_query, evals)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField16b, BinaryField8b, PackedBinaryField8x16b};
+	use binius_utils::felts;
+
+	use super::*;
+	use crate::{composition::SumComposition, polynomial::ArithCircuitPoly};
+
+	#[test]
+	fn test_scaled_sum_lowers_to_single_circuit() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// 7 * (x0^2 * x1 + x1)
+		let a = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).pow(2) * ArithExpr::Var(1));
+		let b = ArithCircuitPoly::<F>::with_n_vars(2, ArithExpr::Var(1)).unwrap();
+		let scaled = ScaledComposition::new(SumComposition::new(a, b).unwrap(), F::new(7));
+		let lowered = ArithCircuitPoly::<F>::with_n_vars(
+			CompositionPoly::n_vars(&scaled),
+			CompositionPoly::expression(&scaled),
+		)
+		.unwrap();
+
+		assert_eq!(CompositionPoly::n_vars(&lowered), 2);
+		assert_eq!(CompositionPoly::degree(&lowered), 3);
+		assert_eq!(CompositionPoly::binary_tower_level(&scaled), 3);
+
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+			P::from_scalars(felts!(BinaryField16b[100, 101, 102, 103, 104, 105, 106, 107])),
+		];
+		assert_eq!(
+			CompositionPoly::evaluate(&lowered, &query).unwrap(),
+			CompositionPoly::evaluate(&scaled, &query).unwrap()
+		);
+	}
+}
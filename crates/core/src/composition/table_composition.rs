@@ -0,0 +1,117 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use binius_field::{PackedField, TowerField};
+use binius_math::{
+	ArithExpr, CompositionPolyOS, DefaultEvaluationDomainFactory, EvaluationDomainFactory,
+	InterpolationDomain,
+};
+use binius_utils::bail;
+
+use crate::polynomial::Error;
+
+/// A univariate composition that looks up its variable in a constant table.
+///
+/// The table entry `table[i]` is the value at the `i`-th element of the default binary subspace,
+/// which in the canonical tower is the field element with underlying value `i`. Between those
+/// points the composition is the unique polynomial of degree `table.len() - 1` interpolating the
+/// table, so it is only meaningful as a lookup when the variable ranges over the table points.
+#[derive(Debug, Clone)]
+pub struct TableComposition<P: PackedField> {
+	table: Vec<P::Scalar>,
+	/// Coefficients of the interpolating polynomial, in increasing degree order.
+	coeffs: Vec<P::Scalar>,
+}
+
+impl<P: PackedField<Scalar: TowerField>> TableComposition<P> {
+	pub fn new(table: Vec<P::Scalar>) -> Result<Self, Error> {
+		if !table.len().is_power_of_two() {
+			bail!(Error::TableLengthNotPowerOfTwo { len: table.len() });
+		}
+
+		let domain = DefaultEvaluationDomainFactory::<P::Scalar>::default().create(table.len())?;
+		let coeffs = InterpolationDomain::from(domain).interpolate(&table)?;
+		Ok(Self { table, coeffs })
+	}
+
+	pub fn table(&self) -> &[P::Scalar] {
+		&self.table
+	}
+}
+
+impl<P: PackedField<Scalar: TowerField>> CompositionPolyOS<P> for TableComposition<P> {
+	fn n_vars(&self) -> usize {
+		1
+	}
+
+	fn degree(&self) -> usize {
+		self.table.len() - 1
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		self.coeffs
+			.iter()
+			.map(|coeff| coeff.min_tower_level())
+			.max()
+			.unwrap_or(0)
+	}
+
+	fn expression(&self) -> ArithExpr<P::Scalar> {
+		self.coeffs
+			.iter()
+			.enumerate()
+			.map(|(i, &coeff)| ArithExpr::Const(coeff) * ArithExpr::Var(0).pow(i as u64))
+			.sum()
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, binius_math::Error> {
+		if query.len() != 1 {
+			bail!(binius_math::Error::IncorrectQuerySize { expected: 1 });
+		}
+
+		// Horner's method
+		Ok(self
+			.coeffs
+			.iter()
+			.rev()
+			.fold(P::zero(), |acc, &coeff| acc * query[0] + coeff))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{BinaryField8b, PackedBinaryField16x8b};
+
+	use super::*;
+	use crate::polynomial::ArithCircuitPoly;
+
+	type F = BinaryField8b;
+	type P = PackedBinaryField16x8b;
+
+	#[test]
+	fn test_interpolates_table_points() {
+		let table = [3, 1, 4, 1, 5, 9, 2, 6].map(F::new).to_vec();
+		let composition = TableComposition::<P>::new(table.clone()).unwrap();
+		assert_eq!(CompositionPolyOS::<P>::degree(&composition), 7);
+
+		let query = P::from_scalars((0..8).map(F::new));
+		let evals = composition.evaluate(&[query]).unwrap();
+		for (i, &entry) in table.iter().enumerate() {
+			assert_eq!(evals.get(i), entry);
+		}
+
+		let circuit = ArithCircuitPoly::new(CompositionPolyOS::<P>::expression(&composition));
+		let point = P::from_scalars((0..16).map(|i| F::new(i * 17)));
+		assert_eq!(
+			CompositionPolyOS::evaluate(&circuit, &[point]).unwrap(),
+			composition.evaluate(&[point]).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_table_length_not_power_of_two() {
+		assert!(matches!(
+			TableComposition::<P>::new(vec![F::new(1); 3]),
+			Err(Error::TableLengthNotPowerOfTwo { len: 3 })
+		));
+	}
+}
@@ -75,12 +75,30 @@ pub struct Boundary<F: TowerField> {
 	pub multiplicity: u64,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum FlushDirection {
 	Push,
 	Pull,
 }
 
+impl FlushDirection {
+	/// The sign of the contribution of a flush in this direction to the channel balance.
+	pub fn sign(&self) -> i64 {
+		match self {
+			FlushDirection::Push => 1,
+			FlushDirection::Pull => -1,
+		}
+	}
+
+	/// Returns the opposite direction.
+	pub fn flip(&self) -> Self {
+		match self {
+			FlushDirection::Push => FlushDirection::Pull,
+			FlushDirection::Pull => FlushDirection::Push,
+		}
+	}
+}
+
 pub fn validate_witness<U, F>(
 	witness: &MultilinearExtensionIndex<U, F>,
 	flushes: &[Flush],
@@ -172,6 +190,58 @@ where
 	Ok(())
 }
 
+/// Builds the boundaries that balance a sequence of states flushed through a channel.
+///
+/// The rows `0..count` of `oracle_ids` hold a sequence of states, and the tables of the
+/// constraint system pull every state but the last one and push every state but the first one,
+/// for example a table that pulls its current state and pushes the next. The channel is then
+/// balanced by a [`FlushDirection::Push`] boundary with the first row, which supplies the initial
+/// state, and a [`FlushDirection::Pull`] boundary with the last row, which consumes the final
+/// state. The boundaries are returned in that order, both with multiplicity one.
+pub fn boundaries_from_endpoints<U, F>(
+	witness: &MultilinearExtensionIndex<U, F>,
+	channel_id: ChannelId,
+	oracle_ids: &[OracleId],
+	count: usize,
+) -> Result<Vec<Boundary<F>>, Error>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	let first_id = *oracle_ids.first().ok_or(Error::EmptyFlushOracles)?;
+	let polys = oracle_ids
+		.iter()
+		.map(|&id| witness.get_multilin_poly(id))
+		.collect::<Result<Vec<_>, _>>()?;
+	if count == 0 || polys.iter().any(|poly| count > 1 << poly.n_vars()) {
+		return Err(Error::FlushCountExceedsOracleSize {
+			id: first_id,
+			count,
+		});
+	}
+
+	let row = |i| {
+		polys
+			.iter()
+			.map(|poly| poly.evaluate_on_hypercube(i))
+			.collect::<Result<Vec<_>, _>>()
+	};
+	Ok(vec![
+		Boundary {
+			values: row(0)?,
+			channel_id,
+			direction: FlushDirection::Push,
+			multiplicity: 1,
+		},
+		Boundary {
+			values: row(count - 1)?,
+			channel_id,
+			direction: FlushDirection::Pull,
+			multiplicity: 1,
+		},
+	])
+}
+
 #[derive(Default, Debug, Clone)]
 struct Channel<F: TowerField> {
 	width: Option<usize>,
@@ -205,11 +275,7 @@ impl<F: TowerField> Channel<F> {
 				got: values.len(),
 			});
 		}
-		*self.multiplicities.entry(values).or_default() += (multiplicity as i64)
-			* (match direction {
-				FlushDirection::Pull => -1i64,
-				FlushDirection::Push => 1i64,
-			});
+		*self.multiplicities.entry(values).or_default() += (multiplicity as i64) * direction.sign();
 		Ok(())
 	}
 
@@ -217,3 +283,19 @@ impl<F: TowerField> Channel<F> {
 		self.multiplicities.iter().all(|(_, m)| *m == 0)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn test_flush_direction_sign_and_flip() {
+		let push = FlushDirection::Push;
+		let pull = FlushDirection::Pull;
+
+		assert_eq!(push.flip(), pull);
+		assert_eq!(pull.flip(), push);
+		assert_eq!(push.sign(), -pull.sign());
+		assert_eq!(push.sign(), 1);
+	}
+}
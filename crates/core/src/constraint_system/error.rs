@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use super::channel::ChannelId;
+use super::{channel::ChannelId, validate};
 use crate::{
 	oracle,
 	oracle::OracleId,
@@ -37,9 +37,20 @@ pub enum Error {
 		witness_num_vars: usize,
 	},
 
+	#[error("no flush into channel {channel_id} from the given oracles can balance the boundary")]
+	BoundaryFlushNotFound { channel_id: ChannelId },
+
+	#[error("the boundary values do not match the boundary row flushed into channel {channel_id}")]
+	BoundaryValuesNotFlushed { channel_id: ChannelId },
+
 	#[error("cannot flush {count} rows of oracle {id}")]
 	FlushCountExceedsOracleSize { id: OracleId, count: usize },
 
+	#[error("{} zero constraint violations, the first being {:?}", violations.len(), violations.first())]
+	ConstraintViolations {
+		violations: Vec<validate::ConstraintViolation>,
+	},
+
 	#[error("Non-zero oracles contain zeros")]
 	Zeros,
 
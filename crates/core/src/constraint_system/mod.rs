@@ -7,12 +7,29 @@ mod prove;
 pub mod validate;
 mod verify;
 
-use binius_field::TowerField;
-use channel::{ChannelId, Flush};
+use binius_field::{
+	as_packed_field::{PackScalar, PackedType},
+	underlier::{UnderlierType, WithUnderlier},
+	ExtensionField, PackedField, TowerField,
+};
+use binius_hal::ComputationBackendExt;
+use binius_math::{ArithExpr, MultilinearExtension, MultilinearPoly};
+use binius_utils::bail;
+use channel::{Boundary, ChannelId, Flush, FlushDirection};
+use error::Error;
 pub use prove::prove;
+use rand::RngCore;
 pub use verify::verify;
 
-use crate::oracle::{ConstraintSet, MultilinearOracleSet, OracleId};
+use crate::{
+	oracle::{
+		Constraint, ConstraintSet, MultilinearOracleSet, MultilinearPolyOracle, OracleId,
+		ProjectionVariant, ShiftVariant,
+	},
+	polynomial::test_utils::decompose_index_to_hypercube_point,
+	tower::{TowerFamily, TowerUnderlier},
+	witness::{witness_from_underliers, MultilinearExtensionIndex, MultilinearWitness},
+};
 
 /// Contains the 3 things that place constraints on witness data in Binius
 /// - virtual oracles
@@ -31,6 +48,26 @@ pub struct ConstraintSystem<F: TowerField> {
 }
 
 impl<F: TowerField> ConstraintSystem<F> {
+	/// Iterates over the constraints of all the constraint sets.
+	pub fn constraints(&self) -> impl Iterator<Item = ConstraintRef<'_, F>> {
+		self.table_constraints.iter().flat_map(|constraint_set| {
+			constraint_set
+				.constraints
+				.iter()
+				.map(move |constraint| ConstraintRef {
+					constraint_set,
+					constraint,
+				})
+		})
+	}
+
+	/// Returns the total number of bits in the committed oracles.
+	///
+	/// See [`MultilinearOracleSet::total_committed_bits`].
+	pub fn total_committed_bits(&self) -> usize {
+		self.oracles.total_committed_bits()
+	}
+
 	pub fn no_base_constraints(self) -> ConstraintSystem<F> {
 		ConstraintSystem {
 			oracles: self.oracles,
@@ -40,6 +77,292 @@ impl<F: TowerField> ConstraintSystem<F> {
 			max_channel_id: self.max_channel_id,
 		}
 	}
+
+	/// Checks that a boundary is matched by a row flushed from the given oracles.
+	///
+	/// The boundary must be matched by a flush of `oracle_ids` into the boundary's channel in the
+	/// opposite direction. A pushed boundary is the state pulled by the first flushed row, and a
+	/// pulled boundary is the state pushed by the last flushed row. This gives a targeted error for
+	/// a mismatch between the statement and the witness, before the full channel balancing check.
+	pub fn check_boundary<U>(
+		&self,
+		witness: &MultilinearExtensionIndex<U, F>,
+		boundary: &Boundary<F>,
+		oracle_ids: &[OracleId],
+	) -> Result<(), Error>
+	where
+		U: UnderlierType + PackScalar<F>,
+	{
+		if boundary.values.len() != oracle_ids.len() {
+			bail!(Error::ChannelFlushWidthMismatch {
+				expected: oracle_ids.len(),
+				got: boundary.values.len(),
+			});
+		}
+
+		let rows = self
+			.flushes
+			.iter()
+			.filter(|flush| {
+				flush.channel_id == boundary.channel_id
+					&& flush.oracles == oracle_ids
+					&& flush.direction == boundary.direction.flip()
+					&& flush.count > 0
+			})
+			.map(|flush| match boundary.direction {
+				FlushDirection::Push => 0,
+				FlushDirection::Pull => flush.count - 1,
+			})
+			.collect::<Vec<_>>();
+		if rows.is_empty() {
+			bail!(Error::BoundaryFlushNotFound {
+				channel_id: boundary.channel_id,
+			});
+		}
+
+		let polys = oracle_ids
+			.iter()
+			.map(|&id| witness.get_multilin_poly(id))
+			.collect::<Result<Vec<_>, _>>()?;
+		for i in rows {
+			let row = polys
+				.iter()
+				.map(|poly| poly.evaluate_on_hypercube(i))
+				.collect::<Result<Vec<_>, _>>()?;
+			if row == boundary.values {
+				return Ok(());
+			}
+		}
+
+		Err(Error::BoundaryValuesNotFlushed {
+			channel_id: boundary.channel_id,
+		})
+	}
+
+	/// Generates a random witness that is consistent with the oracle definitions.
+	///
+	/// Committed oracles are filled with random values at their tower level, and every virtual
+	/// oracle (shifted, linear combination, packed, etc.) is derived from the oracles it is defined
+	/// over. The witness therefore satisfies the structural relations checked by
+	/// [`validate::validate_virtual_oracle_witness`], but in general it does not satisfy the
+	/// zero-constraints, non-zero oracles or channel flushes of the constraint system.
+	pub fn random_witness<'a, U, Tower>(
+		&self,
+		mut rng: impl RngCore,
+	) -> Result<MultilinearExtensionIndex<'a, U, F>, Error>
+	where
+		Tower: TowerFamily<B128 = F>,
+		U: TowerUnderlier<Tower>,
+		F: ExtensionField<Tower::B1>
+			+ ExtensionField<Tower::B8>
+			+ ExtensionField<Tower::B16>
+			+ ExtensionField<Tower::B32>
+			+ ExtensionField<Tower::B64>,
+	{
+		let mut witness = MultilinearExtensionIndex::new();
+		// Oracles may only refer to oracles that were added before them, so iterating in id
+		// order visits the inner oracles of a virtual oracle first.
+		for oracle in self.oracles.iter() {
+			let n_vars = oracle.n_vars();
+			let poly = match &oracle {
+				MultilinearPolyOracle::Committed { tower_level, .. } => match tower_level {
+					0 => random_multilin_poly::<U, Tower::B1, F>(n_vars, &mut rng)?,
+					3 => random_multilin_poly::<U, Tower::B8, F>(n_vars, &mut rng)?,
+					4 => random_multilin_poly::<U, Tower::B16, F>(n_vars, &mut rng)?,
+					5 => random_multilin_poly::<U, Tower::B32, F>(n_vars, &mut rng)?,
+					6 => random_multilin_poly::<U, Tower::B64, F>(n_vars, &mut rng)?,
+					7 => random_multilin_poly::<U, Tower::B128, F>(n_vars, &mut rng)?,
+					_ => bail!(Error::CannotCommitTowerLevel {
+						tower_level: *tower_level,
+					}),
+				},
+				MultilinearPolyOracle::Packed { packed, .. } => {
+					// A packed oracle shares the underlying data of its inner oracle.
+					let inner = witness.get_multilin_poly(packed.inner().id())?;
+					let underliers = inner
+						.packed_evals()
+						.ok_or(crate::witness::Error::NoExplicitBackingMultilinearExtension {
+							id: packed.inner().id(),
+						})?
+						.iter()
+						.map(|&packed| PackedType::<U, F>::to_underlier(packed))
+						.collect();
+					match F::TOWER_LEVEL - inner.log_extension_degree() + packed.log_degree() {
+						0 => witness_from_underliers::<U, Tower::B1, F>(n_vars, underliers)?,
+						3 => witness_from_underliers::<U, Tower::B8, F>(n_vars, underliers)?,
+						4 => witness_from_underliers::<U, Tower::B16, F>(n_vars, underliers)?,
+						5 => witness_from_underliers::<U, Tower::B32, F>(n_vars, underliers)?,
+						6 => witness_from_underliers::<U, Tower::B64, F>(n_vars, underliers)?,
+						7 => witness_from_underliers::<U, Tower::B128, F>(n_vars, underliers)?,
+						tower_level => bail!(Error::CannotCommitTowerLevel { tower_level }),
+					}
+				}
+				_ => {
+					let values = derived_oracle_values(&oracle, &witness)?;
+					let packed: Vec<PackedType<U, F>> = values
+						.chunks(PackedType::<U, F>::WIDTH)
+						.map(|chunk| PackedType::<U, F>::from_scalars(chunk.iter().copied()))
+						.collect();
+					MultilinearExtension::new(n_vars, packed)?
+						.specialize_arc_dyn::<PackedType<U, F>>()
+				}
+			};
+			witness.update_multilin_poly([(oracle.id(), poly)])?;
+		}
+		Ok(witness)
+	}
+}
+
+fn random_multilin_poly<'a, U, FS, F>(
+	n_vars: usize,
+	mut rng: impl RngCore,
+) -> Result<MultilinearWitness<'a, PackedType<U, F>>, Error>
+where
+	U: UnderlierType + PackScalar<FS> + PackScalar<F>,
+	FS: TowerField,
+	F: TowerField + ExtensionField<FS>,
+{
+	let len = 1 << n_vars.saturating_sub(PackedType::<U, FS>::LOG_WIDTH);
+	let underliers = (0..len)
+		.map(|_| PackedType::<U, FS>::random(&mut rng).to_underlier())
+		.collect();
+	Ok(witness_from_underliers::<U, FS, F>(n_vars, underliers)?)
+}
+
+/// Evaluates a virtual oracle over the hypercube from the witnesses of its inner oracles.
+fn derived_oracle_values<U, F>(
+	oracle: &MultilinearPolyOracle<F>,
+	witness: &MultilinearExtensionIndex<U, F>,
+) -> Result<Vec<F>, Error>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	let n_vars = oracle.n_vars();
+	let values = match oracle {
+		MultilinearPolyOracle::Transparent { inner, .. } => (0..1 << n_vars)
+			.map(|i| {
+				inner
+					.poly()
+					.evaluate(&decompose_index_to_hypercube_point(n_vars, i))
+			})
+			.collect::<Result<_, _>>()?,
+		MultilinearPolyOracle::LinearCombination {
+			linear_combination, ..
+		} => {
+			let polys = linear_combination
+				.polys()
+				.map(|oracle| witness.get_multilin_poly(oracle.id()))
+				.collect::<Result<Vec<_>, _>>()?;
+			(0..1 << n_vars)
+				.map(|i| {
+					linear_combination.coefficients().zip(&polys).try_fold(
+						linear_combination.offset(),
+						|acc, (coeff, poly)| {
+							Ok::<F, Error>(acc + poly.evaluate_on_hypercube_and_scale(i, coeff)?)
+						},
+					)
+				})
+				.collect::<Result<_, _>>()?
+		}
+		MultilinearPolyOracle::Repeating { inner, .. } => {
+			let inner_poly = witness.get_multilin_poly(inner.id())?;
+			(0..1 << n_vars)
+				.map(|i| inner_poly.evaluate_on_hypercube(i % (1 << inner.n_vars())))
+				.collect::<Result<_, _>>()?
+		}
+		MultilinearPolyOracle::Shifted { shifted, .. } => {
+			let inner_poly = witness.get_multilin_poly(shifted.inner().id())?;
+			let block_len = 1 << shifted.block_size();
+			let shift_offset = shifted.shift_offset();
+			(0..1 << n_vars)
+				.map(|i| {
+					let block_start = i - i % block_len;
+					let offset = i % block_len;
+					let source = match shifted.shift_variant() {
+						ShiftVariant::CircularLeft => {
+							Some((offset + block_len - shift_offset) % block_len)
+						}
+						ShiftVariant::LogicalLeft => offset.checked_sub(shift_offset),
+						ShiftVariant::LogicalRight => {
+							Some(offset + shift_offset).filter(|&source| source < block_len)
+						}
+					};
+					match source {
+						Some(source) => inner_poly.evaluate_on_hypercube(block_start + source),
+						None => Ok(F::ZERO),
+					}
+				})
+				.collect::<Result<_, _>>()?
+		}
+		MultilinearPolyOracle::Projected { projected, .. } => {
+			let inner_poly = witness.get_multilin_poly(projected.inner().id())?;
+			let query =
+				binius_hal::make_portable_backend().multilinear_query(projected.values())?;
+			let projected_poly = match projected.projection_variant() {
+				ProjectionVariant::FirstVars => inner_poly.evaluate_partial_low(query.to_ref())?,
+				ProjectionVariant::LastVars => inner_poly.evaluate_partial_high(query.to_ref())?,
+			};
+			(0..1 << n_vars)
+				.map(|i| projected_poly.evaluate_on_hypercube(i))
+				.collect::<Result<_, _>>()?
+		}
+		MultilinearPolyOracle::ZeroPadded { inner, .. } => {
+			let inner_poly = witness.get_multilin_poly(inner.id())?;
+			(0..1 << n_vars)
+				.map(|i| {
+					if i < 1 << inner.n_vars() {
+						inner_poly.evaluate_on_hypercube(i)
+					} else {
+						Ok(F::ZERO)
+					}
+				})
+				.collect::<Result<_, _>>()?
+		}
+		MultilinearPolyOracle::Committed { .. } | MultilinearPolyOracle::Packed { .. } => {
+			unreachable!("committed and packed oracles are not derived from their evaluations")
+		}
+	};
+	Ok(values)
+}
+
+/// A constraint of a [`ConstraintSystem`] along with the constraint set that contains it.
+#[derive(Debug, Clone, Copy)]
+pub struct ConstraintRef<'a, F: TowerField> {
+	constraint_set: &'a ConstraintSet<F>,
+	constraint: &'a Constraint<F>,
+}
+
+impl<'a, F: TowerField> ConstraintRef<'a, F> {
+	pub fn name(&self) -> &'a str {
+		&self.constraint.name
+	}
+
+	/// The oracles read by the composition, in increasing order.
+	pub fn oracle_ids(&self) -> Vec<OracleId> {
+		self.constraint
+			.composition
+			.vars_usage()
+			.into_iter()
+			.zip(&self.constraint_set.oracle_ids)
+			.filter_map(|(used, &id)| used.then_some(id))
+			.collect()
+	}
+
+	/// The composition of the constraint.
+	///
+	/// Variable `i` of the composition is the oracle `constraint_set().oracle_ids[i]`.
+	pub fn composition(&self) -> &'a ArithExpr<F> {
+		&self.constraint.composition
+	}
+
+	pub fn max_degree(&self) -> usize {
+		self.constraint.composition.degree()
+	}
+
+	pub fn constraint_set(&self) -> &'a ConstraintSet<F> {
+		self.constraint_set
+	}
 }
 
 /// Constraint system proof that has been serialized into bytes
@@ -53,3 +376,208 @@ impl Proof {
 		self.transcript.len()
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		arch::OptimalUnderlier, BinaryField128b, BinaryField1b, BinaryField32b, Field, TowerField,
+	};
+	use binius_math::ArithExpr;
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		oracle::{ConstraintSetBuilder, Error as OracleError},
+		protocols::sumcheck,
+		tower::CanonicalTowerFamily,
+	};
+
+	#[test]
+	fn test_constraints_oracle_ids() {
+		type F = BinaryField128b;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b, c] = oracles.add_committed_multiple(4, BinaryField1b::TOWER_LEVEL);
+		let d = oracles.add_committed(5, BinaryField1b::TOWER_LEVEL);
+
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck(
+			"and",
+			[a, b, c],
+			ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Var(2),
+		);
+		builder.add_zerocheck("xor", [c, a], ArithExpr::Var(0) + ArithExpr::Var(1));
+		builder.add_zerocheck("bit", [d], ArithExpr::Var(0).pow(2) - ArithExpr::Var(0));
+
+		let constraint_system = ConstraintSystem {
+			oracles: oracles.clone(),
+			table_constraints: builder.build(&oracles).unwrap(),
+			non_zero_oracle_ids: vec![],
+			flushes: vec![],
+			max_channel_id: 0,
+		};
+
+		let mut constraints = constraint_system
+			.constraints()
+			.map(|constraint| {
+				(constraint.name().to_string(), constraint.oracle_ids(), constraint.max_degree())
+			})
+			.collect::<Vec<_>>();
+		constraints.sort();
+		assert_eq!(
+			constraints,
+			vec![
+				("and".to_string(), vec![a, b, c], 2),
+				("bit".to_string(), vec![d], 2),
+				("xor".to_string(), vec![a, c], 1),
+			]
+		);
+	}
+
+	#[test]
+	fn test_non_polynomial_composition_rejected() {
+		type F = BinaryField128b;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b] = oracles.add_committed_multiple(4, BinaryField1b::TOWER_LEVEL);
+
+		let mut builder = ConstraintSetBuilder::<F>::new();
+		builder.add_zerocheck(
+			"inv",
+			[a, b],
+			ArithExpr::Var(0).inv() * ArithExpr::Var(1) - ArithExpr::one(),
+		);
+		let result = builder.build(&oracles);
+		assert!(matches!(result, Err(OracleError::NonPolynomialComposition { .. })));
+
+		// The inverse of a constant is a polynomial
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck(
+			"const_inv",
+			[a, b],
+			ArithExpr::Const(F::new(3)).inv() * ArithExpr::Var(0) - ArithExpr::Var(1),
+		);
+		assert_eq!(builder.build(&oracles).unwrap().len(), 1);
+	}
+
+	#[test]
+	fn test_total_committed_bits() {
+		type F = BinaryField128b;
+
+		// A sum of three columns into a fourth, all of them committed
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b, c, sum] = oracles.add_committed_multiple(5, BinaryField32b::TOWER_LEVEL);
+		let combined = oracles
+			.add_linear_combination(5, [(a, F::new(1)), (b, F::new(1)), (c, F::new(1))])
+			.unwrap();
+		oracles
+			.add_shifted(combined, 1, 5, ShiftVariant::LogicalLeft)
+			.unwrap();
+
+		let mut builder = ConstraintSetBuilder::new();
+		builder.add_zerocheck("sum", [combined, sum], ArithExpr::Var(0) - ArithExpr::Var(1));
+		let constraint_system = ConstraintSystem {
+			oracles: oracles.clone(),
+			table_constraints: builder.build(&oracles).unwrap(),
+			non_zero_oracle_ids: vec![],
+			flushes: vec![],
+			max_channel_id: 0,
+		};
+
+		assert_eq!(constraint_system.total_committed_bits(), 4 * 32 * 32);
+	}
+
+	#[test]
+	fn test_validate_witness_checks_sums() {
+		type F = BinaryField128b;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b] = oracles.add_committed_multiple(4, BinaryField32b::TOWER_LEVEL);
+
+		let system_with_sums = |sums: [F; 2]| {
+			let mut builder = ConstraintSetBuilder::new();
+			builder.add_sumcheck([a], ArithExpr::Var(0), sums[0]);
+			builder.add_sumcheck([a, b], ArithExpr::Var(0) * ArithExpr::Var(1), sums[1]);
+			ConstraintSystem {
+				oracles: oracles.clone(),
+				table_constraints: builder.build(&oracles).unwrap(),
+				non_zero_oracle_ids: vec![],
+				flushes: vec![],
+				max_channel_id: 0,
+			}
+		};
+
+		let witness = system_with_sums([F::ZERO; 2])
+			.random_witness::<OptimalUnderlier, CanonicalTowerFamily>(StdRng::seed_from_u64(0))
+			.unwrap();
+		let a_poly = witness.get_multilin_poly(a).unwrap();
+		let b_poly = witness.get_multilin_poly(b).unwrap();
+		let [a_sum, ab_sum] = (0..1 << 4).fold([F::ZERO; 2], |[a_sum, ab_sum], i| {
+			let a = a_poly.evaluate_on_hypercube(i).unwrap();
+			let b = b_poly.evaluate_on_hypercube(i).unwrap();
+			[a_sum + a, ab_sum + a * b]
+		});
+
+		let constraint_system = system_with_sums([a_sum, ab_sum]);
+		validate::validate_witness(&constraint_system, &[], &witness).unwrap();
+		validate::validate_witness_par(&constraint_system, &[], &witness).unwrap();
+
+		let constraint_system = system_with_sums([a_sum, ab_sum + F::ONE]);
+		for result in [
+			validate::validate_witness(&constraint_system, &[], &witness),
+			validate::validate_witness_par(&constraint_system, &[], &witness),
+		] {
+			assert!(matches!(
+				result,
+				Err(Error::Constraint(sumcheck::Error::SumcheckNaiveValidationFailure {
+					composition_index: 1
+				}))
+			));
+		}
+	}
+
+	#[test]
+	fn test_random_witness_derives_virtual_oracles() {
+		type F = BinaryField128b;
+
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let bits = oracles.add_committed(10, BinaryField1b::TOWER_LEVEL);
+		let words = oracles.add_committed(10, BinaryField32b::TOWER_LEVEL);
+		let shifted = oracles
+			.add_shifted(words, 3, 4, ShiftVariant::LogicalLeft)
+			.unwrap();
+		let combined = oracles
+			.add_linear_combination_with_offset(
+				10,
+				F::new(7),
+				[(bits, F::new(3)), (shifted, F::new(5))],
+			)
+			.unwrap();
+		let repeated = oracles.add_repeating(combined, 2).unwrap();
+		let padded = oracles.add_zero_padded(shifted, 11).unwrap();
+		let packed = oracles.add_packed(bits, 3).unwrap();
+
+		let constraint_system = ConstraintSystem {
+			oracles,
+			table_constraints: vec![],
+			non_zero_oracle_ids: vec![],
+			flushes: vec![],
+			max_channel_id: 0,
+		};
+		let witness = constraint_system
+			.random_witness::<OptimalUnderlier, CanonicalTowerFamily>(StdRng::seed_from_u64(0))
+			.unwrap();
+
+		validate::validate_witness(&constraint_system, &[], &witness).unwrap();
+
+		let combined = witness.get_multilin_poly(combined).unwrap();
+		let bits = witness.get_multilin_poly(bits).unwrap();
+		assert_eq!(
+			combined.evaluate_on_hypercube(0).unwrap(),
+			F::new(7) + F::new(3) * bits.evaluate_on_hypercube(0).unwrap()
+		);
+		for id in [repeated, padded, packed] {
+			assert!(witness.has(id));
+		}
+	}
+}
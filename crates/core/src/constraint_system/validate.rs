@@ -1,10 +1,13 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use binius_field::{
-	as_packed_field::PackScalar, underlier::UnderlierType, BinaryField1b, TowerField,
+	as_packed_field::{PackScalar, PackedType},
+	underlier::UnderlierType,
+	BinaryField1b, TowerField,
 };
 use binius_hal::ComputationBackendExt;
 use binius_math::MultilinearPoly;
+use binius_maybe_rayon::prelude::*;
 use binius_utils::bail;
 
 use super::{
@@ -13,10 +16,16 @@ use super::{
 	ConstraintSystem,
 };
 use crate::{
-	oracle::{ConstraintPredicate, MultilinearPolyOracle},
-	polynomial::{test_utils::decompose_index_to_hypercube_point, ArithCircuitPoly},
-	protocols::sumcheck::prove::zerocheck,
-	witness::MultilinearExtensionIndex,
+	oracle::{ConstraintPredicate, ConstraintSet, MultilinearPolyOracle},
+	polynomial::{
+		test_utils::decompose_index_to_hypercube_point, ArithCircuitPoly, MultilinearComposite,
+	},
+	protocols::sumcheck::{
+		self,
+		prove::{regular_sumcheck, zerocheck},
+		CompositeSumClaim,
+	},
+	witness::{MultilinearExtensionIndex, MultilinearWitness},
 };
 
 pub fn validate_witness<U, F>(
@@ -30,26 +39,28 @@ where
 {
 	// Check the constraint sets
 	for constraint_set in constraint_system.table_constraints.iter() {
-		let multilinears = constraint_set
-			.oracle_ids
-			.iter()
-			.map(|id| witness.get_multilin_poly(*id))
-			.collect::<Result<Vec<_>, _>>()?;
+		let (multilinears, compositions) = constraint_set_witness(constraint_set, witness)?;
 
 		let mut zero_claims = vec![];
-		for constraint in constraint_set.constraints.iter() {
+		let mut sum_claims = vec![];
+		for (constraint, composition) in constraint_set.constraints.iter().zip(compositions) {
 			match constraint.predicate {
-				ConstraintPredicate::Zero => zero_claims.push((
-					constraint.name.clone(),
-					ArithCircuitPoly::with_n_vars(
-						multilinears.len(),
-						constraint.composition.clone(),
-					)?,
-				)),
-				ConstraintPredicate::Sum(_) => unimplemented!(),
+				ConstraintPredicate::Zero => {
+					zero_claims.push((constraint.name.clone(), composition))
+				}
+				ConstraintPredicate::Sum(sum) => sum_claims.push((sum, composition)),
 			}
 		}
 		zerocheck::validate_witness(&multilinears, &zero_claims)?;
+		regular_sumcheck::validate_witness(
+			&multilinears,
+			sum_claims
+				.iter()
+				.map(|(sum, composition)| CompositeSumClaim {
+					composition,
+					sum: *sum,
+				}),
+		)?;
 	}
 
 	// Check that nonzero oracles are non-zero over the entire hypercube
@@ -75,6 +86,134 @@ where
 	Ok(())
 }
 
+/// A hypercube vertex at which a zero constraint does not evaluate to zero.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintViolation {
+	pub name: String,
+	pub vertex_index: usize,
+}
+
+/// Variant of [`validate_witness`] that checks the constraints in parallel.
+///
+/// Every constraint is evaluated over the hypercube on its own rayon task. The violations of the
+/// zero constraints are all collected into [`Error::ConstraintViolations`], ordered by constraint
+/// and then by vertex, instead of stopping at the first one. A sum constraint whose sum does not
+/// match fails with [`sumcheck::Error::SumcheckNaiveValidationFailure`]. The remaining checks are
+/// the same as in [`validate_witness`], with the virtual oracles also checked in parallel.
+pub fn validate_witness_par<U, F>(
+	constraint_system: &ConstraintSystem<F>,
+	boundaries: &[Boundary<F>],
+	witness: &MultilinearExtensionIndex<'_, U, F>,
+) -> Result<(), Error>
+where
+	U: UnderlierType + PackScalar<F> + PackScalar<BinaryField1b>,
+	F: TowerField,
+{
+	let mut claims = vec![];
+	for constraint_set in constraint_system.table_constraints.iter() {
+		let (multilinears, compositions) = constraint_set_witness(constraint_set, witness)?;
+		let n_vars = constraint_set.n_vars;
+		for (composition_index, (constraint, composition)) in constraint_set
+			.constraints
+			.iter()
+			.zip(compositions)
+			.enumerate()
+		{
+			let composite = MultilinearComposite::new(n_vars, composition, multilinears.clone())?;
+			claims.push((constraint, composition_index, composite));
+		}
+	}
+
+	let violations = claims
+		.into_par_iter()
+		.map(|(constraint, composition_index, composite)| {
+			let mut violations = vec![];
+			match constraint.predicate {
+				ConstraintPredicate::Zero => {
+					for vertex_index in 0..1 << composite.n_vars() {
+						if composite.evaluate_on_hypercube(vertex_index)? != F::ZERO {
+							violations.push(ConstraintViolation {
+								name: constraint.name.to_string(),
+								vertex_index,
+							});
+						}
+					}
+				}
+				ConstraintPredicate::Sum(expected_sum) => {
+					let sum = (0..1 << composite.n_vars())
+						.map(|vertex_index| composite.evaluate_on_hypercube(vertex_index))
+						.sum::<Result<F, _>>()?;
+					if sum != expected_sum {
+						bail!(sumcheck::Error::SumcheckNaiveValidationFailure {
+							composition_index
+						});
+					}
+				}
+			}
+			Ok::<_, Error>(violations)
+		})
+		.collect::<Result<Vec<_>, _>>()?
+		.into_iter()
+		.flatten()
+		.collect::<Vec<_>>();
+	if !violations.is_empty() {
+		bail!(Error::ConstraintViolations { violations });
+	}
+
+	nonzerocheck::validate_witness(
+		witness,
+		&constraint_system.oracles,
+		&constraint_system.non_zero_oracle_ids,
+	)?;
+
+	channel::validate_witness(
+		witness,
+		&constraint_system.flushes,
+		boundaries,
+		constraint_system.max_channel_id,
+	)?;
+
+	constraint_system
+		.oracles
+		.iter()
+		.collect::<Vec<_>>()
+		.into_par_iter()
+		.try_for_each(|oracle| validate_virtual_oracle_witness(oracle, witness))
+}
+
+/// Returns the witness multilinears of a constraint set, which must all have the constraint set
+/// number of variables, and the composition circuit of each of its constraints.
+#[allow(clippy::type_complexity)]
+fn constraint_set_witness<'a, U, F>(
+	constraint_set: &ConstraintSet<F>,
+	witness: &MultilinearExtensionIndex<'a, U, F>,
+) -> Result<(Vec<MultilinearWitness<'a, PackedType<U, F>>>, Vec<ArithCircuitPoly<F>>), Error>
+where
+	U: UnderlierType + PackScalar<F>,
+	F: TowerField,
+{
+	let multilinears = constraint_set
+		.oracle_ids
+		.iter()
+		.map(|id| witness.get_multilin_poly(*id))
+		.collect::<Result<Vec<_>, _>>()?;
+	if multilinears
+		.iter()
+		.any(|multilinear| multilinear.n_vars() != constraint_set.n_vars)
+	{
+		bail!(sumcheck::Error::NumberOfVariablesMismatch);
+	}
+
+	let compositions = constraint_set
+		.constraints
+		.iter()
+		.map(|constraint| {
+			ArithCircuitPoly::with_n_vars(multilinears.len(), constraint.composition.clone())
+		})
+		.collect::<Result<Vec<_>, _>>()?;
+	Ok((multilinears, compositions))
+}
+
 pub fn validate_virtual_oracle_witness<U, F>(
 	oracle: MultilinearPolyOracle<F>,
 	witness: &MultilinearExtensionIndex<U, F>,
@@ -40,6 +40,7 @@ pub struct ConstraintSet<F: Field> {
 
 // A deferred constraint constructor that instantiates index composition after the superset of oracles is known
 #[allow(clippy::type_complexity)]
+#[derive(Clone)]
 struct UngroupedConstraint<F: Field> {
 	name: Arc<str>,
 	oracle_ids: Vec<OracleId>,
@@ -49,7 +50,7 @@ struct UngroupedConstraint<F: Field> {
 
 /// A builder struct that turns individual compositions over oraclized multilinears into a set of
 /// type erased `IndexComposition` instances operating over a superset of oracles of all constraints.
-#[derive(Default)]
+#[derive(Default, Clone)]
 pub struct ConstraintSetBuilder<F: Field> {
 	constraints: Vec<UngroupedConstraint<F>>,
 }
@@ -103,6 +104,9 @@ impl<F: Field> ConstraintSetBuilder<F> {
 			// Do not bail!, this error is handled in evalcheck.
 			return Err(Error::EmptyConstraintSet);
 		}
+		for constraint in &self.constraints {
+			check_polynomial(&constraint.name, &constraint.composition)?;
+		}
 		for id in oracle_ids.iter() {
 			if !oracles.is_valid_oracle_id(*id) {
 				bail!(Error::InvalidOracleId(*id));
@@ -195,6 +199,7 @@ impl<F: Field> ConstraintSetBuilder<F> {
 				if constraint.oracle_ids.is_empty() {
 					bail!(Error::EmptyConstraintSet);
 				}
+				check_polynomial(&constraint.name, &constraint.composition)?;
 				for id in constraint.oracle_ids.iter() {
 					if !oracles.is_valid_oracle_id(*id) {
 						bail!(Error::InvalidOracleId(*id));
@@ -265,6 +270,19 @@ impl<F: Field> ConstraintSetBuilder<F> {
 	}
 }
 
+/// Checks that the composition of a constraint is a polynomial.
+///
+/// Compositions containing [`ArithExpr::Inv`] of a non-constant expression have no degree and
+/// cannot be proven with sumcheck.
+fn check_polynomial<F: Field>(name: &str, composition: &ArithExpr<F>) -> Result<(), Error> {
+	if !composition.is_polynomial() {
+		bail!(Error::NonPolynomialComposition {
+			name: name.to_string(),
+		});
+	}
+	Ok(())
+}
+
 /// Find index of every subset element within the superset.
 /// If the superset contains duplicate elements the index of the first match is used
 ///
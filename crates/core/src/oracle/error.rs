@@ -26,4 +26,6 @@ pub enum Error {
 	EmptyConstraintSet,
 	#[error("expected constraint set to contain only constraints with n_vars={expected}, but found n_vars={got}")]
 	ConstraintSetNvarsMismatch { got: usize, expected: usize },
+	#[error("the composition of constraint {name} is not a polynomial")]
+	NonPolynomialComposition { name: String },
 }
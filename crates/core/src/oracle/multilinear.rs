@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{array, fmt::Debug, sync::Arc};
+use std::{array, collections::HashMap, fmt::Debug, sync::Arc};
 
 use binius_field::{Field, TowerField};
 use binius_utils::bail;
@@ -230,6 +230,66 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 		Ok(self.mut_ref.add_to_set(oracle))
 	}
 
+	/// Adds a copy of an existing oracle under a new name.
+	///
+	/// The copy has the same kind and shape as the source oracle, and derived oracles reference the
+	/// same inner oracles as the source. Copies of committed oracles are committed separately.
+	pub fn cloned(self, src_id: OracleId) -> Result<OracleId, Error> {
+		if src_id >= self.mut_ref.oracles.len() {
+			bail!(Error::InvalidOracleId(src_id));
+		}
+
+		let src = self.mut_ref.get_from_set(src_id);
+
+		let oracle = |id: OracleId| {
+			use MultilinearPolyOracle::*;
+			let name = self.name;
+			match (*src).clone() {
+				Transparent { inner, .. } => Transparent { id, inner, name },
+				Committed {
+					n_vars,
+					tower_level,
+					..
+				} => Committed {
+					oracle_id: id,
+					n_vars,
+					tower_level,
+					name,
+				},
+				Repeating {
+					inner, log_count, ..
+				} => Repeating {
+					id,
+					inner,
+					log_count,
+					name,
+				},
+				Projected { projected, .. } => Projected {
+					id,
+					projected,
+					name,
+				},
+				Shifted { shifted, .. } => Shifted { id, shifted, name },
+				Packed { packed, .. } => Packed { id, packed, name },
+				LinearCombination {
+					linear_combination, ..
+				} => LinearCombination {
+					id,
+					linear_combination,
+					name,
+				},
+				ZeroPadded { inner, n_vars, .. } => ZeroPadded {
+					id,
+					inner,
+					n_vars,
+					name,
+				},
+			}
+		};
+
+		Ok(self.mut_ref.add_to_set(oracle))
+	}
+
 	fn add_committed_with_name(
 		&mut self,
 		n_vars: usize,
@@ -255,15 +315,20 @@ impl<F: TowerField> MultilinearOracleSetAddition<'_, F> {
 ///
 /// The oracle set also tracks the committed polynomial in batches where each batch is committed
 /// together with a polynomial commitment scheme.
+///
+/// Each oracle can be tagged with free-form key-value metadata for downstream tooling. The metadata
+/// has no effect on the protocol.
 #[derive(Default, Debug, Clone)]
 pub struct MultilinearOracleSet<F: TowerField> {
 	oracles: Vec<Arc<MultilinearPolyOracle<F>>>,
+	metadata: Vec<HashMap<String, String>>,
 }
 
 impl<F: TowerField> MultilinearOracleSet<F> {
 	pub fn new() -> Self {
 		Self {
 			oracles: Vec::new(),
+			metadata: Vec::new(),
 		}
 	}
 
@@ -275,6 +340,27 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		(0..self.oracles.len()).map(|id| self.oracle(id))
 	}
 
+	/// Iterates over the ids of the committed oracles, in increasing order.
+	///
+	/// These are the oracles whose witnesses must be committed to, as opposed to the transparent
+	/// and virtual oracles that are derived from them.
+	pub fn committed_oracles(&self) -> impl Iterator<Item = OracleId> + '_ {
+		self.oracles
+			.iter()
+			.filter(|oracle| matches!(oracle.as_ref(), MultilinearPolyOracle::Committed { .. }))
+			.map(|oracle| oracle.id())
+	}
+
+	/// Returns the total number of bits in the committed oracles.
+	///
+	/// Each committed oracle contributes `2^n_vars` values of its tower level's bit width. Virtual
+	/// oracles are derived from the committed ones and are not counted.
+	pub fn total_committed_bits(&self) -> usize {
+		self.committed_oracles()
+			.map(|id| (1 << self.n_vars(id)) << self.tower_level(id))
+			.sum()
+	}
+
 	pub fn add(&mut self) -> MultilinearOracleSetAddition<F> {
 		MultilinearOracleSetAddition {
 			name: None,
@@ -300,6 +386,7 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		let id = self.oracles.len();
 
 		self.oracles.push(Arc::new(oracle(id)));
+		self.metadata.push(HashMap::new());
 		id
 	}
 
@@ -375,6 +462,10 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 		self.add().zero_padded(id, n_vars)
 	}
 
+	pub fn add_cloned(&mut self, id: OracleId) -> Result<OracleId, Error> {
+		self.add().cloned(id)
+	}
+
 	pub fn oracle(&self, id: OracleId) -> MultilinearPolyOracle<F> {
 		(*self.oracles[id]).clone()
 	}
@@ -391,6 +482,25 @@ impl<F: TowerField> MultilinearOracleSet<F> {
 	pub fn tower_level(&self, id: OracleId) -> usize {
 		self.oracles[id].binary_tower_level()
 	}
+
+	/// Tags the oracle with `key`, replacing any previous value of that key.
+	pub fn set_oracle_metadata(
+		&mut self,
+		id: OracleId,
+		key: &str,
+		value: String,
+	) -> Result<(), Error> {
+		if !self.is_valid_oracle_id(id) {
+			bail!(Error::InvalidOracleId(id));
+		}
+		self.metadata[id].insert(key.to_owned(), value);
+		Ok(())
+	}
+
+	/// The metadata the oracle was tagged with by [`Self::set_oracle_metadata`].
+	pub fn oracle_metadata(&self, id: OracleId) -> &HashMap<String, String> {
+		&self.metadata[id]
+	}
 }
 
 /// A multilinear polynomial oracle in the polynomial IOP model.
@@ -741,7 +851,7 @@ impl<F: Field> MultilinearPolyOracle<F> {
 mod tests {
 	use binius_field::{BinaryField128b, BinaryField1b, Field, TowerField};
 
-	use super::{MultilinearOracleSet, ProjectionVariant};
+	use super::{MultilinearOracleSet, MultilinearPolyOracle, ProjectionVariant, ShiftVariant};
 
 	#[test]
 	fn add_projection_with_all_vars() {
@@ -757,4 +867,64 @@ mod tests {
 			.unwrap();
 		let _ = oracles.oracle(projected);
 	}
+
+	#[test]
+	fn committed_oracles_excludes_virtual() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let [a, b] = oracles.add_committed_multiple(5, BinaryField1b::TOWER_LEVEL);
+		let shifted = oracles
+			.add_shifted(a, 1, 5, ShiftVariant::LogicalLeft)
+			.unwrap();
+		let c = oracles.add_committed(5, BinaryField128b::TOWER_LEVEL);
+		oracles
+			.add_linear_combination(5, [(shifted, F::ONE), (b, F::ONE)])
+			.unwrap();
+		let d = oracles.add_committed(3, BinaryField1b::TOWER_LEVEL);
+		oracles.add_packed(a, 2).unwrap();
+
+		assert_eq!(oracles.committed_oracles().collect::<Vec<_>>(), vec![a, b, c, d]);
+	}
+
+	#[test]
+	fn add_cloned_preserves_kind_and_shape() {
+		type F = BinaryField128b;
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let data = oracles.add_committed(5, BinaryField1b::TOWER_LEVEL);
+		let shifted = oracles
+			.add_shifted(data, 1, 3, ShiftVariant::CircularLeft)
+			.unwrap();
+
+		let data_clone = oracles.add_named("data_clone").cloned(data).unwrap();
+		let shifted_clone = oracles.add_named("shifted_clone").cloned(shifted).unwrap();
+		assert_ne!(data_clone, data);
+		assert_ne!(shifted_clone, shifted);
+
+		let MultilinearPolyOracle::Committed {
+			oracle_id,
+			n_vars,
+			tower_level,
+			name,
+		} = oracles.oracle(data_clone)
+		else {
+			panic!("expected a committed oracle");
+		};
+		assert_eq!(oracle_id, data_clone);
+		assert_eq!(n_vars, 5);
+		assert_eq!(tower_level, BinaryField1b::TOWER_LEVEL);
+		assert_eq!(name.as_deref(), Some("data_clone"));
+
+		let (
+			MultilinearPolyOracle::Shifted { shifted: src, .. },
+			MultilinearPolyOracle::Shifted { shifted: dst, .. },
+		) = (oracles.oracle(shifted), oracles.oracle(shifted_clone))
+		else {
+			panic!("expected shifted oracles");
+		};
+		assert_eq!(src, dst);
+		assert_eq!(dst.inner().id(), data);
+		assert_eq!(oracles.n_vars(shifted_clone), oracles.n_vars(shifted));
+
+		assert!(oracles.add_cloned(100).is_err());
+	}
 }
@@ -1,52 +1,101 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{fmt::Debug, mem::MaybeUninit, sync::Arc};
+use std::{
+	cmp::Ordering, collections::HashMap, fmt::Debug, iter::repeat_with, mem::MaybeUninit,
+	ops::Range, sync::Arc,
+};
 
-use binius_field::{ExtensionField, Field, PackedField, TowerField};
+use binius_field::{
+	deserialize_canonical, serialize_canonical, BinaryField128b, BinaryField16b, BinaryField1b,
+	BinaryField32b, BinaryField64b, BinaryField8b, ExtensionField, Field, PackedField, TowerField,
+};
 use binius_math::{ArithExpr, CompositionPoly, CompositionPolyOS, Error};
+use binius_maybe_rayon::prelude::*;
+use binius_utils::{
+	bail,
+	serialization::{
+		read_header, read_varint, write_header, write_varint, ArtifactKind, DeserializeBytes,
+		Error as SerializationError, SerializeBytes,
+	},
+	thread_local_mut::ThreadLocalMut,
+};
+use bytes::{Buf, BufMut};
+use rand::{rngs::StdRng, Rng, SeedableRng};
 use stackalloc::{helpers::slice_assume_init, stackalloc_uninit};
 
-use super::MultivariatePoly;
+use super::{test_utils::decompose_index_to_hypercube_point, MultivariatePoly};
 
 /// Convert the expression to a sequence of arithmetic operations that can be evaluated in sequence.
+///
+/// Also returns the square-and-multiply schedules of the distinct exponents, which are shared by
+/// all `Pow` steps raising to the same power.
 fn circuit_steps_for_expr<F: Field>(
 	expr: &ArithExpr<F>,
-) -> (Vec<CircuitStep<F>>, CircuitStepArgument<F>) {
+) -> (Vec<CircuitStep<F>>, CircuitStepArgument<F>, Vec<PowSchedule>) {
 	let mut steps = Vec::new();
+	let mut pow_schedules = Vec::new();
 
 	fn to_circuit_inner<F: Field>(
 		expr: &ArithExpr<F>,
 		result: &mut Vec<CircuitStep<F>>,
+		pow_schedules: &mut Vec<PowSchedule>,
 	) -> CircuitStepArgument<F> {
 		match expr {
 			ArithExpr::Const(value) => CircuitStepArgument::Const(*value),
 			ArithExpr::Var(index) => CircuitStepArgument::Expr(CircuitNode::Var(*index)),
 			ArithExpr::Add(left, right) => {
-				let left = to_circuit_inner(left, result);
-				let right = to_circuit_inner(right, result);
+				let left = to_circuit_inner(left, result, pow_schedules);
+				let right = to_circuit_inner(right, result, pow_schedules);
 				result.push(CircuitStep::Add(left, right));
 				CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
 			}
+			ArithExpr::Sub(left, right) => {
+				let left = to_circuit_inner(left, result, pow_schedules);
+				let right = to_circuit_inner(right, result, pow_schedules);
+				result.push(CircuitStep::Sub(left, right));
+				CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
+			}
 			ArithExpr::Mul(left, right) => {
-				let left = to_circuit_inner(left, result);
-				let right = to_circuit_inner(right, result);
+				let left = to_circuit_inner(left, result, pow_schedules);
+				let right = to_circuit_inner(right, result, pow_schedules);
 				result.push(CircuitStep::Mul(left, right));
 				CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
 			}
 			ArithExpr::Pow(id, exp) => {
-				let id = to_circuit_inner(id, result);
-				result.push(CircuitStep::Pow(id, *exp));
+				let id = to_circuit_inner(id, result, pow_schedules);
+				let schedule = match pow_schedules
+					.iter()
+					.position(|schedule| schedule.exp == *exp)
+				{
+					Some(schedule) => schedule,
+					None => {
+						pow_schedules.push(PowSchedule::new(*exp));
+						pow_schedules.len() - 1
+					}
+				};
+				result.push(CircuitStep::Pow(id, schedule));
+				CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
+			}
+			ArithExpr::Inv(inner) => {
+				let inner = to_circuit_inner(inner, result, pow_schedules);
+				result.push(CircuitStep::Inv(inner));
 				CircuitStepArgument::Expr(CircuitNode::Slot(result.len() - 1))
 			}
 		}
 	}
 
-	let ret = to_circuit_inner(expr, &mut steps);
-	(steps, ret)
+	let ret = to_circuit_inner(expr, &mut steps, &mut pow_schedules);
+	(steps, ret, pow_schedules)
+}
+
+/// Computes the total degree of a sequence of expressions, which is the maximum of their
+/// [`ArithExpr::degree`]s. This is the degree reported by [`ArithCircuitPoly`].
+pub fn compute_degree<F: Field>(exprs: &[ArithExpr<F>]) -> usize {
+	exprs.iter().map(ArithExpr::degree).max().unwrap_or(0)
 }
 
 /// Input of the circuit calculation step
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum CircuitNode {
 	/// Input variable
 	Var(usize),
@@ -70,7 +119,7 @@ impl CircuitNode {
 	}
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum CircuitStepArgument<F> {
 	Expr(CircuitNode),
 	Const(F),
@@ -80,11 +129,55 @@ enum CircuitStepArgument<F> {
 ///
 /// ExprIds used by an Expr has to be less than the index of the Expr itself within the ArithCircuitPoly,
 /// to ensure it represents a directed acyclic graph that can be computed in sequence.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum CircuitStep<F: Field> {
 	Add(CircuitStepArgument<F>, CircuitStepArgument<F>),
+	Sub(CircuitStepArgument<F>, CircuitStepArgument<F>),
 	Mul(CircuitStepArgument<F>, CircuitStepArgument<F>),
-	Pow(CircuitStepArgument<F>, u64),
+	/// Raises the argument to a power, given by its index in the circuit's exponent schedules.
+	Pow(CircuitStepArgument<F>, usize),
+	/// Inverts the argument, mapping zero to zero.
+	Inv(CircuitStepArgument<F>),
+}
+
+impl<F: Field> CircuitStep<F> {
+	/// Returns the same operation applied to the arguments transformed by `f`.
+	fn map_arguments(self, f: impl Fn(CircuitStepArgument<F>) -> CircuitStepArgument<F>) -> Self {
+		match self {
+			Self::Add(x, y) => Self::Add(f(x), f(y)),
+			Self::Sub(x, y) => Self::Sub(f(x), f(y)),
+			Self::Mul(x, y) => Self::Mul(f(x), f(y)),
+			Self::Pow(x, schedule) => Self::Pow(f(x), schedule),
+			Self::Inv(x) => Self::Inv(f(x)),
+		}
+	}
+}
+
+/// The square-and-multiply schedule of an exponent.
+#[derive(Debug)]
+struct PowSchedule {
+	exp: u64,
+	/// Bits of the exponent from the most significant set bit down to the least significant one.
+	bits: Box<[bool]>,
+}
+
+impl PowSchedule {
+	fn new(exp: u64) -> Self {
+		let n_bits = u64::BITS - exp.leading_zeros();
+		let bits = (0..n_bits).rev().map(|i| (exp >> i) & 1 == 1).collect();
+		Self { exp, bits }
+	}
+
+	fn apply<P: PackedField>(&self, value: P) -> P {
+		let mut res = P::one();
+		for &bit in self.bits.iter() {
+			res = res.square();
+			if bit {
+				res.mul_assign(value)
+			}
+		}
+		res
+	}
 }
 
 /// Describes polynomial evaluations using a directed acyclic graph of expressions.
@@ -93,514 +186,4005 @@ enum CircuitStep<F: Field> {
 ///
 /// The advantage over a hard coded CompositionPolyOS is that this can be constructed and manipulated dynamically at runtime
 /// and the object representing different polnomials can be stored in a homogeneous collection.
+///
+/// The expression may contain [`ArithExpr::Inv`] nodes, in which case it is not a polynomial. Its
+/// degree is then reported as `usize::MAX`, see [`Self::is_polynomial`], and such circuits must not
+/// be used where a degree bound is needed, such as in sumcheck.
 #[derive(Debug, Clone)]
 pub struct ArithCircuitPoly<F: Field> {
 	expr: ArithExpr<F>,
 	steps: Arc<[CircuitStep<F>]>,
 	/// The "top level expression", which depends on circuit expression evaluations
 	retval: CircuitStepArgument<F>,
+	/// Exponent schedules referenced by the `Pow` steps, one per distinct exponent
+	pow_schedules: Arc<[PowSchedule]>,
 	degree: usize,
 	n_vars: usize,
 }
 
 impl<F: Field> ArithCircuitPoly<F> {
+	/// Constructs the circuit evaluating `expr`, after folding its constant subexpressions.
+	///
+	/// The number of variables is that of `expr` before folding, so that `x0 * 0` still has one
+	/// variable, while the degree is that of the folded expression.
 	pub fn new(expr: ArithExpr<F>) -> Self {
-		let degree = expr.degree();
 		let n_vars = expr.n_vars();
-		let (exprs, retval) = circuit_steps_for_expr(&expr);
+		let expr = fold_constants(expr);
+		let degree = compute_degree(std::slice::from_ref(&expr));
+		let (exprs, retval, pow_schedules) = circuit_steps_for_expr(&expr);
 
 		Self {
 			expr,
 			steps: exprs.into(),
 			retval,
+			pow_schedules: pow_schedules.into(),
 			degree,
 			n_vars,
 		}
 	}
 
+	/// Constructs an [`ArithCircuitPoly`] like [`Self::new`], with the duplicated subexpressions
+	/// merged by [`Self::common_subexpression_elimination`].
+	pub fn new_optimized(expr: ArithExpr<F>) -> Self {
+		Self::new(expr).common_subexpression_elimination()
+	}
+
 	/// Constructs an [`ArithCircuitPoly`] with the given number of variables.
 	///
 	/// The number of variables may be greater than the number of variables actually read in the
 	/// arithmetic expression.
 	pub fn with_n_vars(n_vars: usize, expr: ArithExpr<F>) -> Result<Self, Error> {
-		let degree = expr.degree();
 		if n_vars < expr.n_vars() {
 			return Err(Error::IncorrectNumberOfVariables {
 				expected: expr.n_vars(),
 				actual: n_vars,
 			});
 		}
-		let (exprs, retval) = circuit_steps_for_expr(&expr);
+		let expr = fold_constants(expr);
+		let degree = compute_degree(std::slice::from_ref(&expr));
+		let (exprs, retval, pow_schedules) = circuit_steps_for_expr(&expr);
 
 		Ok(Self {
 			expr,
 			steps: exprs.into(),
 			retval,
+			pow_schedules: pow_schedules.into(),
 			n_vars,
 			degree,
 		})
 	}
-}
 
-impl<F: TowerField> CompositionPoly<F> for ArithCircuitPoly<F> {
-	fn degree(&self) -> usize {
-		self.degree
+	/// Constructs the monomial $\prod_i x_i^{e_i}$ with the given exponents.
+	///
+	/// Variables with a zero exponent are left out of the product, so the monomial with all
+	/// exponents zero is the constant one. The circuit has `exponents.len()` variables.
+	pub fn monomial(exponents: &[u64]) -> Self {
+		let expr = exponents
+			.iter()
+			.enumerate()
+			.filter(|(_, &exp)| exp != 0)
+			.map(|(i, &exp)| match exp {
+				1 => ArithExpr::Var(i),
+				exp => ArithExpr::Var(i).pow(exp),
+			})
+			.product();
+		Self::with_n_vars(exponents.len(), expr)
+			.expect("the monomial reads no variable beyond the exponents")
 	}
 
-	fn n_vars(&self) -> usize {
-		self.n_vars
+	/// Constructs the polynomial $\sum_j c_j \prod_i x_i^{e_{j,i}}$ from its terms.
+	///
+	/// Each term is a pair of the exponents $e_j$ of a [`Self::monomial`] and its coefficient
+	/// $c_j$. Every term must have exactly `n_vars` exponents.
+	pub fn from_coefficients(n_vars: usize, terms: &[(Vec<u64>, F)]) -> Result<Self, Error> {
+		if let Some(index) = terms
+			.iter()
+			.position(|(exponents, _)| exponents.len() != n_vars)
+		{
+			return Err(Error::IncorrectArgumentLength {
+				arg: format!("terms[{index}]"),
+				expected: n_vars,
+			});
+		}
+
+		let expr = terms
+			.iter()
+			.map(|(exponents, coeff)| ArithExpr::Const(*coeff) * Self::monomial(exponents).expr)
+			.sum();
+		Self::with_n_vars(n_vars, expr)
 	}
+}
 
-	fn binary_tower_level(&self) -> usize {
-		F::TOWER_LEVEL
+impl<F: Field> ArithCircuitPoly<F> {
+	/// Returns the length of the longest dependency chain from any leaf to the top-level
+	/// expression.
+	///
+	/// Variables and constants have depth zero.
+	pub fn depth(&self) -> usize {
+		expr_depth(&self.expr)
 	}
 
-	fn expression<FE: ExtensionField<F>>(&self) -> ArithExpr<FE> {
-		self.expr.convert_field()
+	/// Returns the value of the circuit if it is constant after constant folding.
+	///
+	/// A constraint whose composition is constant does not depend on the witness, so callers can
+	/// check it once, or replace it with a boundary, instead of proving it.
+	pub fn as_constant(&self) -> Option<F> {
+		match fold_constants(self.expr.clone()) {
+			ArithExpr::Const(value) => Some(value),
+			_ => None,
+		}
 	}
 
-	fn evaluate<P: PackedField<Scalar: ExtensionField<F>>>(&self, query: &[P]) -> Result<P, Error> {
-		if query.len() != self.n_vars {
-			return Err(Error::IncorrectQuerySize {
-				expected: self.n_vars,
+	/// Splits the circuit into parts over pairwise disjoint sets of variables.
+	///
+	/// The operands of the chain of additions or multiplications at the root are grouped so that
+	/// operands sharing a variable end up in the same part, and each part is returned as a
+	/// circuit with the same number of variables as `self`, together with the operation that
+	/// combines the parts back into the circuit. Operands reading no variable are folded into the
+	/// first part. A circuit that does not split returns itself as the only part.
+	pub fn connected_components(&self) -> (CombineOp, Vec<Self>) {
+		fn flatten<'a, F: Field>(
+			expr: &'a ArithExpr<F>,
+			is_mul: bool,
+			operands: &mut Vec<&'a ArithExpr<F>>,
+		) {
+			match (expr, is_mul) {
+				(ArithExpr::Mul(left, right), true) | (ArithExpr::Add(left, right), false) => {
+					flatten(left, is_mul, operands);
+					flatten(right, is_mul, operands);
+				}
+				_ => operands.push(expr),
+			}
+		}
+
+		let is_mul = match &self.expr {
+			ArithExpr::Mul(..) => true,
+			ArithExpr::Add(..) => false,
+			_ => return (CombineOp::Sum, vec![self.clone()]),
+		};
+		let op = if is_mul {
+			CombineOp::Product
+		} else {
+			CombineOp::Sum
+		};
+		let mut operands = Vec::new();
+		flatten(&self.expr, is_mul, &mut operands);
+
+		// Each part is the union of the variables read by its operands, with the operands in
+		// their original order.
+		let mut parts = Vec::<(Vec<bool>, Vec<usize>)>::new();
+		let mut constants = Vec::new();
+		for (index, operand) in operands.iter().enumerate() {
+			let mut usage = operand.vars_usage();
+			usage.resize(self.n_vars, false);
+			if !usage.contains(&true) {
+				constants.push(index);
+				continue;
+			}
+
+			let mut part = (usage, vec![index]);
+			parts.retain(|(other_usage, other_operands)| {
+				let shares_var = other_usage.iter().zip(&part.0).any(|(&a, &b)| a && b);
+				if shares_var {
+					part.0
+						.iter_mut()
+						.zip(other_usage)
+						.for_each(|(a, &b)| *a |= b);
+					part.1.extend(other_operands);
+				}
+				!shares_var
 			});
+			parts.push(part);
+		}
+		if parts.len() <= 1 {
+			return (op, vec![self.clone()]);
 		}
 
-		// `stackalloc_uninit` throws a debug assert if `size` is 0, so set minimum of 1.
-		stackalloc_uninit::<P, _, _>(self.steps.len().max(1), |evals| {
-			let get_argument_value = |input: CircuitStepArgument<F>, evals: &[P]| match input {
-				// Safety: The index is guaranteed to be within bounds by the construction of the circuit
-				CircuitStepArgument::Expr(CircuitNode::Var(index)) => unsafe {
-					*query.get_unchecked(index)
-				},
-				// Safety: The index is guaranteed to be within bounds by the circuit evaluation order
-				CircuitStepArgument::Expr(CircuitNode::Slot(slot)) => unsafe {
-					*evals.get_unchecked(slot)
-				},
-				CircuitStepArgument::Const(value) => P::broadcast(value.into()),
-			};
+		parts.sort_by_key(|(_, indices)| indices.iter().copied().min());
+		parts[0].1.extend(constants);
+		let components = parts
+			.into_iter()
+			.map(|(_, mut indices)| {
+				indices.sort_unstable();
+				let expr = indices
+					.into_iter()
+					.map(|index| operands[index].clone())
+					.reduce(|acc, operand| if is_mul { acc * operand } else { acc + operand })
+					.expect("every part has at least one operand");
+				Self::with_n_vars(self.n_vars, expr)
+					.expect("the part reads a subset of the variables of the circuit")
+			})
+			.collect();
+		(op, components)
+	}
 
-			for (i, expr) in self.steps.iter().enumerate() {
-				// Safety: previous evaluations are initialized by the previous loop iterations
-				let (before, after) = unsafe { evals.split_at_mut_unchecked(i) };
-				let before = unsafe { slice_assume_init(before) };
-				let new_val = match expr {
-					CircuitStep::Add(x, y) => {
-						get_argument_value(*x, before) + get_argument_value(*y, before)
-					}
-					CircuitStep::Mul(x, y) => {
-						get_argument_value(*x, before) * get_argument_value(*y, before)
-					}
-					CircuitStep::Pow(id, exp) => pow(get_argument_value(*id, before), *exp),
-				};
+	/// Whether the circuit computes a polynomial, that is, it has no inverse of a non-constant
+	/// expression. Otherwise its degree is `usize::MAX`.
+	pub fn is_polynomial(&self) -> bool {
+		self.degree != usize::MAX
+	}
 
-				// Safety: `evals.len()` == `self.exprs.len()`, so `after` is guaranteed to have at least one element
-				unsafe {
-					after.get_unchecked_mut(0).write(new_val);
+	/// Returns, for each variable, an upper bound on the degree of the polynomial in it.
+	///
+	/// The degrees are propagated through the circuit steps with the rules of
+	/// [`ArithExpr::degree_by`], the same way [`CompositionPoly::degree`] bounds the total degree.
+	pub fn degrees_by_var(&self) -> Vec<usize> {
+		(0..self.n_vars)
+			.map(|var| self.degree_by(|index| (index == var) as usize))
+			.collect()
+	}
+
+	/// The degree of the circuit when the variable with index `i` has degree `var_degree(i)`,
+	/// propagated through the circuit steps like [`ArithExpr::degree_by`].
+	fn degree_by(&self, var_degree: impl Fn(usize) -> usize) -> usize {
+		let mut step_degrees = Vec::with_capacity(self.steps.len());
+		let argument_degree =
+			|argument: &CircuitStepArgument<F>, step_degrees: &[usize]| match argument {
+				CircuitStepArgument::Const(_) => 0,
+				CircuitStepArgument::Expr(CircuitNode::Var(index)) => var_degree(*index),
+				CircuitStepArgument::Expr(CircuitNode::Slot(slot)) => step_degrees[*slot],
+			};
+
+		for step in self.steps.iter() {
+			let degree = |argument| argument_degree(argument, &step_degrees);
+			let step_degree = match step {
+				CircuitStep::Add(x, y) | CircuitStep::Sub(x, y) => {
+					ArithExpr::<F>::add_degree(degree(x), degree(y))
 				}
-			}
+				CircuitStep::Mul(x, y) => ArithExpr::<F>::mul_degree(degree(x), degree(y)),
+				CircuitStep::Pow(x, schedule) => {
+					ArithExpr::<F>::pow_degree(degree(x), self.pow_schedules[*schedule].exp)
+				}
+				CircuitStep::Inv(x) => ArithExpr::<F>::inv_degree(degree(x)),
+			};
+			step_degrees.push(step_degree);
+		}
 
-			// Safety: `evals.len()` == `self.exprs.len()`, and all expression evaluations have
-			// been initialized
-			unsafe {
-				let evals = slice_assume_init(evals);
-				Ok(get_argument_value(self.retval, evals))
-			}
-		})
+		argument_degree(&self.retval, &step_degrees)
 	}
 
-	fn batch_evaluate<P: PackedField<Scalar: ExtensionField<F>>>(
-		&self,
-		batch_query: &[&[P]],
-		evals: &mut [P],
-	) -> Result<(), Error> {
-		let row_len = evals.len();
-		if batch_query.iter().any(|row| row.len() != row_len) {
-			return Err(Error::BatchEvaluateSizeMismatch);
+	/// Rewrites chains of additions and multiplications into balanced trees.
+	///
+	/// Both operations are associative, so the rewritten circuit evaluates to the same values,
+	/// but its depth is logarithmic rather than linear in the chain length. Shorter dependency
+	/// chains expose more instruction-level parallelism during evaluation.
+	pub fn rebalance(self) -> Self {
+		let expr = rebalance_expr(self.expr);
+		let (steps, retval, pow_schedules) = circuit_steps_for_expr(&expr);
+
+		Self {
+			expr,
+			steps: steps.into(),
+			retval,
+			pow_schedules: pow_schedules.into(),
+			degree: self.degree,
+			n_vars: self.n_vars,
 		}
+	}
 
-		// `stackalloc_uninit` throws a debug assert if `size` is 0, so set minimum of 1.
-		stackalloc_uninit::<P, (), _>((self.steps.len() * row_len).max(1), |sparse_evals| {
-			for (i, expr) in self.steps.iter().enumerate() {
-				let (before, current) = sparse_evals.split_at_mut(i * row_len);
+	/// Returns the formal partial derivative of the polynomial with respect to the variable `var`.
+	///
+	/// The field has characteristic 2, so the derivative of $X^n$ is $X^{n-1}$ for odd $n$ and
+	/// zero for even $n$. The derivative has the same number of variables as the polynomial.
+	pub fn derivative(&self, var: usize) -> Self {
+		let expr = derivative_expr(&self.expr, var).unwrap_or_else(ArithExpr::zero);
+		Self::with_n_vars(self.n_vars, expr)
+			.expect("the derivative does not have more variables than the polynomial")
+	}
 
-				// Safety: `before` is guaranteed to be initialized by the previous loop iterations.
-				let before = unsafe { slice_assume_init(before) };
-				let current = &mut current[..row_len];
+	/// Reduces the powers of the variables in `boolean_vars` to degree one.
+	///
+	/// The caller asserts that these variables only take the values 0 and 1, for which
+	/// $x^n = x$ for every $n \geq 1$. Both `x^n` and `x * x` are rewritten to `x`. The result
+	/// agrees with the polynomial on all such points, but not elsewhere. Variable indices that are
+	/// out of range are ignored.
+	pub fn simplify_boolean(&self, boolean_vars: &[usize]) -> Self {
+		let mut is_boolean = vec![false; self.n_vars];
+		for &var in boolean_vars.iter().filter(|&&var| var < self.n_vars) {
+			is_boolean[var] = true;
+		}
+		let expr = simplify_boolean_expr(self.expr.clone(), &is_boolean);
+		Self::with_n_vars(self.n_vars, expr)
+			.expect("the simplification does not add variables to the polynomial")
+	}
 
-				match expr {
-					CircuitStep::Add(left, right) => {
-						apply_binary_op(
-							left,
-							right,
-							batch_query,
-							before,
-							current,
-							|left, right, out| {
-								out.write(left + right);
-							},
-						);
-					}
-					CircuitStep::Mul(left, right) => {
-						apply_binary_op(
-							left,
-							right,
-							batch_query,
-							before,
-							current,
-							|left, right, out| {
-								out.write(left * right);
-							},
-						);
+	/// Drops the circuit steps that the top-level expression does not depend on.
+	///
+	/// The remaining steps keep their relative order and are renumbered, so the circuit evaluates
+	/// to the same values with less scratch space.
+	pub fn dead_code_elimination(self) -> Self {
+		let mut reachable = vec![false; self.steps.len()];
+		let mark = |arg: &CircuitStepArgument<F>, reachable: &mut [bool]| {
+			if let CircuitStepArgument::Expr(CircuitNode::Slot(slot)) = arg {
+				reachable[*slot] = true;
+			}
+		};
+		mark(&self.retval, &mut reachable);
+		// Steps only refer to earlier steps, so a reverse scan visits every user before its inputs.
+		for (i, step) in self.steps.iter().enumerate().rev() {
+			if reachable[i] {
+				match step {
+					CircuitStep::Add(x, y) | CircuitStep::Sub(x, y) | CircuitStep::Mul(x, y) => {
+						mark(x, &mut reachable);
+						mark(y, &mut reachable);
 					}
-					CircuitStep::Pow(id, exp) => match id {
-						CircuitStepArgument::Expr(id) => {
-							let id = id.get_sparse_chunk(batch_query, before, row_len);
-							for j in 0..row_len {
-								// Safety: `current` and `id` have length equal to `row_len`
-								unsafe {
-									current
-										.get_unchecked_mut(j)
-										.write(pow(*id.get_unchecked(j), *exp));
-								}
-							}
-						}
-						CircuitStepArgument::Const(id) => {
-							let id: P = P::broadcast((*id).into());
-							let result = pow(id, *exp);
-							for j in 0..row_len {
-								// Safety: `current` has length equal to `row_len`
-								unsafe {
-									current.get_unchecked_mut(j).write(result);
-								}
-							}
-						}
-					},
+					CircuitStep::Pow(x, _) | CircuitStep::Inv(x) => mark(x, &mut reachable),
 				}
 			}
+		}
 
-			match self.retval {
-				CircuitStepArgument::Expr(node) => {
-					// Safety: `sparse_evals` is fully initialized by the previous loop iterations
-					let sparse_evals = unsafe { slice_assume_init(sparse_evals) };
-					evals.copy_from_slice(node.get_sparse_chunk(batch_query, sparse_evals, row_len))
-				}
-				CircuitStepArgument::Const(val) => evals.fill(P::broadcast(val.into())),
+		let mut new_slots = vec![0; self.steps.len()];
+		let mut steps = Vec::new();
+		let remap = |arg: CircuitStepArgument<F>, new_slots: &[usize]| match arg {
+			CircuitStepArgument::Expr(CircuitNode::Slot(slot)) => {
+				CircuitStepArgument::Expr(CircuitNode::Slot(new_slots[slot]))
 			}
-		});
+			arg => arg,
+		};
+		for (i, step) in self.steps.iter().enumerate() {
+			if !reachable[i] {
+				continue;
+			}
+			new_slots[i] = steps.len();
+			steps.push(step.map_arguments(|arg| remap(arg, &new_slots)));
+		}
 
-		Ok(())
+		Self {
+			retval: remap(self.retval, &new_slots),
+			steps: steps.into(),
+			..self
+		}
 	}
-}
 
-impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPolyOS<P>
-	for ArithCircuitPoly<F>
-{
-	fn degree(&self) -> usize {
-		CompositionPoly::degree(self)
-	}
+	/// Merges the circuit steps that compute structurally identical subexpressions.
+	///
+	/// Each step is looked up by its operation and its already deduplicated arguments, so a
+	/// repeated subtree, such as the same power of a variable used in several products, is computed
+	/// once and all its uses refer to the first occurrence. Commuted operands are not recognized as
+	/// identical. The circuit evaluates to the same values with fewer steps and less scratch space.
+	pub fn common_subexpression_elimination(self) -> Self {
+		let mut new_slots = vec![0; self.steps.len()];
+		let mut steps = Vec::new();
+		let mut first_occurrences = HashMap::new();
+		let remap = |arg: CircuitStepArgument<F>, new_slots: &[usize]| match arg {
+			CircuitStepArgument::Expr(CircuitNode::Slot(slot)) => {
+				CircuitStepArgument::Expr(CircuitNode::Slot(new_slots[slot]))
+			}
+			arg => arg,
+		};
+		for (i, step) in self.steps.iter().enumerate() {
+			let step = step.map_arguments(|arg| remap(arg, &new_slots));
+			new_slots[i] = *first_occurrences.entry(step).or_insert_with(|| {
+				steps.push(step);
+				steps.len() - 1
+			});
+		}
 
-	fn n_vars(&self) -> usize {
-		CompositionPoly::n_vars(self)
+		Self {
+			retval: remap(self.retval, &new_slots),
+			steps: steps.into(),
+			..self
+		}
+		.dead_code_elimination()
 	}
 
-	fn expression(&self) -> ArithExpr<P::Scalar> {
-		self.expr.convert_field()
-	}
+	/// Returns the circuit $g|_{X_{var} = 0} + g|_{X_{var} = 1}$, summing the polynomial over the
+	/// boolean values of the variable `var`.
+	///
+	/// The variables following `var` are renumbered down by one, so the result has one variable
+	/// less than the polynomial.
+	pub fn sum_over_var(&self, var: usize) -> Result<Self, Error> {
+		if var >= self.n_vars {
+			return Err(Error::ArgumentRangeError {
+				arg: "var".into(),
+				range: 0..self.n_vars,
+			});
+		}
 
-	fn binary_tower_level(&self) -> usize {
-		CompositionPoly::binary_tower_level(self)
+		let sum =
+			bind_expr(self.expr.clone(), var, F::ZERO) + bind_expr(self.expr.clone(), var, F::ONE);
+		// `var` no longer occurs in the sum, so its entry in the remapping is irrelevant.
+		let indices = (0..self.n_vars)
+			.map(|i| if i > var { i - 1 } else { i })
+			.collect::<Vec<_>>();
+		Self::with_n_vars(self.n_vars - 1, sum.remap_vars(&indices)?)
 	}
 
-	fn evaluate(&self, query: &[P]) -> Result<P, Error> {
-		CompositionPoly::evaluate(self, query)
-	}
+	/// Returns the circuit with every variable $x_i$ replaced by $x_{mapping\[i\]}$.
+	///
+	/// This lets a circuit built against one variable order be evaluated against another. The
+	/// number of variables of the result is one more than the largest index that the variables of
+	/// the circuit are mapped to.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::IncorrectArgumentLength`] if `mapping` is shorter than the number of variables
+	pub fn remap_variables(&self, mapping: &[usize]) -> Result<Self, Error> {
+		if mapping.len() < self.n_vars {
+			return Err(Error::IncorrectArgumentLength {
+				arg: "mapping".into(),
+				expected: self.n_vars,
+			});
+		}
 
-	fn batch_evaluate(&self, batch_query: &[&[P]], evals: &mut [P]) -> Result<(), Error> {
-		CompositionPoly::batch_evaluate(self, batch_query, evals)
+		let n_vars = mapping[..self.n_vars]
+			.iter()
+			.max()
+			.map_or(0, |&max_index| max_index + 1);
+		Self::with_n_vars(n_vars, self.expr.clone().remap_vars(mapping)?)
 	}
 }
 
-impl<F: TowerField> MultivariatePoly<F> for ArithCircuitPoly<F> {
-	fn degree(&self) -> usize {
-		CompositionPoly::degree(&self)
+impl<F: TowerField> ArithCircuitPoly<F> {
+	/// Evaluates the polynomial with some of its variables bound to the outputs of other circuits.
+	///
+	/// Each binding `(var, circuit)` evaluates `circuit` on `base_query` and substitutes the result
+	/// for variable `var` of `self`, whose other variables take their values from `base_query`. If
+	/// a variable is bound more than once, the last binding is used.
+	pub fn evaluate_with_bindings<P>(
+		&self,
+		base_query: &[P],
+		bindings: &[(usize, &ArithCircuitPoly<F>)],
+	) -> Result<P, Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		if base_query.len() != self.n_vars {
+			return Err(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+
+		let mut query = base_query.to_vec();
+		for &(var, circuit) in bindings {
+			if var >= self.n_vars {
+				return Err(Error::ArgumentRangeError {
+					arg: "bindings".into(),
+					range: 0..self.n_vars,
+				});
+			}
+			query[var] = CompositionPoly::evaluate(circuit, base_query)?;
+		}
+
+		CompositionPoly::evaluate(self, &query)
 	}
 
-	fn n_vars(&self) -> usize {
-		CompositionPoly::n_vars(&self)
+	/// Evaluates the polynomial on `query` and adds the result into `acc`.
+	///
+	/// This is equivalent to `*acc += self.evaluate(query)?` and is meant for tight loops that
+	/// accumulate many evaluations into a single packed value.
+	pub fn evaluate_accumulate<P>(&self, query: &[P], acc: &mut P) -> Result<(), Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		*acc += CompositionPoly::evaluate(self, query)?;
+		Ok(())
 	}
 
-	fn binary_tower_level(&self) -> usize {
-		CompositionPoly::binary_tower_level(&self)
+	/// Evaluates the circuit at `query`, using `evals` as scratch space for the step results.
+	///
+	/// `query` must have length `n_vars` and `evals` must have room for at least one value per
+	/// step.
+	fn evaluate_with_scratch<P>(&self, query: &[P], evals: &mut [MaybeUninit<P>]) -> P
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		debug_assert_eq!(query.len(), self.n_vars);
+		debug_assert!(evals.len() >= self.steps.len());
+
+		let get_argument_value = |input: CircuitStepArgument<F>, evals: &[P]| match input {
+			// Safety: The index is guaranteed to be within bounds by the construction of the circuit
+			CircuitStepArgument::Expr(CircuitNode::Var(index)) => unsafe {
+				*query.get_unchecked(index)
+			},
+			// Safety: The index is guaranteed to be within bounds by the circuit evaluation order
+			CircuitStepArgument::Expr(CircuitNode::Slot(slot)) => unsafe {
+				*evals.get_unchecked(slot)
+			},
+			CircuitStepArgument::Const(value) => P::broadcast(value.into()),
+		};
+
+		for (i, expr) in self.steps.iter().enumerate() {
+			// Safety: previous evaluations are initialized by the previous loop iterations
+			let (before, after) = unsafe { evals.split_at_mut_unchecked(i) };
+			let before = unsafe { slice_assume_init(before) };
+			let new_val = match expr {
+				CircuitStep::Add(x, y) => {
+					get_argument_value(*x, before) + get_argument_value(*y, before)
+				}
+				CircuitStep::Sub(x, y) => {
+					get_argument_value(*x, before) - get_argument_value(*y, before)
+				}
+				CircuitStep::Mul(x, y) => {
+					get_argument_value(*x, before) * get_argument_value(*y, before)
+				}
+				CircuitStep::Pow(id, schedule) => {
+					self.pow_schedules[*schedule].apply(get_argument_value(*id, before))
+				}
+				CircuitStep::Inv(id) => get_argument_value(*id, before).invert_or_zero(),
+			};
+
+			// Safety: `evals.len()` >= `self.steps.len()`, so `after` is guaranteed to have at least one element
+			unsafe {
+				after.get_unchecked_mut(0).write(new_val);
+			}
+		}
+
+		// Safety: all the step evaluations have been initialized
+		unsafe {
+			let evals = slice_assume_init(&evals[..self.steps.len()]);
+			get_argument_value(self.retval, evals)
+		}
 	}
 
-	fn evaluate(&self, query: &[F]) -> Result<F, super::Error> {
-		CompositionPoly::evaluate(&self, query).map_err(|e| e.into())
+	/// Evaluates the circuit at each of the independent `queries`, writing the results to `out`.
+	///
+	/// The queries are evaluated in parallel, and each thread reuses its own scratch space for
+	/// the step results across the queries it evaluates. Unlike
+	/// [`CompositionPoly::batch_evaluate`], which takes one column per variable, each element of
+	/// `queries` is a full query of length `n_vars`.
+	pub fn evaluate_batch<P>(&self, queries: &[&[P]], out: &mut [P]) -> Result<(), Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		if queries.len() != out.len() {
+			bail!(Error::BatchEvaluateSizeMismatch);
+		}
+		if queries.iter().any(|query| query.len() != self.n_vars) {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+
+		let scratch = ThreadLocalMut::new();
+		queries
+			.par_iter()
+			.zip(out.par_iter_mut())
+			.for_each(|(query, out)| {
+				scratch.with_mut(
+					|| alloc_scratch(self.steps.len()),
+					|evals| *out = self.evaluate_with_scratch(query, evals),
+				)
+			});
+		Ok(())
 	}
-}
 
-/// Apply a binary operation to two arguments and store the result in `current_evals`.
-/// `op` must be a function that takes two arguments and initialized the result with the third argument.
-fn apply_binary_op<F: Field, P: PackedField<Scalar: ExtensionField<F>>>(
-	left: &CircuitStepArgument<F>,
-	right: &CircuitStepArgument<F>,
-	batch_query: &[&[P]],
-	evals_before: &[P],
-	current_evals: &mut [MaybeUninit<P>],
-	op: impl Fn(P, P, &mut MaybeUninit<P>),
-) {
-	let row_len = current_evals.len();
+	/// Evaluates the polynomial at a scalar point through the packed evaluation path over `P`.
+	///
+	/// Every coordinate of the query is broadcast to `P`, and lane 0 of the result is returned.
+	pub fn evaluate_scalar_at<P>(&self, query_scalars: &[P::Scalar]) -> Result<P::Scalar, Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		let query = query_scalars
+			.iter()
+			.map(|&scalar| P::broadcast(scalar))
+			.collect::<Vec<_>>();
+		Ok(CompositionPoly::evaluate(self, &query)?.get(0))
+	}
 
-	match (left, right) {
-		(CircuitStepArgument::Expr(left), CircuitStepArgument::Expr(right)) => {
-			let left = left.get_sparse_chunk(batch_query, evals_before, row_len);
-			let right = right.get_sparse_chunk(batch_query, evals_before, row_len);
-			for j in 0..row_len {
-				// Safety: `current`, `left` and `right` have length equal to `row_len`
-				unsafe {
-					op(
-						*left.get_unchecked(j),
-						*right.get_unchecked(j),
-						current_evals.get_unchecked_mut(j),
-					)
-				}
+	/// Evaluates a univariate circuit at every point of `domain`, in order.
+	///
+	/// This produces the value vector of the composition over an evaluation domain, such as the
+	/// one of an additive NTT.
+	///
+	/// ## Throws
+	///
+	/// * [`Error::IncorrectNumberOfVariables`] if the circuit does not have exactly one variable
+	pub fn evaluate_over_domain(&self, domain: &[F]) -> Result<Vec<F>, Error> {
+		if self.n_vars != 1 {
+			bail!(Error::IncorrectNumberOfVariables {
+				expected: 1,
+				actual: self.n_vars,
+			});
+		}
+
+		domain
+			.iter()
+			.map(|&point| CompositionPoly::evaluate(self, &[point]))
+			.collect()
+	}
+
+	/// Evaluates the circuit row by row over a layer whose inputs are split in two halves.
+	///
+	/// The first half of the variables is read from `left` and the second half from `right`, both
+	/// holding `n_vars / 2` values per row in row-major order, and the evaluation of each row is
+	/// written to `out`. The number of variables must be even.
+	pub fn evaluate_layer<P>(&self, left: &[P], right: &[P], out: &mut [P]) -> Result<(), Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		if self.n_vars % 2 != 0 {
+			bail!(Error::OddNumberOfVariables {
+				n_vars: self.n_vars,
+			});
+		}
+		let half = self.n_vars / 2;
+		for (arg, len) in [("left", left.len()), ("right", right.len())] {
+			if len != out.len() * half {
+				bail!(Error::IncorrectArgumentLength {
+					arg: arg.to_string(),
+					expected: out.len() * half,
+				});
 			}
 		}
-		(CircuitStepArgument::Expr(left), CircuitStepArgument::Const(right)) => {
-			let left = left.get_sparse_chunk(batch_query, evals_before, row_len);
-			let right = P::broadcast((*right).into());
-			for j in 0..row_len {
-				// Safety: `current` and `left` have length equal to `row_len`
-				unsafe {
-					op(*left.get_unchecked(j), right, current_evals.get_unchecked_mut(j));
-				}
+
+		let mut query = Vec::with_capacity(self.n_vars);
+		for (row, out) in out.iter_mut().enumerate() {
+			query.clear();
+			query.extend_from_slice(&left[row * half..(row + 1) * half]);
+			query.extend_from_slice(&right[row * half..(row + 1) * half]);
+			*out = CompositionPoly::evaluate(self, &query)?;
+		}
+		Ok(())
+	}
+
+	/// Evaluates the polynomial at a packed query whose variables are given in their own fields.
+	///
+	/// Each variable is embedded from its field into the scalar field of `P` lane by lane, which
+	/// avoids embedding whole columns into the evaluation field beforehand. Every variable must
+	/// provide `P::WIDTH` values and have a tower level at most that of `P::Scalar`.
+	pub fn evaluate_mixed<P>(&self, query: &[MixedQuery]) -> Result<P, Error>
+	where
+		P: PackedField<Scalar: TowerField<Canonical: TryFrom<BinaryField128b>> + ExtensionField<F>>,
+	{
+		if query.len() != self.n_vars {
+			bail!(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+
+		let query = query
+			.iter()
+			.enumerate()
+			.map(|(index, value)| value.embed::<P>(index))
+			.collect::<Result<Vec<_>, _>>()?;
+		CompositionPoly::evaluate(self, &query)
+	}
+
+	/// Evaluates the polynomial over columns stored in lane-major order.
+	///
+	/// Each column holds the `out.len() * P::WIDTH` hypercube values of a variable as `n_lanes`
+	/// contiguous lanes, so that the value of row `r` is at index
+	/// `(r % n_lanes) * (len / n_lanes) + r / n_lanes`. The columns are transposed into packed
+	/// queries row by row, and the evaluations are written to `out` in hypercube order.
+	pub fn evaluate_lane_major<P>(
+		&self,
+		columns: &[&[P::Scalar]],
+		n_lanes: usize,
+		out: &mut [P],
+	) -> Result<(), Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		if columns.len() != self.n_vars {
+			return Err(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+		let len = out.len() * P::WIDTH;
+		if n_lanes == 0 || len % n_lanes != 0 || columns.iter().any(|column| column.len() != len) {
+			return Err(Error::BatchEvaluateSizeMismatch);
+		}
+		let lane_len = len / n_lanes;
+
+		let mut query = vec![P::zero(); self.n_vars];
+		for (i, out) in out.iter_mut().enumerate() {
+			for (query, column) in query.iter_mut().zip(columns) {
+				*query = P::from_fn(|j| {
+					let row = i * P::WIDTH + j;
+					column[(row % n_lanes) * lane_len + row / n_lanes]
+				});
 			}
+			*out = CompositionPoly::evaluate(self, &query)?;
 		}
-		(CircuitStepArgument::Const(left), CircuitStepArgument::Expr(right)) => {
-			let left = P::broadcast((*left).into());
-			let right = right.get_sparse_chunk(batch_query, evals_before, row_len);
-			for j in 0..row_len {
-				// Safety: `current` and `right` have length equal to `row_len`
-				unsafe {
-					op(left, *right.get_unchecked(j), current_evals.get_unchecked_mut(j));
+		Ok(())
+	}
+
+	/// Evaluates the polynomial at infinity in the variable `free_var`, with all other variables
+	/// fixed to the values in `fixed`.
+	///
+	/// The evaluation at infinity of a univariate polynomial of degree at most $d$ is its
+	/// coefficient of $X^d$, where $d$ is the degree of the expression in the free variable. The
+	/// coefficient is propagated through the circuit steps along with the degree, so it does not
+	/// depend on the size of the field and avoids a separate interpolation step in sumcheck
+	/// provers.
+	pub fn evaluate_at_infinity<P>(&self, free_var: usize, fixed: &[P]) -> Result<P, Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		if free_var >= self.n_vars {
+			return Err(Error::ArgumentRangeError {
+				arg: "free_var".into(),
+				range: 0..self.n_vars,
+			});
+		}
+		if fixed.len() + 1 != self.n_vars {
+			return Err(Error::IncorrectQuerySize {
+				expected: self.n_vars - 1,
+			});
+		}
+
+		// The degree in the free variable and the coefficient of that power of each step.
+		let mut step_terms = Vec::<(usize, P)>::with_capacity(self.steps.len());
+		let argument_term =
+			|argument: &CircuitStepArgument<F>, step_terms: &[(usize, P)]| match argument {
+				CircuitStepArgument::Const(value) => (0, P::broadcast((*value).into())),
+				CircuitStepArgument::Expr(CircuitNode::Var(index)) => match index.cmp(&free_var) {
+					Ordering::Less => (0, fixed[*index]),
+					Ordering::Equal => (1, P::one()),
+					Ordering::Greater => (0, fixed[*index - 1]),
+				},
+				CircuitStepArgument::Expr(CircuitNode::Slot(slot)) => step_terms[*slot],
+			};
+
+		for step in self.steps.iter() {
+			let term = |argument| argument_term(argument, &step_terms);
+			let step_term = match step {
+				CircuitStep::Add(x, y) | CircuitStep::Sub(x, y) => {
+					let (x_degree, x_coeff) = term(x);
+					let (y_degree, mut y_coeff) = term(y);
+					if matches!(step, CircuitStep::Sub(..)) {
+						y_coeff = P::zero() - y_coeff;
+					}
+					let coeff = match x_degree.cmp(&y_degree) {
+						Ordering::Greater => x_coeff,
+						Ordering::Less => y_coeff,
+						Ordering::Equal => x_coeff + y_coeff,
+					};
+					(ArithExpr::<F>::add_degree(x_degree, y_degree), coeff)
 				}
-			}
+				CircuitStep::Mul(x, y) => {
+					let ((x_degree, x_coeff), (y_degree, y_coeff)) = (term(x), term(y));
+					(ArithExpr::<F>::mul_degree(x_degree, y_degree), x_coeff * y_coeff)
+				}
+				CircuitStep::Pow(x, schedule) => {
+					let (degree, coeff) = term(x);
+					let exp = self.pow_schedules[*schedule].exp;
+					(ArithExpr::<F>::pow_degree(degree, exp), coeff.pow_u64(exp))
+				}
+				CircuitStep::Inv(x) => {
+					let (degree, coeff) = term(x);
+					if degree != 0 {
+						return Err(Error::NotPolynomial);
+					}
+					(0, coeff.invert_or_zero())
+				}
+			};
+			step_terms.push(step_term);
 		}
-		(CircuitStepArgument::Const(left), CircuitStepArgument::Const(right)) => {
-			let left = P::broadcast((*left).into());
-			let right = P::broadcast((*right).into());
-			let mut result = MaybeUninit::uninit();
-			op(left, right, &mut result);
-			for j in 0..row_len {
-				// Safety:
-				// - `current` has length equal to `row_len`
-				// - `result` is initialized by `op`
-				unsafe {
-					current_evals
-						.get_unchecked_mut(j)
-						.write(result.assume_init());
+
+		Ok(argument_term(&self.retval, &step_terms).1)
+	}
+
+	/// Evaluates the circuit after checking that its degree in `var` is at most `max_degree`.
+	///
+	/// The degree is the static bound computed by [`Self::degrees_by_var`], so the check costs
+	/// nothing per evaluation beyond that pass and guards provers against compositions whose
+	/// claimed degree is too small.
+	pub fn evaluate_checked_degree<P>(
+		&self,
+		query: &[P],
+		var: usize,
+		max_degree: usize,
+	) -> Result<P, Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		if var >= self.n_vars {
+			return Err(Error::ArgumentRangeError {
+				arg: "var".into(),
+				range: 0..self.n_vars,
+			});
+		}
+		let degree = self.degrees_by_var()[var];
+		if degree > max_degree {
+			return Err(Error::DegreeBoundExceeded {
+				var,
+				degree,
+				max_degree,
+			});
+		}
+		CompositionPoly::evaluate(self, query)
+	}
+
+	/// Returns, for each variable, how much the evaluation changes when that variable is zeroed.
+	///
+	/// The entry for variable `i` is `g(query) - g(query')`, where `query'` is `query` with the
+	/// `i`-th element replaced by zero. This is a debugging heuristic that shows which inputs
+	/// affect the output at this point, not a derivative.
+	pub fn sensitivity<P>(&self, query: &[P]) -> Result<Vec<P>, Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		if query.len() != self.n_vars {
+			return Err(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+
+		let eval = CompositionPoly::evaluate(self, query)?;
+		let mut zeroed = query.to_vec();
+		(0..self.n_vars)
+			.map(|var| {
+				zeroed[var] = P::zero();
+				let result = CompositionPoly::evaluate(self, &zeroed);
+				zeroed[var] = query[var];
+				Ok(eval - result?)
+			})
+			.collect()
+	}
+
+	/// Checks whether the polynomial evaluates to zero at every point of the boolean hypercube.
+	///
+	/// The check is exact for circuits with at most [`MAX_EXHAUSTIVE_CUBE_VARS`] variables. For
+	/// larger circuits the hypercube is too big to enumerate, so the polynomial is instead evaluated
+	/// at a fixed number of pseudo-random hypercube points, and a `true` result only means that no
+	/// non-zero evaluation was found.
+	pub fn is_zero_on_cube(&self) -> bool {
+		let is_zero_at = |point: &[F]| {
+			CompositionPoly::evaluate(self, point).expect("point has length n_vars") == F::ZERO
+		};
+
+		if self.n_vars <= MAX_EXHAUSTIVE_CUBE_VARS {
+			return (0..1 << self.n_vars)
+				.all(|index| is_zero_at(&decompose_index_to_hypercube_point(self.n_vars, index)));
+		}
+
+		tracing::warn!(
+			n_vars = self.n_vars,
+			"checking that the circuit is zero on the hypercube probabilistically"
+		);
+		let mut rng = StdRng::from_seed([0; 32]);
+		(0..N_CUBE_PROBES).all(|_| {
+			let point = (0..self.n_vars)
+				.map(|_| if rng.gen() { F::ONE } else { F::ZERO })
+				.collect::<Vec<_>>();
+			is_zero_at(&point)
+		})
+	}
+
+	/// Checks whether the polynomial is invariant under any permutation of its variables.
+	///
+	/// The transpositions of variable 0 with each other variable generate all permutations, so
+	/// only those are checked. For circuits with at most [`MAX_EXHAUSTIVE_CUBE_VARS`] variables
+	/// the check is exact over the boolean hypercube. In addition, the polynomial is evaluated at a
+	/// fixed number of pseudo-random points of `F` and at their transposed images, so a `true`
+	/// result is probabilistic: by the Schwartz-Zippel lemma each probe misses a difference with
+	/// probability at most `degree / |F|`.
+	pub fn is_symmetric(&self) -> bool {
+		let evaluate =
+			|point: &[F]| CompositionPoly::evaluate(self, point).expect("point has length n_vars");
+		let is_symmetric_at = |point: &[F]| {
+			let eval = evaluate(point);
+			(1..self.n_vars).all(|var| {
+				let mut swapped = point.to_vec();
+				swapped.swap(0, var);
+				evaluate(&swapped) == eval
+			})
+		};
+
+		if self.n_vars <= MAX_EXHAUSTIVE_CUBE_VARS
+			&& !(0..1 << self.n_vars).all(|index| {
+				is_symmetric_at(&decompose_index_to_hypercube_point(self.n_vars, index))
+			}) {
+			return false;
+		}
+
+		let mut rng = StdRng::from_seed([0; 32]);
+		(0..N_SYMMETRY_PROBES).all(|_| {
+			let point = repeat_with(|| F::random(&mut rng))
+				.take(self.n_vars)
+				.collect::<Vec<_>>();
+			is_symmetric_at(&point)
+		})
+	}
+
+	/// Returns the smallest tower level of a subfield of `F` that contains all the constants of the
+	/// expression, or 0 if there are none.
+	pub fn min_constant_tower_level(&self) -> usize {
+		min_constant_tower_level(&self.expr)
+	}
+
+	/// Formats the expression as an S-expression.
+	///
+	/// Variables are written as `(var i)`, constants as `(const c)` with `c` the integer value of
+	/// the canonical encoding, and operations as `(add a b)`, `(sub a b)`, `(mul a b)`,
+	/// `(pow a e)` and `(inv a)`. For example, $x_0^2 (x_1 + 123)$ is written as
+	/// `(mul (pow (var 0) 2) (add (var 1) (const 123)))`.
+	pub fn to_sexpr(&self) -> String {
+		let mut out = String::new();
+		write_sexpr(&self.expr, &mut out);
+		out
+	}
+
+	/// Formats the expression in infix notation, such as `x0^2 * (x1 + 123)`.
+	///
+	/// Inverses are written as `inv(a)`.
+	/// Variable `i` is written as `var_names[i]`, or `x{i}` when `var_names` is too short.
+	/// Constants are written as the integer value of their canonical encoding, as in
+	/// [`Self::to_sexpr`]. Parentheses are only inserted where the precedence of the operations,
+	/// `^` over `*` over `+` and `-`, requires them.
+	pub fn to_string_expr(&self, var_names: &[&str]) -> String {
+		let mut out = String::new();
+		write_infix(&self.expr, var_names, &mut out);
+		out
+	}
+
+	/// Parses a circuit with `n_vars` variables from the S-expression format produced by
+	/// [`Self::to_sexpr`].
+	///
+	/// The S-expression only records the variables that are read, so the number of variables is
+	/// passed separately, as in [`Self::with_n_vars`], and must be greater than the highest
+	/// variable index in the expression. Variable indices must be below 2^16 and the expression may be nested at most 2^10 levels
+	/// deep, so that untrusted input cannot exhaust memory or the stack.
+	pub fn from_sexpr(s: &str, n_vars: usize) -> Result<Self, Error> {
+		let mut parser = SExprParser {
+			input: s,
+			pos: 0,
+			depth: 0,
+		};
+		let expr = parser.parse_expr()?;
+		parser.skip_whitespace();
+		if parser.pos != s.len() {
+			return Err(parser.error("unexpected trailing input"));
+		}
+		Self::with_n_vars(n_vars, expr)
+	}
+
+	/// Renders the circuit as a Graphviz DOT digraph.
+	///
+	/// Each circuit step is a node labelled by its operation (`+`, `-`, `*` or `^n`), with edges to
+	/// the steps, variables and constants it reads. Variables are labelled `Var(i)` and constants
+	/// `Const(c)`, with `c` the integer value of the canonical encoding as in [`Self::to_sexpr`].
+	/// Every occurrence of a constant gets its own node. The top-level expression is drawn with a
+	/// double border.
+	pub fn to_dot(&self) -> String {
+		let mut out = String::from("digraph ArithCircuitPoly {\n");
+		let mut n_consts = 0;
+		let mut var_is_drawn = vec![false; self.n_vars];
+		let mut node = |argument: &CircuitStepArgument<F>, out: &mut String| match argument {
+			CircuitStepArgument::Const(value) => {
+				let name = format!("c{n_consts}");
+				n_consts += 1;
+				out.push_str(&format!(
+					"  {name} [label=\"Const({})\", shape=box];\n",
+					canonical_u128(*value)
+				));
+				name
+			}
+			CircuitStepArgument::Expr(CircuitNode::Var(index)) => {
+				if !var_is_drawn[*index] {
+					var_is_drawn[*index] = true;
+					out.push_str(&format!("  v{index} [label=\"Var({index})\", shape=box];\n"));
+				}
+				format!("v{index}")
+			}
+			CircuitStepArgument::Expr(CircuitNode::Slot(slot)) => format!("s{slot}"),
+		};
+
+		for (i, step) in self.steps.iter().enumerate() {
+			let (label, arguments) = match step {
+				CircuitStep::Add(x, y) => ("+".to_string(), vec![x, y]),
+				CircuitStep::Sub(x, y) => ("-".to_string(), vec![x, y]),
+				CircuitStep::Mul(x, y) => ("*".to_string(), vec![x, y]),
+				CircuitStep::Pow(x, schedule) => {
+					(format!("^{}", self.pow_schedules[*schedule].exp), vec![x])
 				}
+				CircuitStep::Inv(x) => ("inv".to_string(), vec![x]),
+			};
+			out.push_str(&format!("  s{i} [label=\"{label}\"];\n"));
+			for argument in arguments {
+				let child = node(argument, &mut out);
+				out.push_str(&format!("  s{i} -> {child};\n"));
+			}
+		}
+
+		let root = node(&self.retval, &mut out);
+		out.push_str(&format!("  {root} [peripheries=2];\n}}\n"));
+		out
+	}
+}
+
+#[cfg(feature = "bench")]
+impl<F: TowerField> ArithCircuitPoly<F> {
+	/// Measures the evaluation throughput of the circuit over the packed field `P`.
+	///
+	/// Evaluates the circuit over `rows` random hypercube rows, rounded up to a whole number of
+	/// packed elements, and returns the number of scalar evaluations per second. The batch is
+	/// evaluated once before timing so that the stack scratch space and caches are warm.
+	pub fn bench_throughput<P>(&self, rows: usize) -> f64
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		let n_packed = rows.div_ceil(P::WIDTH).max(1);
+		let mut rng = StdRng::seed_from_u64(0);
+		let columns = (0..self.n_vars)
+			.map(|_| {
+				std::iter::repeat_with(|| P::random(&mut rng))
+					.take(n_packed)
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let batch_query = columns.iter().map(Vec::as_slice).collect::<Vec<_>>();
+		let mut evals = vec![P::zero(); n_packed];
+
+		CompositionPoly::batch_evaluate(self, &batch_query, &mut evals)
+			.expect("query has n_vars columns of equal length");
+
+		let start = std::time::Instant::now();
+		CompositionPoly::batch_evaluate(self, &batch_query, &mut evals)
+			.expect("query has n_vars columns of equal length");
+		std::hint::black_box(&evals);
+		let elapsed = start.elapsed().max(std::time::Duration::from_nanos(1));
+
+		(n_packed * P::WIDTH) as f64 / elapsed.as_secs_f64()
+	}
+}
+
+/// The largest number of variables for which [`ArithCircuitPoly::is_zero_on_cube`] evaluates the
+/// circuit at every hypercube point.
+pub const MAX_EXHAUSTIVE_CUBE_VARS: usize = 16;
+
+/// The number of hypercube points probed by [`ArithCircuitPoly::is_zero_on_cube`] for circuits with
+/// too many variables to check exhaustively.
+const N_CUBE_PROBES: usize = 1 << 12;
+
+/// The number of pseudo-random field points probed by [`ArithCircuitPoly::is_symmetric`].
+const N_SYMMETRY_PROBES: usize = 16;
+
+fn expr_depth<F: Field>(expr: &ArithExpr<F>) -> usize {
+	match expr {
+		ArithExpr::Const(_) | ArithExpr::Var(_) => 0,
+		ArithExpr::Add(left, right) | ArithExpr::Sub(left, right) | ArithExpr::Mul(left, right) => {
+			1 + expr_depth(left).max(expr_depth(right))
+		}
+		ArithExpr::Pow(base, _) | ArithExpr::Inv(base) => 1 + expr_depth(base),
+	}
+}
+
+fn rebalance_expr<F: Field>(expr: ArithExpr<F>) -> ArithExpr<F> {
+	/// Collects the operands of the maximal chain of additions rooted at `expr`.
+	fn flatten_add<F: Field>(expr: ArithExpr<F>, operands: &mut Vec<ArithExpr<F>>) {
+		match expr {
+			ArithExpr::Add(left, right) => {
+				flatten_add(*left, operands);
+				flatten_add(*right, operands);
+			}
+			expr => operands.push(rebalance_expr(expr)),
+		}
+	}
+
+	/// Collects the operands of the maximal chain of multiplications rooted at `expr`.
+	fn flatten_mul<F: Field>(expr: ArithExpr<F>, operands: &mut Vec<ArithExpr<F>>) {
+		match expr {
+			ArithExpr::Mul(left, right) => {
+				flatten_mul(*left, operands);
+				flatten_mul(*right, operands);
+			}
+			expr => operands.push(rebalance_expr(expr)),
+		}
+	}
+
+	/// Joins the operands pairwise into a balanced tree, preserving their order.
+	fn balanced<F: Field>(
+		mut operands: Vec<ArithExpr<F>>,
+		join: fn(ArithExpr<F>, ArithExpr<F>) -> ArithExpr<F>,
+	) -> ArithExpr<F> {
+		if operands.len() == 1 {
+			return operands.pop().expect("operands has one element");
+		}
+		let right = operands.split_off(operands.len() / 2);
+		join(balanced(operands, join), balanced(right, join))
+	}
+
+	match expr {
+		expr @ ArithExpr::Add(..) => {
+			let mut operands = Vec::new();
+			flatten_add(expr, &mut operands);
+			balanced(operands, |left, right| left + right)
+		}
+		expr @ ArithExpr::Mul(..) => {
+			let mut operands = Vec::new();
+			flatten_mul(expr, &mut operands);
+			balanced(operands, |left, right| left * right)
+		}
+		ArithExpr::Sub(left, right) => rebalance_expr(*left) - rebalance_expr(*right),
+		ArithExpr::Pow(base, exp) => rebalance_expr(*base).pow(exp),
+		ArithExpr::Inv(inner) => rebalance_expr(*inner).inv(),
+		expr => expr,
+	}
+}
+
+/// Rewrites the powers of the boolean variables, flagged by `is_boolean`, to the variables
+/// themselves.
+fn simplify_boolean_expr<F: Field>(expr: ArithExpr<F>, is_boolean: &[bool]) -> ArithExpr<F> {
+	let is_boolean_var =
+		|expr: &ArithExpr<F>| matches!(expr, ArithExpr::Var(index) if is_boolean[*index]);
+	match expr {
+		ArithExpr::Add(left, right) => {
+			simplify_boolean_expr(*left, is_boolean) + simplify_boolean_expr(*right, is_boolean)
+		}
+		ArithExpr::Sub(left, right) => {
+			simplify_boolean_expr(*left, is_boolean) - simplify_boolean_expr(*right, is_boolean)
+		}
+		ArithExpr::Mul(left, right) => {
+			let left = simplify_boolean_expr(*left, is_boolean);
+			let right = simplify_boolean_expr(*right, is_boolean);
+			if is_boolean_var(&left) && left == right {
+				left
+			} else {
+				left * right
+			}
+		}
+		ArithExpr::Pow(base, exp) => {
+			let base = simplify_boolean_expr(*base, is_boolean);
+			if exp >= 1 && is_boolean_var(&base) {
+				base
+			} else {
+				base.pow(exp)
+			}
+		}
+		ArithExpr::Inv(inner) => simplify_boolean_expr(*inner, is_boolean).inv(),
+		expr => expr,
+	}
+}
+
+/// Computes the formal partial derivative of an expression, returning `None` if it is zero.
+fn derivative_expr<F: Field>(expr: &ArithExpr<F>, var: usize) -> Option<ArithExpr<F>> {
+	match expr {
+		ArithExpr::Const(_) => None,
+		ArithExpr::Var(index) => (*index == var).then(ArithExpr::one),
+		ArithExpr::Add(left, right) => {
+			match (derivative_expr(left, var), derivative_expr(right, var)) {
+				(Some(left), Some(right)) => Some(left + right),
+				(left, right) => left.or(right),
 			}
 		}
-	}
-}
+		ArithExpr::Sub(left, right) => {
+			match (derivative_expr(left, var), derivative_expr(right, var)) {
+				(Some(left), Some(right)) => Some(left - right),
+				(Some(left), None) => Some(left),
+				(None, Some(right)) => Some(ArithExpr::zero() - right),
+				(None, None) => None,
+			}
+		}
+		ArithExpr::Mul(left, right) => {
+			// Product rule: (ab)' = a'b + ab'
+			let left_term = derivative_expr(left, var).map(|d_left| d_left * (**right).clone());
+			let right_term = derivative_expr(right, var).map(|d_right| (**left).clone() * d_right);
+			match (left_term, right_term) {
+				(Some(left), Some(right)) => Some(left + right),
+				(left, right) => left.or(right),
+			}
+		}
+		ArithExpr::Pow(base, exp) => {
+			// Power rule in characteristic 2: (a^n)' = n a^(n-1) a', where n is taken modulo 2
+			if exp % 2 == 0 {
+				return None;
+			}
+			let d_base = derivative_expr(base, var)?;
+			if *exp == 1 {
+				Some(d_base)
+			} else {
+				Some((**base).clone().pow(exp - 1) * d_base)
+			}
+		}
+		ArithExpr::Inv(inner) => {
+			// (1/a)' = -a'/a^2, where the sign is irrelevant in characteristic 2
+			let d_inner = derivative_expr(inner, var)?;
+			Some(d_inner * (**inner).clone().inv().pow(2))
+		}
+	}
+}
+
+impl<F: TowerField> CompositionPoly<F> for ArithCircuitPoly<F> {
+	fn degree(&self) -> usize {
+		self.degree
+	}
+
+	fn n_vars(&self) -> usize {
+		self.n_vars
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		F::TOWER_LEVEL
+	}
+
+	fn expression<FE: ExtensionField<F>>(&self) -> ArithExpr<FE> {
+		self.expr.convert_field()
+	}
+
+	fn evaluate<P: PackedField<Scalar: ExtensionField<F>>>(&self, query: &[P]) -> Result<P, Error> {
+		if query.len() != self.n_vars {
+			return Err(Error::IncorrectQuerySize {
+				expected: self.n_vars,
+			});
+		}
+
+		// `stackalloc_uninit` throws a debug assert if `size` is 0, so set minimum of 1.
+		stackalloc_uninit::<P, _, _>(self.steps.len().max(1), |evals| {
+			Ok(self.evaluate_with_scratch(query, evals))
+		})
+	}
+
+	fn batch_evaluate<P: PackedField<Scalar: ExtensionField<F>>>(
+		&self,
+		batch_query: &[&[P]],
+		evals: &mut [P],
+	) -> Result<(), Error> {
+		let row_len = evals.len();
+		if batch_query.iter().any(|row| row.len() != row_len) {
+			return Err(Error::BatchEvaluateSizeMismatch);
+		}
+
+		// The scratch space is allocated on the stack of the evaluating thread rather than shared
+		// across threads, so its pages are first touched by that thread and are placed on its NUMA
+		// node by the OS. No NUMA-aware allocator is needed here.
+		//
+		// `stackalloc_uninit` throws a debug assert if `size` is 0, so set minimum of 1.
+		stackalloc_uninit::<P, (), _>((self.steps.len() * row_len).max(1), |sparse_evals| {
+			for (i, expr) in self.steps.iter().enumerate() {
+				let (before, current) = sparse_evals.split_at_mut(i * row_len);
+
+				// Safety: `before` is guaranteed to be initialized by the previous loop iterations.
+				let before = unsafe { slice_assume_init(before) };
+				let current = &mut current[..row_len];
+
+				match expr {
+					CircuitStep::Add(left, right) => {
+						apply_binary_op(
+							left,
+							right,
+							batch_query,
+							before,
+							current,
+							|left, right, out| {
+								out.write(left + right);
+							},
+						);
+					}
+					CircuitStep::Sub(left, right) => {
+						apply_binary_op(
+							left,
+							right,
+							batch_query,
+							before,
+							current,
+							|left, right, out| {
+								out.write(left - right);
+							},
+						);
+					}
+					CircuitStep::Mul(left, right) => {
+						apply_binary_op(
+							left,
+							right,
+							batch_query,
+							before,
+							current,
+							|left, right, out| {
+								out.write(left * right);
+							},
+						);
+					}
+					CircuitStep::Pow(id, schedule) => match id {
+						CircuitStepArgument::Expr(id) => {
+							let schedule = &self.pow_schedules[*schedule];
+							let id = id.get_sparse_chunk(batch_query, before, row_len);
+							for j in 0..row_len {
+								// Safety: `current` and `id` have length equal to `row_len`
+								unsafe {
+									current
+										.get_unchecked_mut(j)
+										.write(schedule.apply(*id.get_unchecked(j)));
+								}
+							}
+						}
+						CircuitStepArgument::Const(id) => {
+							let id: P = P::broadcast((*id).into());
+							let result = self.pow_schedules[*schedule].apply(id);
+							for j in 0..row_len {
+								// Safety: `current` has length equal to `row_len`
+								unsafe {
+									current.get_unchecked_mut(j).write(result);
+								}
+							}
+						}
+					},
+					CircuitStep::Inv(id) => match id {
+						CircuitStepArgument::Expr(id) => {
+							let id = id.get_sparse_chunk(batch_query, before, row_len);
+							for j in 0..row_len {
+								// Safety: `current` and `id` have length equal to `row_len`
+								unsafe {
+									current
+										.get_unchecked_mut(j)
+										.write(id.get_unchecked(j).invert_or_zero());
+								}
+							}
+						}
+						CircuitStepArgument::Const(id) => {
+							let result = P::broadcast((*id).into()).invert_or_zero();
+							for j in 0..row_len {
+								// Safety: `current` has length equal to `row_len`
+								unsafe {
+									current.get_unchecked_mut(j).write(result);
+								}
+							}
+						}
+					},
+				}
+			}
+
+			match self.retval {
+				CircuitStepArgument::Expr(node) => {
+					// Safety: `sparse_evals` is fully initialized by the previous loop iterations
+					let sparse_evals = unsafe { slice_assume_init(sparse_evals) };
+					evals.copy_from_slice(node.get_sparse_chunk(batch_query, sparse_evals, row_len))
+				}
+				CircuitStepArgument::Const(val) => evals.fill(P::broadcast(val.into())),
+			}
+		});
+
+		Ok(())
+	}
+}
+
+impl<F: TowerField, P: PackedField<Scalar: ExtensionField<F>>> CompositionPolyOS<P>
+	for ArithCircuitPoly<F>
+{
+	fn degree(&self) -> usize {
+		CompositionPoly::degree(self)
+	}
+
+	fn n_vars(&self) -> usize {
+		CompositionPoly::n_vars(self)
+	}
+
+	fn expression(&self) -> ArithExpr<P::Scalar> {
+		self.expr.convert_field()
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		CompositionPoly::binary_tower_level(self)
+	}
+
+	fn evaluate(&self, query: &[P]) -> Result<P, Error> {
+		CompositionPoly::evaluate(self, query)
+	}
+
+	fn batch_evaluate(&self, batch_query: &[&[P]], evals: &mut [P]) -> Result<(), Error> {
+		CompositionPoly::batch_evaluate(self, batch_query, evals)
+	}
+}
+
+impl<F: TowerField> MultivariatePoly<F> for ArithCircuitPoly<F> {
+	fn degree(&self) -> usize {
+		CompositionPoly::degree(&self)
+	}
+
+	fn n_vars(&self) -> usize {
+		CompositionPoly::n_vars(&self)
+	}
+
+	fn binary_tower_level(&self) -> usize {
+		CompositionPoly::binary_tower_level(&self)
+	}
+
+	fn evaluate(&self, query: &[F]) -> Result<F, super::Error> {
+		CompositionPoly::evaluate(&self, query).map_err(|e| e.into())
+	}
+}
+
+/// The operation combining the parts returned by [`ArithCircuitPoly::connected_components`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CombineOp {
+	Sum,
+	Product,
+}
+
+impl CombineOp {
+	/// Combines the evaluations of the parts into the evaluation of the whole circuit.
+	pub fn combine<P: PackedField>(self, evals: impl IntoIterator<Item = P>) -> P {
+		match self {
+			Self::Sum => evals.into_iter().sum(),
+			Self::Product => evals.into_iter().product(),
+		}
+	}
+}
+
+/// The values of one variable of a packed query, given in the canonical tower field of its column.
+///
+/// Each variant holds one value per lane of the packed evaluation field, see
+/// [`ArithCircuitPoly::evaluate_mixed`].
+#[derive(Debug, Clone, Copy)]
+pub enum MixedQuery<'a> {
+	B1(&'a [BinaryField1b]),
+	B8(&'a [BinaryField8b]),
+	B16(&'a [BinaryField16b]),
+	B32(&'a [BinaryField32b]),
+	B64(&'a [BinaryField64b]),
+	B128(&'a [BinaryField128b]),
+}
+
+impl MixedQuery<'_> {
+	/// The tower level of the field the values are given in.
+	pub const fn tower_level(&self) -> usize {
+		match self {
+			Self::B1(_) => BinaryField1b::TOWER_LEVEL,
+			Self::B8(_) => BinaryField8b::TOWER_LEVEL,
+			Self::B16(_) => BinaryField16b::TOWER_LEVEL,
+			Self::B32(_) => BinaryField32b::TOWER_LEVEL,
+			Self::B64(_) => BinaryField64b::TOWER_LEVEL,
+			Self::B128(_) => BinaryField128b::TOWER_LEVEL,
+		}
+	}
+
+	/// Embeds the values of the variable with index `index` into a packed value of `P`.
+	fn embed<P>(&self, index: usize) -> Result<P, Error>
+	where
+		P: PackedField<Scalar: TowerField<Canonical: TryFrom<BinaryField128b>>>,
+	{
+		if self.tower_level() > P::Scalar::TOWER_LEVEL {
+			bail!(Error::QueryTowerLevelTooHigh {
+				index,
+				tower_level: self.tower_level(),
+				max_tower_level: P::Scalar::TOWER_LEVEL,
+			});
+		}
+
+		match self {
+			Self::B1(values) => embed_lanes(index, values),
+			Self::B8(values) => embed_lanes(index, values),
+			Self::B16(values) => embed_lanes(index, values),
+			Self::B32(values) => embed_lanes(index, values),
+			Self::B64(values) => embed_lanes(index, values),
+			Self::B128(values) => embed_lanes(index, values),
+		}
+	}
+}
+
+/// Packs `values` into `P`, embedding each one into `P::Scalar`.
+///
+/// The values are embedded into [`BinaryField128b`] and cast down to the canonical field of
+/// `P::Scalar`, which cannot fail because the caller has checked the tower levels.
+fn embed_lanes<FS, P>(index: usize, values: &[FS]) -> Result<P, Error>
+where
+	FS: TowerField,
+	BinaryField128b: From<FS>,
+	P: PackedField<Scalar: TowerField<Canonical: TryFrom<BinaryField128b>>>,
+{
+	if values.len() != P::WIDTH {
+		bail!(Error::IncorrectArgumentLength {
+			arg: format!("query[{index}]"),
+			expected: P::WIDTH,
+		});
+	}
+
+	Ok(P::from_fn(|i| {
+		let canonical = <P::Scalar as TowerField>::Canonical::try_from(values[i].into())
+			.unwrap_or_else(|_| unreachable!("the tower level was checked by the caller"));
+		canonical.into()
+	}))
+}
+
+/// Allocates the per-thread scratch space of [`ArithCircuitPoly::evaluate_batch`].
+///
+/// With the `numa` feature on Linux, the pages of the buffer are bound to the NUMA node of the
+/// calling thread. Otherwise, or if binding fails, the placement is left to the global allocator.
+fn alloc_scratch<P: Copy>(len: usize) -> Vec<MaybeUninit<P>> {
+	#[allow(unused_mut)]
+	let mut scratch = vec![MaybeUninit::uninit(); len];
+	#[cfg(all(feature = "numa", target_os = "linux"))]
+	numa::bind_to_local_node(&mut scratch);
+	scratch
+}
+
+#[cfg(all(feature = "numa", target_os = "linux"))]
+mod numa {
+	use std::{mem::size_of_val, ptr};
+
+	/// `MPOL_LOCAL` from `linux/mempolicy.h`: allocate on the node of the faulting CPU.
+	const MPOL_LOCAL: libc::c_int = 4;
+
+	/// Binds the whole pages of `buf` to the NUMA node of the calling thread.
+	///
+	/// Pages that `buf` only partially covers may be shared with other allocations, so they are
+	/// left alone. A failed `mbind`, for instance on a kernel without NUMA support, is ignored.
+	pub fn bind_to_local_node<T>(buf: &mut [T]) {
+		// Safety: `sysconf` has no preconditions.
+		let page_size = unsafe { libc::sysconf(libc::_SC_PAGESIZE) };
+		let Ok(page_size) = usize::try_from(page_size) else {
+			return;
+		};
+		if page_size == 0 {
+			return;
+		}
+
+		let begin = buf.as_mut_ptr() as usize;
+		let start = begin.next_multiple_of(page_size);
+		let end = (begin + size_of_val(buf)) / page_size * page_size;
+		if start >= end {
+			return;
+		}
+
+		// Safety: the range is page aligned and lies within `buf`, so only the placement of pages
+		// owned by this allocation changes. No node mask is passed for `MPOL_LOCAL`.
+		unsafe {
+			libc::syscall(
+				libc::SYS_mbind,
+				start,
+				end - start,
+				MPOL_LOCAL,
+				ptr::null::<libc::c_ulong>(),
+				0 as libc::c_ulong,
+				0 as libc::c_uint,
+			);
+		}
+	}
+}
+
+/// Upper bound on the number of variables of a deserialized or parsed circuit.
+const MAX_DECODED_N_VARS: usize = 1 << 16;
+
+/// Upper bound on the number of nodes of the expression tree of a deserialized circuit, counting
+/// every occurrence of a shared node.
+const MAX_DECODED_EXPR_SIZE: usize = 1 << 20;
+
+/// Upper bound on the nesting depth of the expression of a deserialized or parsed circuit.
+///
+/// The expression is processed recursively, so deeper inputs would overflow the stack.
+const MAX_DECODED_EXPR_DEPTH: usize = 1 << 10;
+
+/// Serializes an artifact header of version 1, the number of variables and the expression as a
+/// list of nodes.
+///
+/// The nodes are written in post order, so every node comes after its operands and the last node
+/// is the root. Each node is a tag followed by its payload: a field element for constants, and the
+/// indices of the operand nodes for operations. Equal subexpressions are written once and shared
+/// by index. The number of variables, the node count, variable indices, exponents and node indices
+/// are written as LEB128 varints. Only the expression is stored; the circuit steps and the degree
+/// are recomputed on deserialization.
+impl<F: TowerField> SerializeBytes for ArithCircuitPoly<F> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		let mut nodes = Vec::new();
+		intern_expr_nodes(&self.expr, &mut nodes, &mut HashMap::new());
+
+		write_header(&mut write_buf, ArtifactKind::ArithCircuitPoly, 1)?;
+		write_varint(&mut write_buf, self.n_vars as u64)?;
+		write_varint(&mut write_buf, nodes.len() as u64)?;
+		for node in &nodes {
+			if write_buf.remaining_mut() < node.len() {
+				bail!(SerializationError::WriteBufferFull);
+			}
+			write_buf.put_slice(node);
+		}
+		Ok(())
+	}
+}
+
+/// Deserializes a circuit written by [`SerializeBytes`].
+///
+/// Operands must refer to nodes strictly before the node using them, which keeps the nodes a
+/// directed acyclic graph, and variable indices must be below the stored number of variables.
+/// Since shared nodes are expanded into a tree, the size and the depth of that tree are bounded, so
+/// a small crafted input cannot exhaust memory or the stack. The degree is always recomputed from
+/// the expression, so a tampered stream cannot make the circuit misreport its shape.
+impl<F: TowerField> DeserializeBytes for ArithCircuitPoly<F> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		read_header(&mut read_buf, ArtifactKind::ArithCircuitPoly, 1)?;
+
+		let invalid = || SerializationError::InvalidConstruction {
+			name: "ArithCircuitPoly",
+		};
+
+		let n_vars = read_varint(&mut read_buf)?;
+		if n_vars > MAX_DECODED_N_VARS as u64 {
+			bail!(invalid());
+		}
+		let n_vars = n_vars as usize;
+		// The nodes are distinct subexpressions of the expression tree, so there are no more of
+		// them than the bound on its size.
+		let n_nodes = read_varint(&mut read_buf)?;
+		if n_nodes > MAX_DECODED_EXPR_SIZE as u64 {
+			bail!(invalid());
+		}
+		let n_nodes = n_nodes as usize;
+		// Every node takes at least one byte, so a truncated stream does not get the allocation of
+		// the full node count. The remaining bytes may be just the buffered part of a stream, so
+		// they only bound the preallocation.
+		let capacity = n_nodes.min(read_buf.remaining());
+
+		let mut nodes = Vec::<ExprNode<F>>::with_capacity(capacity);
+		let mut shapes = Vec::<(usize, usize)>::with_capacity(capacity);
+		for index in 0..n_nodes {
+			let node = deserialize_expr_node(&mut read_buf, index, n_vars)?;
+			let (size, depth) = node.operands().map(|operand| shapes[operand]).fold(
+				(1usize, 1usize),
+				|(size, depth), (operand_size, operand_depth)| {
+					(size.saturating_add(operand_size), depth.max(operand_depth + 1))
+				},
+			);
+			if size > MAX_DECODED_EXPR_SIZE || depth > MAX_DECODED_EXPR_DEPTH {
+				bail!(invalid());
+			}
+			nodes.push(node);
+			shapes.push((size, depth));
+		}
+		if nodes.is_empty() {
+			bail!(invalid());
+		}
+		let expr = build_expr(&nodes, nodes.len() - 1);
+
+		Self::with_n_vars(n_vars, expr).map_err(|_| invalid())
+	}
+}
+
+/// A node of a serialized expression, with its operands given by node index.
+enum ExprNode<F: Field> {
+	Const(F),
+	Var(usize),
+	Add(usize, usize),
+	Sub(usize, usize),
+	Mul(usize, usize),
+	Pow(usize, u64),
+	Inv(usize),
+}
+
+impl<F: Field> ExprNode<F> {
+	fn operands(&self) -> impl Iterator<Item = usize> {
+		let (first, second) = match *self {
+			Self::Const(_) | Self::Var(_) => (None, None),
+			Self::Add(left, right) | Self::Sub(left, right) | Self::Mul(left, right) => {
+				(Some(left), Some(right))
+			}
+			Self::Pow(base, _) | Self::Inv(base) => (Some(base), None),
+		};
+		first.into_iter().chain(second)
+	}
+}
+
+/// Expands the node at `index` into an expression tree.
+fn build_expr<F: Field>(nodes: &[ExprNode<F>], index: usize) -> ArithExpr<F> {
+	match nodes[index] {
+		ExprNode::Const(value) => ArithExpr::Const(value),
+		ExprNode::Var(var) => ArithExpr::Var(var),
+		ExprNode::Add(left, right) => build_expr(nodes, left) + build_expr(nodes, right),
+		ExprNode::Sub(left, right) => build_expr(nodes, left) - build_expr(nodes, right),
+		ExprNode::Mul(left, right) => build_expr(nodes, left) * build_expr(nodes, right),
+		ExprNode::Pow(base, exp) => build_expr(nodes, base).pow(exp),
+		ExprNode::Inv(inner) => build_expr(nodes, inner).inv(),
+	}
+}
+
+/// Appends the encodings of the nodes of `expr` in post order, reusing the index of an equal node
+/// written before, and returns the index of the root node.
+///
+/// The encoding of a node refers to its operands by index, so equal encodings denote equal
+/// subexpressions.
+fn intern_expr_nodes<F: TowerField>(
+	expr: &ArithExpr<F>,
+	nodes: &mut Vec<Vec<u8>>,
+	indices: &mut HashMap<Vec<u8>, usize>,
+) -> usize {
+	let mut node = Vec::new();
+	match expr {
+		ArithExpr::Const(value) => {
+			node.push(0);
+			serialize_canonical(*value, &mut node).expect("vectors grow on demand");
+		}
+		ArithExpr::Var(index) => {
+			node.push(1);
+			put_varint(&mut node, *index as u64);
+		}
+		ArithExpr::Add(left, right) => intern_operands(2, [left, right], &mut node, nodes, indices),
+		ArithExpr::Mul(left, right) => intern_operands(3, [left, right], &mut node, nodes, indices),
+		ArithExpr::Pow(base, exp) => {
+			intern_operands(4, [base], &mut node, nodes, indices);
+			put_varint(&mut node, *exp);
+		}
+		ArithExpr::Sub(left, right) => intern_operands(5, [left, right], &mut node, nodes, indices),
+		ArithExpr::Inv(inner) => intern_operands(6, [inner], &mut node, nodes, indices),
+	}
+	*indices.entry(node).or_insert_with_key(|node| {
+		nodes.push(node.clone());
+		nodes.len() - 1
+	})
+}
+
+/// Interns the operands and writes the tag of an operation node followed by the operand indices.
+fn intern_operands<F: TowerField, const N: usize>(
+	tag: u8,
+	operands: [&ArithExpr<F>; N],
+	node: &mut Vec<u8>,
+	nodes: &mut Vec<Vec<u8>>,
+	indices: &mut HashMap<Vec<u8>, usize>,
+) {
+	let operands = operands.map(|operand| intern_expr_nodes(operand, nodes, indices));
+	node.push(tag);
+	for operand in operands {
+		put_varint(node, operand as u64);
+	}
+}
+
+fn put_varint(node: &mut Vec<u8>, value: u64) {
+	write_varint(node, value).expect("vectors grow on demand");
+}
+
+/// Reads the node at position `index` of an expression over `n_vars` variables.
+fn deserialize_expr_node<F: TowerField>(
+	read_buf: &mut impl Buf,
+	index: usize,
+	n_vars: usize,
+) -> Result<ExprNode<F>, SerializationError> {
+	let operand = |read_buf: &mut _| {
+		let reference = read_varint(&mut *read_buf)?;
+		if reference >= index as u64 {
+			bail!(SerializationError::NonPrecedingReference {
+				name: "ArithExpr",
+				index,
+				reference,
+			});
+		}
+		Ok(reference as usize)
+	};
+
+	if read_buf.remaining() < 1 {
+		bail!(SerializationError::NotEnoughBytes);
+	}
+	let node = match read_buf.get_u8() {
+		0 => ExprNode::Const(deserialize_canonical(&mut *read_buf)?),
+		1 => {
+			let var = read_varint(&mut *read_buf)?;
+			if var >= n_vars as u64 {
+				bail!(SerializationError::InvalidConstruction { name: "ArithExpr" });
+			}
+			ExprNode::Var(var as usize)
+		}
+		2 => ExprNode::Add(operand(read_buf)?, operand(read_buf)?),
+		3 => ExprNode::Mul(operand(read_buf)?, operand(read_buf)?),
+		4 => ExprNode::Pow(operand(read_buf)?, read_varint(&mut *read_buf)?),
+		5 => ExprNode::Sub(operand(read_buf)?, operand(read_buf)?),
+		6 => ExprNode::Inv(operand(read_buf)?),
+		index => bail!(SerializationError::UnknownEnumVariant {
+			name: "ArithExpr",
+			index,
+		}),
+	};
+	Ok(node)
+}
+
+fn min_constant_tower_level<F: TowerField>(expr: &ArithExpr<F>) -> usize {
+	match expr {
+		ArithExpr::Const(value) => value.min_tower_level(),
+		ArithExpr::Var(_) => 0,
+		ArithExpr::Add(left, right) | ArithExpr::Sub(left, right) | ArithExpr::Mul(left, right) => {
+			min_constant_tower_level(left).max(min_constant_tower_level(right))
+		}
+		ArithExpr::Pow(base, _) | ArithExpr::Inv(base) => min_constant_tower_level(base),
+	}
+}
+
+/// The integer value of the canonical encoding of a tower field element.
+fn canonical_u128<F: TowerField>(value: F) -> u128 {
+	let mut bytes = [0u8; 16];
+	serialize_canonical(value, &mut bytes[..]).expect("tower field elements are at most 16 bytes");
+	u128::from_le_bytes(bytes)
+}
+
+fn write_sexpr<F: TowerField>(expr: &ArithExpr<F>, out: &mut String) {
+	match expr {
+		ArithExpr::Const(value) => out.push_str(&format!("(const {})", canonical_u128(*value))),
+		ArithExpr::Var(index) => out.push_str(&format!("(var {index})")),
+		ArithExpr::Add(left, right) => write_sexpr_binary_op("add", left, right, out),
+		ArithExpr::Sub(left, right) => write_sexpr_binary_op("sub", left, right, out),
+		ArithExpr::Mul(left, right) => write_sexpr_binary_op("mul", left, right, out),
+		ArithExpr::Pow(base, exp) => {
+			out.push_str("(pow ");
+			write_sexpr(base, out);
+			out.push_str(&format!(" {exp})"));
+		}
+		ArithExpr::Inv(inner) => {
+			out.push_str("(inv ");
+			write_sexpr(inner, out);
+			out.push(')');
+		}
+	}
+}
+
+fn write_sexpr_binary_op<F: TowerField>(
+	op: &str,
+	left: &ArithExpr<F>,
+	right: &ArithExpr<F>,
+	out: &mut String,
+) {
+	out.push_str(&format!("({op} "));
+	write_sexpr(left, out);
+	out.push(' ');
+	write_sexpr(right, out);
+	out.push(')');
+}
+
+/// Binding strength of the expression's top-level operation, higher binding tighter.
+fn infix_precedence<F: Field>(expr: &ArithExpr<F>) -> usize {
+	match expr {
+		ArithExpr::Add(..) | ArithExpr::Sub(..) => 0,
+		ArithExpr::Mul(..) => 1,
+		ArithExpr::Pow(..) => 2,
+		ArithExpr::Const(_) | ArithExpr::Var(_) | ArithExpr::Inv(_) => 3,
+	}
+}
+
+fn write_infix<F: TowerField>(expr: &ArithExpr<F>, var_names: &[&str], out: &mut String) {
+	// Writes an operand, in parentheses if it binds less tightly than `min_precedence`
+	let write_operand = |operand: &ArithExpr<F>, min_precedence: usize, out: &mut String| {
+		if infix_precedence(operand) < min_precedence {
+			out.push('(');
+			write_infix(operand, var_names, out);
+			out.push(')');
+		} else {
+			write_infix(operand, var_names, out);
+		}
+	};
+
+	match expr {
+		ArithExpr::Const(value) => out.push_str(&canonical_u128(*value).to_string()),
+		ArithExpr::Var(index) => match var_names.get(*index) {
+			Some(name) => out.push_str(name),
+			None => out.push_str(&format!("x{index}")),
+		},
+		ArithExpr::Add(left, right) => {
+			write_operand(left, 0, out);
+			out.push_str(" + ");
+			write_operand(right, 0, out);
+		}
+		ArithExpr::Sub(left, right) => {
+			write_operand(left, 0, out);
+			out.push_str(" - ");
+			write_operand(right, 1, out);
+		}
+		ArithExpr::Mul(left, right) => {
+			write_operand(left, 1, out);
+			out.push_str(" * ");
+			write_operand(right, 1, out);
+		}
+		ArithExpr::Pow(base, exp) => {
+			write_operand(base, 3, out);
+			out.push_str(&format!("^{exp}"));
+		}
+		ArithExpr::Inv(inner) => {
+			out.push_str("inv(");
+			write_infix(inner, var_names, out);
+			out.push(')');
+		}
+	}
+}
+
+/// Recursive descent parser for the S-expression format of [`ArithCircuitPoly::to_sexpr`].
+struct SExprParser<'a> {
+	input: &'a str,
+	pos: usize,
+	/// The number of enclosing expressions being parsed.
+	depth: usize,
+}
+
+impl SExprParser<'_> {
+	fn error(&self, message: impl Into<String>) -> Error {
+		Error::MalformedSExpr {
+			position: self.pos,
+			message: message.into(),
+		}
+	}
+
+	fn skip_whitespace(&mut self) {
+		let rest = &self.input[self.pos..];
+		self.pos += rest.len() - rest.trim_start().len();
+	}
+
+	fn expect(&mut self, c: char) -> Result<(), Error> {
+		self.skip_whitespace();
+		if !self.input[self.pos..].starts_with(c) {
+			return Err(self.error(format!("expected '{c}'")));
+		}
+		self.pos += c.len_utf8();
+		Ok(())
+	}
+
+	/// Reads the next atom, which is a maximal run of characters other than whitespace and
+	/// parentheses.
+	fn atom(&mut self) -> Result<&str, Error> {
+		self.skip_whitespace();
+		let rest = &self.input[self.pos..];
+		let len = rest
+			.find(|c: char| c.is_whitespace() || c == '(' || c == ')')
+			.unwrap_or(rest.len());
+		if len == 0 {
+			return Err(self.error("expected an atom"));
+		}
+		self.pos += len;
+		Ok(&rest[..len])
+	}
+
+	fn integer<T: std::str::FromStr>(&mut self, what: &str) -> Result<T, Error> {
+		self.skip_whitespace();
+		let start = self.pos;
+		let atom = self.atom()?;
+		atom.parse().map_err(|_| Error::MalformedSExpr {
+			position: start,
+			message: format!("invalid {what} '{atom}'"),
+		})
+	}
+
+	fn parse_expr<F: TowerField>(&mut self) -> Result<ArithExpr<F>, Error> {
+		self.expect('(')?;
+		if self.depth == MAX_DECODED_EXPR_DEPTH {
+			return Err(self.error(format!(
+				"expression is nested more than {MAX_DECODED_EXPR_DEPTH} levels deep"
+			)));
+		}
+		self.depth += 1;
+		self.skip_whitespace();
+		let start = self.pos;
+		let expr = match self.atom()? {
+			"var" => {
+				self.skip_whitespace();
+				let index_pos = self.pos;
+				let index = self.integer::<usize>("variable index")?;
+				if index >= MAX_DECODED_N_VARS {
+					return Err(Error::MalformedSExpr {
+						position: index_pos,
+						message: format!(
+							"variable index {index} is not below {MAX_DECODED_N_VARS}"
+						),
+					});
+				}
+				ArithExpr::Var(index)
+			}
+			"const" => {
+				self.skip_whitespace();
+				let value_pos = self.pos;
+				let value = self.integer::<u128>("constant")?;
+				if F::N_BITS < 128 && value >> F::N_BITS != 0 {
+					return Err(Error::MalformedSExpr {
+						position: value_pos,
+						message: format!("constant {value} does not fit in {} bits", F::N_BITS),
+					});
+				}
+				ArithExpr::Const(
+					deserialize_canonical(&value.to_le_bytes()[..]).expect("buffer holds 16 bytes"),
+				)
+			}
+			"add" => self.parse_expr()? + self.parse_expr()?,
+			"sub" => self.parse_expr()? - self.parse_expr()?,
+			"mul" => self.parse_expr()? * self.parse_expr()?,
+			"pow" => {
+				let base = self.parse_expr()?;
+				base.pow(self.integer("exponent")?)
+			}
+			"inv" => self.parse_expr()?.inv(),
+			op => {
+				return Err(Error::MalformedSExpr {
+					position: start,
+					message: format!("unknown operation '{op}'"),
+				})
+			}
+		};
+		self.expect(')')?;
+		self.depth -= 1;
+		Ok(expr)
+	}
+}
+
+/// Apply a binary operation to two arguments and store the result in `current_evals`.
+/// `op` must be a function that takes two arguments and initialized the result with the third argument.
+fn apply_binary_op<F: Field, P: PackedField<Scalar: ExtensionField<F>>>(
+	left: &CircuitStepArgument<F>,
+	right: &CircuitStepArgument<F>,
+	batch_query: &[&[P]],
+	evals_before: &[P],
+	current_evals: &mut [MaybeUninit<P>],
+	op: impl Fn(P, P, &mut MaybeUninit<P>),
+) {
+	let row_len = current_evals.len();
+
+	match (left, right) {
+		(CircuitStepArgument::Expr(left), CircuitStepArgument::Expr(right)) => {
+			let left = left.get_sparse_chunk(batch_query, evals_before, row_len);
+			let right = right.get_sparse_chunk(batch_query, evals_before, row_len);
+			for j in 0..row_len {
+				// Safety: `current`, `left` and `right` have length equal to `row_len`
+				unsafe {
+					op(
+						*left.get_unchecked(j),
+						*right.get_unchecked(j),
+						current_evals.get_unchecked_mut(j),
+					)
+				}
+			}
+		}
+		(CircuitStepArgument::Expr(left), CircuitStepArgument::Const(right)) => {
+			let left = left.get_sparse_chunk(batch_query, evals_before, row_len);
+			let right = P::broadcast((*right).into());
+			for j in 0..row_len {
+				// Safety: `current` and `left` have length equal to `row_len`
+				unsafe {
+					op(*left.get_unchecked(j), right, current_evals.get_unchecked_mut(j));
+				}
+			}
+		}
+		(CircuitStepArgument::Const(left), CircuitStepArgument::Expr(right)) => {
+			let left = P::broadcast((*left).into());
+			let right = right.get_sparse_chunk(batch_query, evals_before, row_len);
+			for j in 0..row_len {
+				// Safety: `current` and `right` have length equal to `row_len`
+				unsafe {
+					op(left, *right.get_unchecked(j), current_evals.get_unchecked_mut(j));
+				}
+			}
+		}
+		(CircuitStepArgument::Const(left), CircuitStepArgument::Const(right)) => {
+			let left = P::broadcast((*left).into());
+			let right = P::broadcast((*right).into());
+			let mut result = MaybeUninit::uninit();
+			op(left, right, &mut result);
+			for j in 0..row_len {
+				// Safety:
+				// - `current` has length equal to `row_len`
+				// - `result` is initialized by `op`
+				unsafe {
+					current_evals
+						.get_unchecked_mut(j)
+						.write(result.assume_init());
+				}
+			}
+		}
+	}
+}
+
+/// Evaluates an [`ArithCircuitPoly`] as its variables are bound one at a time.
+///
+/// This matches how interactive protocols receive challenges sequentially. Each call to
+/// [`Self::bind`] substitutes the value into the expression and folds the resulting constant
+/// subexpressions, so the remaining circuit shrinks as variables are bound.
+#[derive(Debug, Clone)]
+pub struct PartialEvaluator<F: Field> {
+	expr: ArithExpr<F>,
+	n_vars: usize,
+}
+
+impl<F: TowerField> PartialEvaluator<F> {
+	pub fn new(circuit: &ArithCircuitPoly<F>) -> Self {
+		Self {
+			expr: circuit.expr.clone(),
+			n_vars: circuit.n_vars,
+		}
+	}
+
+	/// Binds the variable `var` to `value`. Binding a variable that is already bound has no effect.
+	pub fn bind(self, var: usize, value: F) -> Result<Self, Error> {
+		if var >= self.n_vars {
+			return Err(Error::ArgumentRangeError {
+				arg: "var".into(),
+				range: 0..self.n_vars,
+			});
+		}
+
+		Ok(Self {
+			expr: bind_expr(self.expr, var, value),
+			n_vars: self.n_vars,
+		})
+	}
+
+	/// Returns the circuit over the variables that remain unbound.
+	///
+	/// The circuit has the same number of variables as the original one, and does not depend on
+	/// the bound variables.
+	pub fn circuit(&self) -> ArithCircuitPoly<F> {
+		ArithCircuitPoly::with_n_vars(self.n_vars, self.expr.clone())
+			.expect("binding variables does not increase the number of variables")
+	}
+
+	/// Returns the value of the polynomial once all the variables it depends on are bound.
+	pub fn finish<P>(self) -> Result<P, Error>
+	where
+		P: PackedField<Scalar: ExtensionField<F>>,
+	{
+		match self.expr {
+			ArithExpr::Const(value) => Ok(P::broadcast(value.into())),
+			expr => Err(Error::IncorrectNumberOfVariables {
+				expected: 0,
+				actual: expr.n_vars(),
+			}),
+		}
+	}
+}
+
+/// Precomputes the operations whose operands are all constants, and simplifies additions of zero
+/// and multiplications by zero or one.
+fn fold_constants<F: Field>(expr: ArithExpr<F>) -> ArithExpr<F> {
+	match expr {
+		ArithExpr::Add(left, right) => match (fold_constants(*left), fold_constants(*right)) {
+			(ArithExpr::Const(left), ArithExpr::Const(right)) => ArithExpr::Const(left + right),
+			(ArithExpr::Const(zero), other) | (other, ArithExpr::Const(zero))
+				if zero == F::ZERO =>
+			{
+				other
+			}
+			(left, right) => left + right,
+		},
+		ArithExpr::Sub(left, right) => match (fold_constants(*left), fold_constants(*right)) {
+			(ArithExpr::Const(left), ArithExpr::Const(right)) => ArithExpr::Const(left - right),
+			(left, right) => left - right,
+		},
+		ArithExpr::Mul(left, right) => match (fold_constants(*left), fold_constants(*right)) {
+			(ArithExpr::Const(left), ArithExpr::Const(right)) => ArithExpr::Const(left * right),
+			(ArithExpr::Const(zero), _) | (_, ArithExpr::Const(zero)) if zero == F::ZERO => {
+				ArithExpr::zero()
+			}
+			(ArithExpr::Const(one), other) | (other, ArithExpr::Const(one)) if one == F::ONE => {
+				other
+			}
+			(left, right) => left * right,
+		},
+		ArithExpr::Pow(base, exp) => match fold_constants(*base) {
+			ArithExpr::Const(base) => ArithExpr::Const(base.pow_u64(exp)),
+			base => base.pow(exp),
+		},
+		ArithExpr::Inv(inner) => match fold_constants(*inner) {
+			ArithExpr::Const(inner) => ArithExpr::Const(inner.invert_or_zero()),
+			inner => inner.inv(),
+		},
+		expr => expr,
+	}
+}
+
+/// Substitutes `value` for the variable `var` and folds the constant subexpressions.
+fn bind_expr<F: Field>(expr: ArithExpr<F>, var: usize, value: F) -> ArithExpr<F> {
+	match expr {
+		ArithExpr::Var(index) if index == var => ArithExpr::Const(value),
+		ArithExpr::Add(left, right) => {
+			match (bind_expr(*left, var, value), bind_expr(*right, var, value)) {
+				(ArithExpr::Const(left), ArithExpr::Const(right)) => ArithExpr::Const(left + right),
+				(left, right) => left + right,
+			}
+		}
+		ArithExpr::Sub(left, right) => {
+			match (bind_expr(*left, var, value), bind_expr(*right, var, value)) {
+				(ArithExpr::Const(left), ArithExpr::Const(right)) => ArithExpr::Const(left - right),
+				(left, right) => left - right,
+			}
+		}
+		ArithExpr::Mul(left, right) => {
+			match (bind_expr(*left, var, value), bind_expr(*right, var, value)) {
+				(ArithExpr::Const(left), ArithExpr::Const(right)) => ArithExpr::Const(left * right),
+				(left, right) => left * right,
+			}
+		}
+		ArithExpr::Pow(base, exp) => match bind_expr(*base, var, value) {
+			ArithExpr::Const(base) => ArithExpr::Const(base.pow_u64(exp)),
+			base => base.pow(exp),
+		},
+		ArithExpr::Inv(inner) => match bind_expr(*inner, var, value) {
+			ArithExpr::Const(inner) => ArithExpr::Const(inner.invert_or_zero()),
+			inner => inner.inv(),
+		},
+		expr => expr,
+	}
+}
+
+/// An [`ArithCircuitPoly`] bound to a fixed set of columns, one for each of its variables.
+///
+/// The columns are validated once on construction, so evaluating the composition at successive
+/// rows does not need to check or rebuild the binding.
+#[derive(Debug, Clone)]
+pub struct BoundComposition<'a, F: Field, P> {
+	circuit: &'a ArithCircuitPoly<F>,
+	columns: Vec<&'a [P]>,
+	n_rows: usize,
+}
+
+impl<'a, F, P> BoundComposition<'a, F, P>
+where
+	F: TowerField,
+	P: PackedField<Scalar: ExtensionField<F>>,
+{
+	/// Binds the variable `i` of `circuit` to `columns[i]`. All columns must have the same length.
+	pub fn new(circuit: &'a ArithCircuitPoly<F>, columns: Vec<&'a [P]>) -> Result<Self, Error> {
+		if columns.len() != circuit.n_vars {
+			return Err(Error::IncorrectQuerySize {
+				expected: circuit.n_vars,
+			});
+		}
+		let n_rows = columns.first().map_or(0, |column| column.len());
+		if columns.iter().any(|column| column.len() != n_rows) {
+			return Err(Error::BatchEvaluateSizeMismatch);
+		}
+
+		Ok(Self {
+			circuit,
+			columns,
+			n_rows,
+		})
+	}
+
+	/// The number of rows in the bound columns.
+	pub fn n_rows(&self) -> usize {
+		self.n_rows
+	}
+
+	/// Evaluates the composition on the values of the bound columns at `row`.
+	pub fn evaluate_row(&self, row: usize) -> Result<P, Error> {
+		if row >= self.n_rows {
+			return Err(Error::ArgumentRangeError {
+				arg: "row".into(),
+				range: 0..self.n_rows,
+			});
+		}
+
+		// The query and the circuit scratch space share one stack allocation, so evaluating row by
+		// row does not allocate.
+		let n_vars = self.columns.len();
+		let scratch_len = n_vars + self.circuit.steps.len();
+		Ok(stackalloc_uninit::<P, _, _>(scratch_len.max(1), |scratch| {
+			let (query, evals) = scratch.split_at_mut(n_vars);
+			for (value, column) in query.iter_mut().zip(&self.columns) {
+				value.write(column[row]);
+			}
+			// Safety: every element of `query` was written above.
+			let query = unsafe { slice_assume_init(query) };
+			self.circuit.evaluate_with_scratch(query, evals)
+		}))
+	}
+
+	/// Evaluates the composition on the bound columns at every row in `rows`, writing the values
+	/// to `evals`.
+	///
+	/// The rows are evaluated with a single [`CompositionPoly::batch_evaluate`] over the column
+	/// slices, which is faster than [`Self::evaluate_row`] for chunks of many rows.
+	pub fn evaluate_rows(&self, rows: Range<usize>, evals: &mut [P]) -> Result<(), Error> {
+		if rows.start > rows.end || rows.end > self.n_rows {
+			return Err(Error::ArgumentRangeError {
+				arg: "rows".into(),
+				range: 0..self.n_rows,
+			});
+		}
+		if evals.len() != rows.len() {
+			return Err(Error::BatchEvaluateSizeMismatch);
+		}
+
+		let batch_query = self
+			.columns
+			.iter()
+			.map(|column| &column[rows.clone()])
+			.collect::<Vec<_>>();
+		CompositionPoly::batch_evaluate(self.circuit, &batch_query, evals)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		BinaryField16b, BinaryField2b, BinaryField8b, PackedBinaryField2x128b,
+		PackedBinaryField8x16b, PackedField, TowerField,
+	};
+	use binius_math::CompositionPolyOS;
+	use binius_utils::felts;
+	use proptest::prelude::*;
+
+	use super::*;
+
+	#[test]
+	fn test_constant() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		let expr = ArithExpr::Const(F::new(123));
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
+		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
+		assert_eq!(typed_circuit.degree(), 0);
+		assert_eq!(typed_circuit.n_vars(), 0);
+
+		assert_eq!(typed_circuit.evaluate(&[]).unwrap(), P::broadcast(F::new(123).into()));
+
+		let mut evals = [P::default()];
+		typed_circuit.batch_evaluate(&[], &mut evals).unwrap();
+		assert_eq!(evals, [P::broadcast(F::new(123).into())]);
+	}
+
+	#[test]
+	fn test_identity() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0
+		let expr = ArithExpr::Var(0);
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
+		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
+		assert_eq!(typed_circuit.degree(), 1);
+		assert_eq!(typed_circuit.n_vars(), 1);
+
+		assert_eq!(
+			typed_circuit
+				.evaluate(&[P::broadcast(F::new(123).into())])
+				.unwrap(),
+			P::broadcast(F::new(123).into())
+		);
+
+		let mut evals = [P::default()];
+		typed_circuit
+			.batch_evaluate(&[&[P::broadcast(F::new(123).into())]], &mut evals)
+			.unwrap();
+		assert_eq!(evals, [P::broadcast(F::new(123).into())]);
+	}
+
+	#[test]
+	fn test_add() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// 123 + x0
+		let expr = ArithExpr::Const(F::new(123)) + ArithExpr::Var(0);
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
+		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
+		assert_eq!(typed_circuit.degree(), 1);
+		assert_eq!(typed_circuit.n_vars(), 1);
+
+		assert_eq!(
+			CompositionPoly::evaluate(&circuit, &[P::broadcast(F::new(0).into())]).unwrap(),
+			P::broadcast(F::new(123).into())
+		);
+	}
+
+	#[test]
+	fn test_monomial() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		let monomial = ArithCircuitPoly::<F>::monomial(&[2, 0, 3]);
+		assert_eq!(CompositionPoly::n_vars(&monomial), 3);
+		assert_eq!(CompositionPoly::degree(&monomial), 5);
+
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+			P::from_scalars(felts!(BinaryField16b[8, 9, 10, 11, 12, 13, 14, 15])),
+			P::from_scalars(felts!(BinaryField16b[1, 2, 3, 4, 100, 101, 102, 103])),
+		];
+		let expected = P::from_fn(|i| query[0].get(i).square() * query[2].get(i).pow([3]));
+		assert_eq!(CompositionPoly::evaluate(&monomial, &query).unwrap(), expected);
+
+		let one = ArithCircuitPoly::<F>::monomial(&[0, 0]);
+		assert_eq!(CompositionPoly::n_vars(&one), 2);
+		assert_eq!(CompositionPoly::degree(&one), 0);
+		assert_eq!(CompositionPoly::evaluate(&one, &query[..2]).unwrap(), P::one());
+	}
+
+	#[test]
+	fn test_sub() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0 * x1 - 123
+		let expr = ArithExpr::Var(0) * ArithExpr::Var(1) - ArithExpr::Const(F::new(123));
+		assert!(matches!(expr, ArithExpr::Sub(..)));
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
+		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
+		assert_eq!(typed_circuit.degree(), 2);
+		assert_eq!(typed_circuit.n_vars(), 2);
+
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+			P::from_scalars(felts!(BinaryField16b[1, 1, 1, 1, 9, 9, 9, 9])),
+		];
+		let expected =
+			P::from_fn(|i| query[0].get(i) * query[1].get(i) - BinaryField16b::from(F::new(123)));
+		assert_eq!(CompositionPoly::evaluate(&circuit, &query).unwrap(), expected);
+
+		let mut evals = [P::default()];
+		CompositionPoly::batch_evaluate(&circuit, &[&query[..1], &query[1..]], &mut evals).unwrap();
+		assert_eq!(evals, [expected]);
+	}
+
+	#[test]
+	fn test_mul() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// 123 * x0
+		let expr = ArithExpr::Const(F::new(123)) * ArithExpr::Var(0);
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
+		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
+		assert_eq!(typed_circuit.degree(), 1);
+		assert_eq!(typed_circuit.n_vars(), 1);
+
+		assert_eq!(
+			CompositionPoly::evaluate(
+				&circuit,
+				&[P::from_scalars(
+					felts!(BinaryField16b[0, 1, 2, 3, 122, 123, 124, 125]),
+				)]
+			)
+			.unwrap(),
+			P::from_scalars(felts!(BinaryField16b[0, 123, 157, 230, 85, 46, 154, 225])),
+		);
+	}
+
+	#[test]
+	fn test_as_constant() {
+		type F = BinaryField8b;
+
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Const(F::new(123)));
+		assert_eq!(circuit.as_constant(), Some(F::new(123)));
+
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Var(0));
+		assert_eq!(circuit.as_constant(), None);
+
+		// x0 * 0 + 3^2 folds to a constant
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0) * ArithExpr::zero() + ArithExpr::Const(F::new(3)).pow(2),
+		);
+		assert_eq!(circuit.as_constant(), Some(F::new(3) * F::new(3)));
+	}
+
+	#[test]
+	fn test_pow() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0^13
+		let expr = ArithExpr::Var(0).pow(13);
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
+		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
+		assert_eq!(typed_circuit.degree(), 13);
+		assert_eq!(typed_circuit.n_vars(), 1);
+
+		assert_eq!(
+			CompositionPoly::evaluate(
+				&circuit,
+				&[P::from_scalars(
+					felts!(BinaryField16b[0, 1, 2, 3, 122, 123, 124, 125]),
+				)]
+			)
+			.unwrap(),
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 200, 52, 51, 115])),
+		);
+	}
+
+	#[test]
+	fn test_evaluate_checked_degree() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0^13
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).pow(13));
+		let query = [P::from_scalars(
+			felts!(BinaryField16b[0, 1, 2, 3, 122, 123, 124, 125]),
+		)];
+
+		assert_eq!(
+			circuit.evaluate_checked_degree(&query, 0, 13).unwrap(),
+			CompositionPoly::evaluate(&circuit, &query).unwrap()
+		);
+		assert!(matches!(
+			circuit.evaluate_checked_degree(&query, 0, 5),
+			Err(Error::DegreeBoundExceeded {
+				var: 0,
+				degree: 13,
+				max_degree: 5
+			})
+		));
+		assert!(matches!(
+			circuit.evaluate_checked_degree(&query, 1, 13),
+			Err(Error::ArgumentRangeError { .. })
+		));
+	}
+
+	#[test]
+	fn test_sensitivity() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0 * x2 + 7, which does not read x1
+		let circuit = ArithCircuitPoly::<F>::with_n_vars(
+			3,
+			ArithExpr::Var(0) * ArithExpr::Var(2) + ArithExpr::Const(F::new(7)),
+		)
+		.unwrap();
+		let x0 = P::from_scalars(felts!(BinaryField16b[1, 2, 3, 4, 5, 6, 7, 8]));
+		let x1 = P::from_scalars(felts!(BinaryField16b[9, 10, 11, 12, 13, 14, 15, 16]));
+		let x2 = P::from_scalars(felts!(BinaryField16b[0, 17, 18, 19, 20, 21, 22, 23]));
+
+		let sensitivity = circuit.sensitivity(&[x0, x1, x2]).unwrap();
+		assert_eq!(sensitivity, vec![x0 * x2, P::zero(), x0 * x2]);
+		assert_eq!(sensitivity[0].get(0), BinaryField16b::ZERO);
+
+		assert!(matches!(
+			circuit.sensitivity(&[x0, x1]),
+			Err(Error::IncorrectQuerySize { expected: 3 })
+		));
+	}
+
+	#[test]
+	fn test_pow_schedules_are_shared() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		let expr = ArithExpr::Var(0).pow(13)
+			+ ArithExpr::Var(1).pow(13) * ArithExpr::Var(2).pow(13)
+			+ ArithExpr::Var(0).pow(5);
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+		assert_eq!(circuit.pow_schedules.len(), 2);
+
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+			P::from_scalars(felts!(BinaryField16b[10, 11, 12, 13, 14, 15, 16, 17])),
+			P::from_scalars(felts!(BinaryField16b[20, 21, 22, 23, 24, 25, 26, 27])),
+		];
+		let expected = query[0].pow_u64(13)
+			+ query[1].pow_u64(13) * query[2].pow_u64(13)
+			+ query[0].pow_u64(5);
+		assert_eq!(CompositionPoly::evaluate(&circuit, &query).unwrap(), expected);
+
+		let batch_query = query.map(|q| vec![q; 2]);
+		let batch_query = batch_query.iter().map(Vec::as_slice).collect::<Vec<_>>();
+		let mut evals = [P::zero(); 2];
+		CompositionPoly::batch_evaluate(&circuit, &batch_query, &mut evals).unwrap();
+		assert_eq!(evals, [expected; 2]);
+	}
+
+	#[test]
+	fn test_pow_evaluate_at_infinity() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0^13
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).pow(13));
+		assert_eq!(circuit.evaluate_at_infinity::<P>(0, &[]).unwrap(), P::one());
+
+		// x0^2 * (x1 + 123), which has leading coefficient x1 + 123 in x0 and x0^2 in x1
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123))),
+		);
+		let x1 = P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7]));
+		assert_eq!(
+			circuit.evaluate_at_infinity(0, &[x1]).unwrap(),
+			x1 + P::broadcast(BinaryField16b::new(123))
+		);
+		let x0 = x1;
+		assert_eq!(circuit.evaluate_at_infinity(1, &[x0]).unwrap(), x0.square());
+
+		assert!(circuit.evaluate_at_infinity(2, &[x1]).is_err());
+		assert!(circuit.evaluate_at_infinity(0, &[x1, x1]).is_err());
+	}
+
+	#[test]
+	fn test_evaluate_at_infinity_degree_above_field_size() {
+		type FE = BinaryField16b;
+
+		// The coefficient of X^d of a univariate polynomial of degree at most d, interpolated from
+		// its evaluations at d + 1 points of FE.
+		fn interpolate_leading_coeff(degree: usize, eval: impl Fn(FE) -> FE) -> FE {
+			let points = (0..=degree as u16).map(FE::new).collect::<Vec<_>>();
+			points
+				.iter()
+				.map(|&point| {
+					let denominator = points
+						.iter()
+						.filter(|&&other| other != point)
+						.map(|&other| point - other)
+						.product::<FE>();
+					eval(point) * denominator.invert().unwrap()
+				})
+				.sum()
+		}
+
+		fn check<F: TowerField>(expr: ArithExpr<F>, fixed: FE, expected: FE)
+		where
+			FE: ExtensionField<F>,
+		{
+			let circuit = ArithCircuitPoly::<F>::with_n_vars(2, expr).unwrap();
+			let degree = circuit.degrees_by_var()[0];
+			assert!(degree >= 1 << F::TOWER_LEVEL);
+
+			let at_infinity = circuit.evaluate_at_infinity::<FE>(0, &[fixed]).unwrap();
+			assert_eq!(at_infinity, expected);
+			let interpolated = interpolate_leading_coeff(degree, |x| {
+				CompositionPoly::evaluate(&circuit, &[x, fixed]).unwrap()
+			});
+			assert_eq!(at_infinity, interpolated);
+		}
+
+		let x1 = FE::new(0x1234);
+
+		// x0^3 * x1 + x0 over GF(2), with leading coefficient x1 in x0
+		check(
+			ArithExpr::<BinaryField1b>::Var(0).pow(3) * ArithExpr::Var(1) + ArithExpr::Var(0),
+			x1,
+			x1,
+		);
+		// (x0 + x1)^2 * (x0 + 1)^3 over GF(2), with leading coefficient one
+		check(
+			(ArithExpr::<BinaryField1b>::Var(0) + ArithExpr::Var(1)).pow(2)
+				* (ArithExpr::Var(0) + ArithExpr::one()).pow(3),
+			x1,
+			FE::ONE,
+		);
+		// x0^5 * 3 + x0^5 * x1 over GF(4), with leading coefficient 3 + x1
+		check(
+			ArithExpr::<BinaryField2b>::Var(0).pow(5) * ArithExpr::Const(BinaryField2b::from(3))
+				+ ArithExpr::Var(0).pow(5) * ArithExpr::Var(1),
+			x1,
+			FE::new(3) + x1,
+		);
+		// x0^4 * x1 - x0^4 over GF(4), whose leading terms cancel at x1 = 1
+		check(
+			ArithExpr::<BinaryField2b>::Var(0).pow(4) * ArithExpr::Var(1)
+				- ArithExpr::Var(0).pow(4),
+			FE::ONE,
+			FE::ZERO,
+		);
+	}
+
+	#[test]
+	fn test_evaluate_with_bindings() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0 * x1 + x2
+		let circuit =
+			ArithCircuitPoly::<F>::new(ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Var(2));
+		// (x0 + x2)^2
+		let inner =
+			ArithCircuitPoly::<F>::with_n_vars(3, (ArithExpr::Var(0) + ArithExpr::Var(2)).pow(2))
+				.unwrap();
+		// x0 * (x0 + x2)^2 + x2
+		let composed = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0) * (ArithExpr::Var(0) + ArithExpr::Var(2)).pow(2) + ArithExpr::Var(2),
+		);
+
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+			P::from_scalars(felts!(BinaryField16b[8, 9, 10, 11, 12, 13, 14, 15])),
+			P::from_scalars(felts!(BinaryField16b[100, 101, 102, 103, 104, 105, 106, 107])),
+		];
+		assert_eq!(
+			circuit
+				.evaluate_with_bindings(&query, &[(1, &inner)])
+				.unwrap(),
+			CompositionPoly::evaluate(&composed, &query).unwrap()
+		);
+		assert_eq!(
+			circuit.evaluate_with_bindings(&query, &[]).unwrap(),
+			CompositionPoly::evaluate(&circuit, &query).unwrap()
+		);
+
+		assert!(circuit
+			.evaluate_with_bindings(&query, &[(3, &inner)])
+			.is_err());
+		assert!(circuit
+			.evaluate_with_bindings(&query[..2], &[(1, &inner)])
+			.is_err());
+	}
+
+	#[test]
+	fn test_evaluate_accumulate() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0^2 * (x1 + 123)
+		let expr = ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)));
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let queries = repeat_with(|| [P::random(&mut rng), P::random(&mut rng)])
+			.take(8)
+			.collect::<Vec<_>>();
+
+		let mut acc = P::zero();
+		let mut expected = P::zero();
+		for query in &queries {
+			circuit.evaluate_accumulate(query, &mut acc).unwrap();
+			expected += CompositionPoly::evaluate(&circuit, query).unwrap();
+		}
+		assert_eq!(acc, expected);
+
+		assert!(circuit
+			.evaluate_accumulate(&queries[0][..1], &mut acc)
+			.is_err());
+		assert_eq!(acc, expected);
+	}
+
+	#[test]
+	fn test_evaluate_scalar_at() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0^2 * (x1 + 123)
+		let expr = ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)));
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let query = [
+				BinaryField16b::new(rng.gen()),
+				BinaryField16b::new(rng.gen()),
+			];
+			assert_eq!(
+				circuit.evaluate_scalar_at::<P>(&query).unwrap(),
+				CompositionPoly::evaluate(&circuit, &query).unwrap()
+			);
+		}
+
+		assert!(circuit
+			.evaluate_scalar_at::<P>(&[BinaryField16b::ONE])
+			.is_err());
+	}
+
+	#[test]
+	fn test_evaluate_lane_major() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0^2 * (x1 + 123)
+		let expr = ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)));
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let n_packed = 4;
+		let len = n_packed * P::WIDTH;
+		let n_lanes = 4;
+		let mut rng = StdRng::seed_from_u64(0);
+		let columns = repeat_with(|| {
+			repeat_with(|| BinaryField16b::new(rng.gen()))
+				.take(len)
+				.collect::<Vec<_>>()
+		})
+		.take(2)
+		.collect::<Vec<_>>();
+
+		let lane_major = columns
+			.iter()
+			.map(|column| {
+				(0..n_lanes)
+					.flat_map(|lane| column.iter().skip(lane).step_by(n_lanes).copied())
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let lane_major = lane_major.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let packed = columns
+			.iter()
+			.map(|column| {
+				column
+					.chunks(P::WIDTH)
+					.map(|chunk| P::from_scalars(chunk.iter().copied()))
+					.collect::<Vec<_>>()
+			})
+			.collect::<Vec<_>>();
+		let mut expected = vec![P::zero(); n_packed];
+		CompositionPoly::batch_evaluate(
+			&circuit,
+			&packed.iter().map(Vec::as_slice).collect::<Vec<_>>(),
+			&mut expected,
+		)
+		.unwrap();
+
+		let mut out = vec![P::zero(); n_packed];
+		circuit
+			.evaluate_lane_major(&lane_major, n_lanes, &mut out)
+			.unwrap();
+		assert_eq!(out, expected);
+
+		assert!(circuit
+			.evaluate_lane_major(&lane_major, 3, &mut out)
+			.is_err());
+		assert!(circuit
+			.evaluate_lane_major(&lane_major[..1], n_lanes, &mut out)
+			.is_err());
+		assert!(circuit
+			.evaluate_lane_major(&lane_major, n_lanes, &mut out[..2])
+			.is_err());
+	}
+
+	#[test]
+	fn test_compute_degree() {
+		type F = BinaryField8b;
+
+		let exprs = [
+			ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123))),
+			ArithExpr::Var(0).pow(13),
+			(ArithExpr::Var(2) * ArithExpr::Var(3) + ArithExpr::Var(0)).pow(3),
+			ArithExpr::Const(F::new(5)),
+			ArithExpr::Var(1) - ArithExpr::Var(0).pow(2) * ArithExpr::Const(F::new(3)).inv(),
+		];
+		for expr in &exprs {
+			let circuit = ArithCircuitPoly::new(expr.clone());
+			assert_eq!(compute_degree(std::slice::from_ref(expr)), expr.degree());
+			assert_eq!(
+				compute_degree(std::slice::from_ref(expr)),
+				CompositionPoly::degree(&circuit)
+			);
+		}
+		assert_eq!(compute_degree(&exprs), 13);
+		assert_eq!(compute_degree::<F>(&[]), 0);
+
+		let non_polynomial = ArithExpr::<F>::Var(0) * ArithExpr::Var(1).inv();
+		assert_eq!(compute_degree(&[non_polynomial.clone()]), usize::MAX);
+		assert_eq!(compute_degree(&[non_polynomial.pow(2)]), usize::MAX);
+	}
+
+	#[test]
+	fn test_derivative() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		let query = [
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 122, 123, 124, 125])),
+			P::from_scalars(felts!(BinaryField16b[8, 9, 10, 11, 12, 13, 14, 15])),
+		];
+
+		// d/dx (x^2) = 0
+		let derivative = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).pow(2)).derivative(0);
+		assert_eq!(CompositionPoly::degree(&derivative), 0);
+		assert_eq!(CompositionPoly::n_vars(&derivative), 1);
+		assert_eq!(CompositionPoly::evaluate(&derivative, &query[..1]).unwrap(), P::zero());
+
+		// d/dx (x^3) = x^2
+		let derivative = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).pow(3)).derivative(0);
+		let expected = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).pow(2));
+		assert_eq!(CompositionPoly::degree(&derivative), 2);
+		assert_eq!(
+			CompositionPoly::evaluate(&derivative, &query[..1]).unwrap(),
+			CompositionPoly::evaluate(&expected, &query[..1]).unwrap()
+		);
+
+		// d/dx0 (x0^3 * (x1 + 123) + x0 * x1) = x0^2 * (x1 + 123) + x1
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(3) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)))
+				+ ArithExpr::Var(0) * ArithExpr::Var(1),
+		);
+		let expected = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)))
+				+ ArithExpr::Var(1),
+		);
+		assert_eq!(
+			CompositionPoly::evaluate(&circuit.derivative(0), &query).unwrap(),
+			CompositionPoly::evaluate(&expected, &query).unwrap()
+		);
+		// d/dx1 (...) = x0^3 + x0
+		let expected = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).pow(3) + ArithExpr::Var(0));
+		assert_eq!(
+			CompositionPoly::evaluate(&circuit.derivative(1), &query).unwrap(),
+			CompositionPoly::evaluate(&expected, &query[..1]).unwrap()
+		);
+	}
+
+	#[test]
+	fn test_partial_evaluator() {
+		type F = BinaryField8b;
+
+		// x0^2 * (x1 + 123) + x2^3 * x0
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)))
+				+ ArithExpr::Var(2).pow(3) * ArithExpr::Var(0),
+		);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(3)
+			.collect::<Vec<_>>();
+		let expected = CompositionPoly::evaluate(&circuit, &point).unwrap();
+
+		let mut evaluator = PartialEvaluator::new(&circuit);
+		for var in [2, 0, 1] {
+			assert!(evaluator.clone().finish::<F>().is_err());
+			evaluator = evaluator.bind(var, point[var]).unwrap();
+		}
+		assert_eq!(CompositionPoly::degree(&evaluator.circuit()), 0);
+		assert_eq!(evaluator.finish::<F>().unwrap(), expected);
+
+		// The residual circuit agrees with the original one on the bound variables.
+		let evaluator = PartialEvaluator::new(&circuit).bind(1, point[1]).unwrap();
+		assert_eq!(CompositionPoly::evaluate(&evaluator.circuit(), &point).unwrap(), expected);
+
+		assert!(PartialEvaluator::new(&circuit).bind(3, point[0]).is_err());
+	}
+
+	#[test]
+	fn test_bound_composition() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)))
+				+ ArithExpr::Var(2),
+		);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let columns = repeat_with(|| {
+			repeat_with(|| P::random(&mut rng))
+				.take(4)
+				.collect::<Vec<_>>()
+		})
+		.take(3)
+		.collect::<Vec<_>>();
+		let bound =
+			BoundComposition::new(&circuit, columns.iter().map(Vec::as_slice).collect()).unwrap();
+		assert_eq!(bound.n_rows(), 4);
+
+		for row in 0..4 {
+			let query = columns.iter().map(|column| column[row]).collect::<Vec<_>>();
+			assert_eq!(
+				bound.evaluate_row(row).unwrap(),
+				CompositionPoly::evaluate(&circuit, &query).unwrap()
+			);
+		}
+		assert!(bound.evaluate_row(4).is_err());
+
+		let mut evals = [P::zero(); 3];
+		bound.evaluate_rows(1..4, &mut evals).unwrap();
+		for (row, eval) in (1..4).zip(evals) {
+			assert_eq!(eval, bound.evaluate_row(row).unwrap());
+		}
+		assert!(bound.evaluate_rows(2..5, &mut evals).is_err());
+		assert!(bound.evaluate_rows(0..2, &mut evals).is_err());
+
+		let too_few = columns[..2].iter().map(Vec::as_slice).collect();
+		assert!(BoundComposition::new(&circuit, too_few).is_err());
+		let ragged = vec![&columns[0][..], &columns[1][..], &columns[2][..3]];
+		assert!(BoundComposition::new(&circuit, ragged).is_err());
+	}
+
+	#[test]
+	fn test_evaluate_over_domain() {
+		type F = BinaryField8b;
+
+		// x0^2 + 3 * x0 + 5
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(2)
+				+ ArithExpr::Const(F::new(3)) * ArithExpr::Var(0)
+				+ ArithExpr::Const(F::new(5)),
+		);
+
+		let domain = (0..8).map(F::new).collect::<Vec<_>>();
+		let expected = domain
+			.iter()
+			.map(|&x| x.square() + F::new(3) * x + F::new(5))
+			.collect::<Vec<_>>();
+		assert_eq!(circuit.evaluate_over_domain(&domain).unwrap(), expected);
+		assert_eq!(circuit.evaluate_over_domain(&[]).unwrap(), vec![]);
+
+		let bivariate = ArithCircuitPoly::<F>::new(ArithExpr::Var(0) * ArithExpr::Var(1));
+		assert!(matches!(
+			bivariate.evaluate_over_domain(&domain),
+			Err(Error::IncorrectNumberOfVariables {
+				expected: 1,
+				actual: 2
+			})
+		));
+	}
+
+	#[test]
+	fn test_remap_variables() {
+		type F = BinaryField8b;
+
+		// x0^2 * (x1 + 123)
+		let expr = ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)));
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let swapped = circuit.remap_variables(&[1, 0]).unwrap();
+		assert_eq!(CompositionPoly::n_vars(&swapped), 2);
+		assert_eq!(swapped.degrees_by_var(), vec![1, 2]);
+		assert_eq!(swapped.to_string_expr(&[]), "x1^2 * (x0 + 123)");
+
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..10 {
+			let a = <F as Field>::random(&mut rng);
+			let b = <F as Field>::random(&mut rng);
+			assert_eq!(
+				CompositionPoly::evaluate(&swapped, &[a, b]).unwrap(),
+				CompositionPoly::evaluate(&circuit, &[b, a]).unwrap()
+			);
+		}
+
+		let spread = circuit.remap_variables(&[3, 1]).unwrap();
+		assert_eq!(CompositionPoly::n_vars(&spread), 4);
+
+		assert!(matches!(
+			circuit.remap_variables(&[0]),
+			Err(Error::IncorrectArgumentLength { expected: 2, .. })
+		));
+	}
+
+	#[test]
+	fn test_sum_over_var() {
+		type F = BinaryField8b;
+
+		// x0^2 * (x1 + 123) + x2^3 * x0
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)))
+				+ ArithExpr::Var(2).pow(3) * ArithExpr::Var(0),
+		);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let point = repeat_with(|| <F as Field>::random(&mut rng))
+			.take(2)
+			.collect::<Vec<_>>();
+		for var in 0..3 {
+			let summed = circuit.sum_over_var(var).unwrap();
+			assert_eq!(CompositionPoly::n_vars(&summed), 2);
+
+			let expected = [F::ZERO, F::ONE]
+				.into_iter()
+				.map(|b| {
+					let mut query = point.clone();
+					query.insert(var, b);
+					CompositionPoly::evaluate(&circuit, &query).unwrap()
+				})
+				.sum::<F>();
+			assert_eq!(CompositionPoly::evaluate(&summed, &point).unwrap(), expected);
+		}
+
+		assert!(circuit.sum_over_var(3).is_err());
+	}
+
+	#[test]
+	fn test_mixed() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0^2 * (x1 + 123)
+		let expr = ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)));
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
+		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
+		assert_eq!(typed_circuit.degree(), 3);
+		assert_eq!(typed_circuit.n_vars(), 2);
+		assert_eq!(circuit.degrees_by_var(), vec![2, 1]);
+		assert_eq!(circuit.to_string_expr(&[]), "x0^2 * (x1 + 123)");
+		assert_eq!(circuit.to_string_expr(&["a", "b"]), "a^2 * (b + 123)");
+
+		// test evaluate
+		assert_eq!(
+			CompositionPoly::evaluate(
+				&circuit,
+				&[
+					P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+					P::from_scalars(felts!(BinaryField16b[100, 101, 102, 103, 104, 105, 106, 107])),
+				]
+			)
+			.unwrap(),
+			P::from_scalars(felts!(BinaryField16b[0, 30, 59, 36, 151, 140, 170, 176])),
+		);
+
+		// test batch evaluate
+		let query1 = &[
+			P::from_scalars(felts!(BinaryField16b[0, 0, 0, 0, 0, 0, 0, 0])),
+			P::from_scalars(felts!(BinaryField16b[0, 0, 0, 0, 0, 0, 0, 0])),
+		];
+		let query2 = &[
+			P::from_scalars(felts!(BinaryField16b[1, 1, 1, 1, 1, 1, 1, 1])),
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+		];
+		let query3 = &[
+			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
+			P::from_scalars(felts!(BinaryField16b[100, 101, 102, 103, 104, 105, 106, 107])),
+		];
+		let expected1 = P::from_scalars(felts!(BinaryField16b[0, 0, 0, 0, 0, 0, 0, 0]));
+		let expected2 =
+			P::from_scalars(felts!(BinaryField16b[123, 122, 121, 120, 127, 126, 125, 124]));
+		let expected3 = P::from_scalars(felts!(BinaryField16b[0, 30, 59, 36, 151, 140, 170, 176]));
+
+		let mut batch_result = vec![P::zero(); 3];
+		CompositionPoly::batch_evaluate(
+			&circuit,
+			&[
+				&[query1[0], query2[0], query3[0]],
+				&[query1[1], query2[1], query3[1]],
+			],
+			&mut batch_result,
+		)
+		.unwrap();
+		assert_eq!(&batch_result, &[expected1, expected2, expected3]);
+	}
+
+	#[test]
+	fn test_min_constant_tower_level() {
+		type F = binius_field::BinaryField128b;
+
+		let no_constants = ArithCircuitPoly::<F>::new(ArithExpr::Var(0) * ArithExpr::Var(1));
+		assert_eq!(no_constants.min_constant_tower_level(), 0);
+
+		// x0 * x1 + x0 + 1
+		let binary_constants = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0) * ArithExpr::Var(1)
+				+ ArithExpr::Var(0) * ArithExpr::Const(F::ONE)
+				+ ArithExpr::Const(F::ONE),
+		);
+		assert_eq!(binary_constants.min_constant_tower_level(), 0);
+
+		// x0^2 * (x1 + 123)
+		let byte_constant = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(2)
+				* (ArithExpr::Var(1) + ArithExpr::Const(BinaryField8b::new(123).into())),
+		);
+		assert_eq!(byte_constant.min_constant_tower_level(), 3);
+	}
+
+	#[test]
+	fn test_from_coefficients() {
+		type F = BinaryField8b;
+
+		// 3 * x0^2 * x2 + 5 * x1
+		let circuit = ArithCircuitPoly::<F>::from_coefficients(
+			3,
+			&[(vec![2, 0, 1], F::new(3)), (vec![0, 1, 0], F::new(5))],
+		)
+		.unwrap();
+		assert_eq!(CompositionPoly::n_vars(&circuit), 3);
+		assert_eq!(CompositionPoly::degree(&circuit), 3);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..8 {
+			let query: [F; 3] = std::array::from_fn(|_| <F as Field>::random(&mut rng));
+			let expected = F::new(3) * query[0].square() * query[2] + F::new(5) * query[1];
+			assert_eq!(CompositionPoly::evaluate(&circuit, &query).unwrap(), expected);
+		}
+
+		assert!(matches!(
+			ArithCircuitPoly::<F>::from_coefficients(3, &[(vec![1, 1], F::ONE)]),
+			Err(Error::IncorrectArgumentLength { expected: 3, .. })
+		));
+	}
+
+	#[test]
+	fn test_evaluate_layer() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// A multiplication gate and an addition gate over inputs (a0, a1) and (b0, b1)
+		let [a0, a1, b0, b1] = [0, 1, 2, 3].map(ArithExpr::<F>::Var);
+		let circuit = ArithCircuitPoly::new(a0 * b0 + a1 + b1);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let left = repeat_with(|| P::random(&mut rng))
+			.take(6)
+			.collect::<Vec<_>>();
+		let right = repeat_with(|| P::random(&mut rng))
+			.take(6)
+			.collect::<Vec<_>>();
+		let mut out = [P::zero(); 3];
+		circuit.evaluate_layer(&left, &right, &mut out).unwrap();
+		for row in 0..3 {
+			let expected = left[2 * row] * right[2 * row] + left[2 * row + 1] + right[2 * row + 1];
+			assert_eq!(out[row], expected);
+		}
+
+		assert!(matches!(
+			circuit.evaluate_layer(&left[..4], &right, &mut out),
+			Err(Error::IncorrectArgumentLength { expected: 6, .. })
+		));
+		let odd = ArithCircuitPoly::<F>::new(ArithExpr::Var(2));
+		assert!(matches!(
+			odd.evaluate_layer(&left, &right, &mut out),
+			Err(Error::OddNumberOfVariables { n_vars: 3 })
+		));
+	}
+
+	fn check_components_recombine<F: TowerField>(
+		circuit: &ArithCircuitPoly<F>,
+		components: &[ArithCircuitPoly<F>],
+		op: CombineOp,
+	) {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..8 {
+			let query: Vec<F> = (0..CompositionPoly::n_vars(circuit))
+				.map(|_| <F as Field>::random(&mut rng))
+				.collect();
+			let combined = op.combine(components.iter().map(|component| {
+				assert_eq!(CompositionPoly::n_vars(component), CompositionPoly::n_vars(circuit));
+				CompositionPoly::evaluate(component, &query).unwrap()
+			}));
+			assert_eq!(combined, CompositionPoly::evaluate(circuit, &query).unwrap());
+		}
+	}
+
+	#[test]
+	fn test_connected_components() {
+		type F = BinaryField8b;
+
+		let [x0, x1, x2, x3] = [0, 1, 2, 3].map(ArithExpr::<F>::Var);
+
+		// (x0 + x1) * 3 * (x2 * x3 + x2) splits into (x0 + x1) * 3 and x2 * x3 + x2
+		let circuit = ArithCircuitPoly::new(
+			(x0.clone() + x1.clone())
+				* ArithExpr::Const(F::new(3))
+				* (x2.clone() * x3.clone() + x2.clone()),
+		);
+		let (op, components) = circuit.connected_components();
+		assert_eq!(op, CombineOp::Product);
+		assert_eq!(components.len(), 2);
+		assert_eq!(components[0].expr, (x0.clone() + x1.clone()) * ArithExpr::Const(F::new(3)));
+		assert_eq!(components[1].expr, x2.clone() * x3.clone() + x2.clone());
+		check_components_recombine(&circuit, &components, op);
+
+		// Operands linked through a shared variable stay together
+		let circuit = ArithCircuitPoly::new(
+			x0.clone() * x1.clone() + x2.clone() + ArithExpr::Const(F::new(5)) + x1.clone() * x3,
+		);
+		let (op, components) = circuit.connected_components();
+		assert_eq!(op, CombineOp::Sum);
+		assert_eq!(components.len(), 2);
+		check_components_recombine(&circuit, &components, op);
+
+		let circuit = ArithCircuitPoly::new((x0.clone() + x2.clone()).pow(2) * x2);
+		let (op, components) = circuit.connected_components();
+		assert_eq!(components.len(), 1);
+		check_components_recombine(&circuit, &components, op);
+
+		let circuit = ArithCircuitPoly::new((x0 + x1).pow(3));
+		let (op, components) = circuit.connected_components();
+		assert_eq!(components.len(), 1);
+		check_components_recombine(&circuit, &components, op);
+	}
+
+	#[test]
+	fn test_evaluate_mixed() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0 * x1 + x0, with x0 a bit column and x1 a byte column
+		let circuit =
+			ArithCircuitPoly::<F>::new(ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Var(0));
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let bits: [BinaryField1b; 8] =
+			std::array::from_fn(|_| <BinaryField1b as Field>::random(&mut rng));
+		let bytes: [F; 8] = std::array::from_fn(|_| <F as Field>::random(&mut rng));
+
+		let result = circuit
+			.evaluate_mixed::<P>(&[MixedQuery::B1(&bits), MixedQuery::B8(&bytes)])
+			.unwrap();
+		for i in 0..P::WIDTH {
+			let bit = BinaryField16b::from(bits[i]);
+			let byte = BinaryField16b::from(bytes[i]);
+			assert_eq!(result.get(i), bit * byte + bit);
+		}
+
+		// The values are embedded into the 128-bit field through the subfield embedding
+		let result = circuit
+			.evaluate_mixed::<PackedBinaryField2x128b>(&[
+				MixedQuery::B1(&bits[..2]),
+				MixedQuery::B8(&bytes[..2]),
+			])
+			.unwrap();
+		for i in 0..2 {
+			let bit = BinaryField128b::from(bits[i]);
+			let byte = BinaryField128b::from(bytes[i]);
+			assert_eq!(result.get(i), bit * byte + bit);
+		}
+
+		// A 32-bit variable does not embed into the 16-bit evaluation field
+		let words = [BinaryField32b::ONE; 8];
+		assert!(matches!(
+			circuit.evaluate_mixed::<P>(&[MixedQuery::B1(&bits), MixedQuery::B32(&words)]),
+			Err(Error::QueryTowerLevelTooHigh {
+				index: 1,
+				tower_level: 5,
+				max_tower_level: 4,
+			})
+		));
+		assert!(matches!(
+			circuit.evaluate_mixed::<P>(&[MixedQuery::B1(&bits), MixedQuery::B8(&bytes[..4])]),
+			Err(Error::IncorrectArgumentLength { expected: 8, .. })
+		));
+		assert!(matches!(
+			circuit.evaluate_mixed::<P>(&[MixedQuery::B1(&bits)]),
+			Err(Error::IncorrectQuerySize { expected: 2 })
+		));
+	}
+
+	#[test]
+	fn test_deserialize_recomputes_shape() {
+		type F = BinaryField8b;
+
+		// x0^2 * (x1 + 123)
+		let expr = ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)));
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+
+		let mut bytes = Vec::new();
+		circuit.serialize(&mut bytes).unwrap();
+
+		let deserialized = ArithCircuitPoly::<F>::deserialize(bytes.as_slice()).unwrap();
+		assert_eq!(deserialized.expr, circuit.expr);
+		assert_eq!(CompositionPoly::degree(&deserialized), 3);
+		assert_eq!(CompositionPoly::n_vars(&deserialized), 2);
+
+		// The nodes are x0, x0^2, x1, 123, x1 + 123 and the product. Raise the exponent of the
+		// `Pow` node, which follows the 7 byte header, the n_vars and node count varints, the `Var`
+		// node and the `Pow` tag and operand, from 2 to 5. The degree follows the tampered
+		// expression.
+		let mut tampered = bytes.clone();
+		assert_eq!(tampered[13], 2);
+		tampered[13] = 5;
+		let deserialized = ArithCircuitPoly::<F>::deserialize(tampered.as_slice()).unwrap();
+		assert_eq!(CompositionPoly::degree(&deserialized), 6);
+		assert_eq!(deserialized.degree, compute_degree(std::slice::from_ref(&deserialized.expr)));
+
+		// Make the `Pow` node its own operand.
+		let mut tampered = bytes.clone();
+		tampered[12] = 1;
+		assert!(matches!(
+			ArithCircuitPoly::<F>::deserialize(tampered.as_slice()),
+			Err(SerializationError::NonPrecedingReference {
+				index: 1,
+				reference: 1,
+				..
+			})
+		));
+
+		// Claim fewer variables than the expression reads.
+		let mut tampered = bytes.clone();
+		tampered[7] = 1;
+		assert!(matches!(
+			ArithCircuitPoly::<F>::deserialize(tampered.as_slice()),
+			Err(SerializationError::InvalidConstruction { .. })
+		));
+
+		// Another kind of artifact is rejected.
+		let mut tampered = bytes.clone();
+		tampered[4] = ArtifactKind::Witness as u8;
+		assert!(matches!(
+			ArithCircuitPoly::<F>::deserialize(tampered.as_slice()),
+			Err(SerializationError::UnexpectedArtifactKind { .. })
+		));
+
+		// A stream written by a newer format version is rejected.
+		let mut tampered = bytes;
+		tampered[5] = 2;
+		assert!(matches!(
+			ArithCircuitPoly::<F>::deserialize(tampered.as_slice()),
+			Err(SerializationError::UnsupportedVersion { found: 2 })
+		));
+	}
+
+	#[test]
+	fn test_serialize_shares_nodes() {
+		type F = BinaryField8b;
+
+		// (x0 + x1) * (x0 + x1) is written as the nodes x0, x1, x0 + x1 and the product.
+		let sum = ArithExpr::<F>::Var(0) + ArithExpr::Var(1);
+		let circuit = ArithCircuitPoly::new(sum.clone() * sum);
+
+		let mut bytes = Vec::new();
+		circuit.serialize(&mut bytes).unwrap();
+		assert_eq!(bytes[8], 4);
+
+		let deserialized = ArithCircuitPoly::<F>::deserialize(bytes.as_slice()).unwrap();
+		assert_eq!(deserialized.expr, circuit.expr);
+	}
+
+	#[test]
+	fn test_deserialize_rejects_crafted_input() {
+		type F = BinaryField8b;
+
+		let header = |n_vars: u64, n_nodes: u64| {
+			let mut bytes = Vec::new();
+			write_header(&mut bytes, ArtifactKind::ArithCircuitPoly, 1).unwrap();
+			write_varint(&mut bytes, n_vars).unwrap();
+			write_varint(&mut bytes, n_nodes).unwrap();
+			bytes
+		};
+
+		// Node i is the sum of node i - 1 with itself, which expands to a tree of 2^41 - 1 nodes.
+		let mut bytes = header(1, 41);
+		bytes.extend([1, 0]);
+		for i in 1..41u8 {
+			bytes.extend([2, i - 1, i - 1]);
+		}
+		assert!(matches!(
+			ArithCircuitPoly::<F>::deserialize(bytes.as_slice()),
+			Err(SerializationError::InvalidConstruction { .. })
+		));
 
-fn pow<P: PackedField>(value: P, exp: u64) -> P {
-	let mut res = P::one();
-	for i in (0..64).rev() {
-		res = res.square();
-		if ((exp >> i) & 1) == 1 {
-			res.mul_assign(value)
+		// A chain of inverses nested deeper than the expression is processed.
+		let depth = MAX_DECODED_EXPR_DEPTH as u64 + 1;
+		let mut bytes = header(1, depth);
+		bytes.extend([1, 0]);
+		for i in 1..depth {
+			bytes.push(6);
+			write_varint(&mut bytes, i - 1).unwrap();
 		}
-	}
-	res
-}
+		assert!(matches!(
+			ArithCircuitPoly::<F>::deserialize(bytes.as_slice()),
+			Err(SerializationError::InvalidConstruction { .. })
+		));
 
-#[cfg(test)]
-mod tests {
-	use binius_field::{
-		BinaryField16b, BinaryField8b, PackedBinaryField8x16b, PackedField, TowerField,
-	};
-	use binius_math::CompositionPolyOS;
-	use binius_utils::felts;
+		// A variable index that would overflow the number of variables.
+		let mut bytes = header(1, 1);
+		bytes.push(1);
+		write_varint(&mut bytes, u64::MAX).unwrap();
+		assert!(matches!(
+			ArithCircuitPoly::<F>::deserialize(bytes.as_slice()),
+			Err(SerializationError::InvalidConstruction { .. })
+		));
 
-	use super::*;
+		// A number of variables too large to allocate per-variable data for.
+		let mut bytes = header(u64::MAX, 1);
+		bytes.extend([0, 1]);
+		assert!(matches!(
+			ArithCircuitPoly::<F>::deserialize(bytes.as_slice()),
+			Err(SerializationError::InvalidConstruction { .. })
+		));
+	}
 
 	#[test]
-	fn test_constant() {
+	fn test_deserialize_from_stream() {
+		use binius_utils::serialization::{IoReader, IoWriter};
+
 		type F = BinaryField8b;
-		type P = PackedBinaryField8x16b;
 
-		let expr = ArithExpr::Const(F::new(123));
-		let circuit = ArithCircuitPoly::<F>::new(expr);
+		// A balanced sum of 40000 variables has more nodes than the stream reader buffers.
+		let mut terms = (0..40000).map(ArithExpr::<F>::Var).collect::<Vec<_>>();
+		while terms.len() > 1 {
+			terms = terms
+				.chunks(2)
+				.map(|pair| pair.iter().cloned().sum())
+				.collect();
+		}
+		let circuit = ArithCircuitPoly::new(terms.pop().unwrap());
 
-		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
-		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
-		assert_eq!(typed_circuit.degree(), 0);
-		assert_eq!(typed_circuit.n_vars(), 0);
+		let mut writer = IoWriter::new(std::io::Cursor::new(Vec::new()));
+		circuit.serialize(&mut writer).unwrap();
+		let bytes = writer.finish().unwrap().into_inner();
 
-		assert_eq!(typed_circuit.evaluate(&[]).unwrap(), P::broadcast(F::new(123).into()));
+		let deserialized =
+			ArithCircuitPoly::<F>::deserialize(IoReader::new(bytes.as_slice())).unwrap();
+		assert_eq!(deserialized.expr, circuit.expr);
 
-		let mut evals = [P::default()];
-		typed_circuit.batch_evaluate(&[], &mut evals).unwrap();
-		assert_eq!(evals, [P::broadcast(F::new(123).into())]);
+		let truncated = IoReader::new(&bytes[..bytes.len() - 1]);
+		assert!(matches!(
+			ArithCircuitPoly::<F>::deserialize(truncated),
+			Err(SerializationError::NotEnoughBytes)
+		));
+	}
+
+	fn arb_expr() -> impl Strategy<Value = ArithExpr<BinaryField8b>> {
+		let leaf = prop_oneof![
+			any::<u8>().prop_map(|value| ArithExpr::Const(BinaryField8b::new(value))),
+			(0..300usize).prop_map(ArithExpr::Var),
+		];
+		leaf.prop_recursive(6, 64, 2, |inner| {
+			prop_oneof![
+				(inner.clone(), inner.clone()).prop_map(|(left, right)| left + right),
+				(inner.clone(), inner.clone()).prop_map(|(left, right)| left - right),
+				(inner.clone(), inner.clone()).prop_map(|(left, right)| left * right),
+				(inner.clone(), 0..1000u64).prop_map(|(base, exp)| base.pow(exp)),
+				inner.prop_map(ArithExpr::inv),
+			]
+		})
+	}
+
+	proptest! {
+		#[test]
+		fn test_serialize_round_trip(expr in arb_expr(), extra_vars in 0..4usize) {
+			let circuit =
+				ArithCircuitPoly::<BinaryField8b>::with_n_vars(expr.n_vars() + extra_vars, expr)
+					.unwrap();
+
+			let mut bytes = Vec::new();
+			circuit.serialize(&mut bytes).unwrap();
+			let deserialized = ArithCircuitPoly::<BinaryField8b>::deserialize(bytes.as_slice())
+				.unwrap();
+			prop_assert_eq!(&deserialized.expr, &circuit.expr);
+			prop_assert_eq!(&deserialized.steps, &circuit.steps);
+			prop_assert_eq!(deserialized.retval, circuit.retval);
+			prop_assert_eq!(deserialized.degree, circuit.degree);
+			prop_assert_eq!(deserialized.n_vars, circuit.n_vars);
+
+			prop_assert!(matches!(
+				ArithCircuitPoly::<BinaryField8b>::deserialize(&bytes[..bytes.len() - 1]),
+				Err(SerializationError::NotEnoughBytes)
+			));
+		}
 	}
 
 	#[test]
-	fn test_identity() {
+	fn test_sexpr_round_trip() {
 		type F = BinaryField8b;
-		type P = PackedBinaryField8x16b;
 
-		// x0
-		let expr = ArithExpr::Var(0);
+		// x0^2 * (x1 + 123)
+		let expr = ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)));
 		let circuit = ArithCircuitPoly::<F>::new(expr);
 
-		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
-		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
-		assert_eq!(typed_circuit.degree(), 1);
-		assert_eq!(typed_circuit.n_vars(), 1);
+		let sexpr = circuit.to_sexpr();
+		assert_eq!(sexpr, "(mul (pow (var 0) 2) (add (var 1) (const 123)))");
+
+		let parsed = ArithCircuitPoly::<F>::from_sexpr(&sexpr, 2).unwrap();
+		assert_eq!(parsed.expr, circuit.expr);
+		assert_eq!(CompositionPoly::n_vars(&parsed), 2);
+		assert_eq!(CompositionPoly::degree(&parsed), 3);
+
+		let spaced = ArithCircuitPoly::<F>::from_sexpr(
+			"  (mul\n\t(pow (var 0) 2)\n\t(add (var 1) (const 123)) ) ",
+			2,
+		)
+		.unwrap();
+		assert_eq!(spaced.expr, circuit.expr);
 
+		// Variables above the highest index read by the expression survive the round trip
+		let wide = ArithCircuitPoly::<F>::with_n_vars(5, circuit.expr.clone()).unwrap();
+		let parsed = ArithCircuitPoly::<F>::from_sexpr(&wide.to_sexpr(), 5).unwrap();
+		assert_eq!(parsed.expr, wide.expr);
+		assert_eq!(CompositionPoly::n_vars(&parsed), 5);
+		let query = [2, 3, 5, 7, 11].map(F::new);
 		assert_eq!(
-			typed_circuit
-				.evaluate(&[P::broadcast(F::new(123).into())])
-				.unwrap(),
-			P::broadcast(F::new(123).into())
+			CompositionPoly::evaluate(&parsed, &query).unwrap(),
+			CompositionPoly::evaluate(&wide, &query).unwrap()
 		);
 
-		let mut evals = [P::default()];
-		typed_circuit
-			.batch_evaluate(&[&[P::broadcast(F::new(123).into())]], &mut evals)
-			.unwrap();
-		assert_eq!(evals, [P::broadcast(F::new(123).into())]);
+		assert!(matches!(
+			ArithCircuitPoly::<F>::from_sexpr(&sexpr, 1),
+			Err(Error::IncorrectNumberOfVariables {
+				expected: 2,
+				actual: 1,
+			})
+		));
 	}
 
 	#[test]
-	fn test_add() {
+	fn test_sexpr_malformed() {
+		type F = BinaryField8b;
+
+		let error_at = |s: &str| match ArithCircuitPoly::<F>::from_sexpr(s, 1) {
+			Err(Error::MalformedSExpr { position, .. }) => position,
+			result => panic!("expected a parse error, got {result:?}"),
+		};
+
+		assert_eq!(error_at(""), 0);
+		assert_eq!(error_at("(var 0"), 6);
+		assert_eq!(error_at("(var x)"), 5);
+		assert_eq!(error_at("(var -1)"), 5);
+		assert_eq!(error_at("(const 256)"), 7);
+		assert_eq!(error_at("(div (var 0) (var 1))"), 1);
+		assert_eq!(error_at("(add (var 0))"), 12);
+		assert_eq!(error_at("(pow (var 0) 2.5)"), 13);
+		assert_eq!(error_at("(var 0) (var 1)"), 8);
+		assert_eq!(error_at("(var 65536)"), 5);
+		assert_eq!(error_at("(var 18446744073709551615)"), 5);
+
+		let nested =
+			|depth: usize| format!("{}(var 0){}", "(inv ".repeat(depth), ")".repeat(depth));
+		assert!(ArithCircuitPoly::<F>::from_sexpr(&nested(MAX_DECODED_EXPR_DEPTH - 1), 1).is_ok());
+		assert_eq!(error_at(&nested(MAX_DECODED_EXPR_DEPTH)), 5 * MAX_DECODED_EXPR_DEPTH + 1);
+	}
+
+	#[cfg(feature = "bench")]
+	#[test]
+	fn test_bench_throughput() {
 		type F = BinaryField8b;
 		type P = PackedBinaryField8x16b;
 
-		// 123 + x0
-		let expr = ArithExpr::Const(F::new(123)) + ArithExpr::Var(0);
+		let expr = ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)));
 		let circuit = ArithCircuitPoly::<F>::new(expr);
 
-		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
-		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
-		assert_eq!(typed_circuit.degree(), 1);
-		assert_eq!(typed_circuit.n_vars(), 1);
+		let throughput = circuit.bench_throughput::<P>(1 << 10);
+		assert!(throughput.is_finite());
+		assert!(throughput > 0.0);
+	}
+
+	#[test]
+	fn test_rebalance() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// x0 + x1 + ... + x7, built as a left-deep chain, times x8 * x9
+		let sum = (0..8).map(ArithExpr::Var).sum::<ArithExpr<F>>();
+		let expr = sum * (ArithExpr::Var(8) * ArithExpr::Var(9));
+		let circuit = ArithCircuitPoly::<F>::new(expr);
+		assert_eq!(circuit.depth(), 8);
 
+		let rebalanced = circuit.clone().rebalance();
+		assert_eq!(rebalanced.depth(), 4);
+		assert_eq!(CompositionPoly::degree(&rebalanced), 3);
+		assert_eq!(CompositionPoly::n_vars(&rebalanced), 10);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let query = repeat_with(|| P::random(&mut rng))
+			.take(10)
+			.collect::<Vec<_>>();
 		assert_eq!(
-			CompositionPoly::evaluate(&circuit, &[P::broadcast(F::new(0).into())]).unwrap(),
-			P::broadcast(F::new(123).into())
+			CompositionPoly::evaluate(&rebalanced, &query).unwrap(),
+			CompositionPoly::evaluate(&circuit, &query).unwrap()
 		);
 	}
 
 	#[test]
-	fn test_mul() {
+	fn test_dead_code_elimination() {
 		type F = BinaryField8b;
 		type P = PackedBinaryField8x16b;
 
-		// 123 * x0
-		let expr = ArithExpr::Const(F::new(123)) * ArithExpr::Var(0);
+		let expr = ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)));
 		let circuit = ArithCircuitPoly::<F>::new(expr);
+		let n_steps = circuit.steps.len();
 
-		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
-		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
-		assert_eq!(typed_circuit.degree(), 1);
-		assert_eq!(typed_circuit.n_vars(), 1);
+		// Prepend a step nobody refers to, shifting the slots of all the others.
+		let shift = |arg: CircuitStepArgument<F>| match arg {
+			CircuitStepArgument::Expr(CircuitNode::Slot(slot)) => {
+				CircuitStepArgument::Expr(CircuitNode::Slot(slot + 1))
+			}
+			arg => arg,
+		};
+		let unreferenced = CircuitStep::Mul(
+			CircuitStepArgument::Expr(CircuitNode::Var(0)),
+			CircuitStepArgument::Expr(CircuitNode::Var(1)),
+		);
+		let steps = std::iter::once(unreferenced)
+			.chain(circuit.steps.iter().map(|step| step.map_arguments(shift)))
+			.collect::<Vec<_>>();
+		let padded = ArithCircuitPoly {
+			steps: steps.into(),
+			retval: shift(circuit.retval),
+			..circuit.clone()
+		};
+		assert_eq!(padded.steps.len(), n_steps + 1);
+
+		let eliminated = padded.clone().dead_code_elimination();
+		assert_eq!(eliminated.steps.len(), n_steps);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let query = repeat_with(|| P::random(&mut rng))
+			.take(2)
+			.collect::<Vec<_>>();
+		let expected = CompositionPoly::evaluate(&circuit, &query).unwrap();
+		assert_eq!(CompositionPoly::evaluate(&padded, &query).unwrap(), expected);
+		assert_eq!(CompositionPoly::evaluate(&eliminated, &query).unwrap(), expected);
+	}
 
+	#[test]
+	fn test_common_subexpression_elimination() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// (x0^2 * x1 + x0^2 * x1) * (x0^2 + 7) + x0^2 * x1
+		let square = || ArithExpr::Var(0).pow(2);
+		let product = || square() * ArithExpr::Var(1);
+		let expr = (product() + product()) * (square() + ArithExpr::Const(F::new(7))) + product();
+		let circuit = ArithCircuitPoly::<F>::new(expr.clone());
+		let optimized = ArithCircuitPoly::<F>::new_optimized(expr);
+		assert_eq!(circuit.steps.len(), 11);
+		// x0^2, x0^2 * x1, the sum, x0^2 + 7, the product and the outer sum
+		assert_eq!(optimized.steps.len(), 6);
+		assert_eq!(CompositionPoly::degree(&optimized), CompositionPoly::degree(&circuit));
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let query = repeat_with(|| P::random(&mut rng))
+			.take(2)
+			.collect::<Vec<_>>();
 		assert_eq!(
-			CompositionPoly::evaluate(
-				&circuit,
-				&[P::from_scalars(
-					felts!(BinaryField16b[0, 1, 2, 3, 122, 123, 124, 125]),
-				)]
-			)
-			.unwrap(),
-			P::from_scalars(felts!(BinaryField16b[0, 123, 157, 230, 85, 46, 154, 225])),
+			CompositionPoly::evaluate(&optimized, &query).unwrap(),
+			CompositionPoly::evaluate(&circuit, &query).unwrap()
 		);
+
+		let columns = query.iter().map(std::slice::from_ref).collect::<Vec<_>>();
+		let mut evals = [P::default()];
+		CompositionPoly::batch_evaluate(&optimized, &columns, &mut evals).unwrap();
+		assert_eq!(evals[0], CompositionPoly::evaluate(&circuit, &query).unwrap());
 	}
 
 	#[test]
-	fn test_pow() {
+	fn test_is_zero_on_cube() {
+		type F = BinaryField8b;
+
+		// x0^2 + x0 vanishes on {0, 1} in characteristic 2
+		let circuit = ArithCircuitPoly::<F>::with_n_vars(
+			3,
+			ArithExpr::Var(0).pow(2) + ArithExpr::Var(0) + ArithExpr::Var(2).pow(3),
+		)
+		.unwrap();
+		assert!(!circuit.is_zero_on_cube());
+
+		let circuit = ArithCircuitPoly::<F>::with_n_vars(
+			3,
+			(ArithExpr::Var(0).pow(2) + ArithExpr::Var(0)) * ArithExpr::Var(2),
+		)
+		.unwrap();
+		assert!(circuit.is_zero_on_cube());
+
+		// Past the exhaustive limit the cube is probed at random points. In characteristic 2 each
+		// factor x_i^2 + x_i = x_i * (x_i + 1) vanishes at 0 and 1, so the product vanishes on the
+		// whole cube and every probe is zero. The sum of the variables is the parity of the point,
+		// which is one at half of the cube, so a probe finds a non-zero evaluation.
+		let n_vars = MAX_EXHAUSTIVE_CUBE_VARS + 4;
+		let circuit = ArithCircuitPoly::<F>::new(
+			(0..n_vars)
+				.map(|i| ArithExpr::Var(i).pow(2) + ArithExpr::Var(i))
+				.product(),
+		);
+		assert!(circuit.is_zero_on_cube());
+		let circuit = ArithCircuitPoly::<F>::new((0..n_vars).map(ArithExpr::Var).sum());
+		assert!(!circuit.is_zero_on_cube());
+	}
+	#[test]
+	fn test_evaluate_batch() {
 		type F = BinaryField8b;
 		type P = PackedBinaryField8x16b;
 
-		// x0^13
-		let expr = ArithExpr::Var(0).pow(13);
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0) * ArithExpr::Var(1) + ArithExpr::Var(2).pow(3)
+				- ArithExpr::Const(F::new(7)),
+		);
+		let mut rng = StdRng::seed_from_u64(0);
+		let queries = repeat_with(|| repeat_with(|| P::random(&mut rng)).take(3).collect())
+			.take(100)
+			.collect::<Vec<Vec<_>>>();
+		let queries = queries.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let mut out = vec![P::zero(); queries.len()];
+		circuit.evaluate_batch(&queries, &mut out).unwrap();
+		for (query, out) in queries.iter().zip(&out) {
+			assert_eq!(*out, CompositionPoly::evaluate(&circuit, query).unwrap());
+		}
+
+		assert!(matches!(
+			circuit.evaluate_batch(&queries, &mut out[1..]),
+			Err(Error::BatchEvaluateSizeMismatch)
+		));
+		assert!(matches!(
+			circuit.evaluate_batch(&[&queries[0][..2]], &mut out[..1]),
+			Err(Error::IncorrectQuerySize { expected: 3 })
+		));
+	}
+
+	#[cfg(all(feature = "numa", target_os = "linux"))]
+	#[test]
+	fn test_evaluate_batch_numa() {
+		type F = BinaryField8b;
+		type P = PackedBinaryField8x16b;
+
+		// Horner evaluation of a long polynomial in x1 with x0 as the leading coefficient, so
+		// that the per-thread scratch space spans several pages.
+		let expr = (0..1000).fold(ArithExpr::Var(0), |acc, i| {
+			acc * ArithExpr::Var(1) + ArithExpr::Const(F::new(i as u8))
+		});
 		let circuit = ArithCircuitPoly::<F>::new(expr);
+		assert!(circuit.steps.len() * size_of::<P>() > 4 * 4096);
 
-		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
-		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
-		assert_eq!(typed_circuit.degree(), 13);
-		assert_eq!(typed_circuit.n_vars(), 1);
+		let mut rng = StdRng::seed_from_u64(0);
+		let queries = repeat_with(|| repeat_with(|| P::random(&mut rng)).take(2).collect())
+			.take(100)
+			.collect::<Vec<Vec<_>>>();
+		let queries = queries.iter().map(Vec::as_slice).collect::<Vec<_>>();
+
+		let mut out = vec![P::zero(); queries.len()];
+		circuit.evaluate_batch(&queries, &mut out).unwrap();
+		for (query, out) in queries.iter().zip(&out) {
+			assert_eq!(*out, CompositionPoly::evaluate(&circuit, query).unwrap());
+		}
+	}
+
+	#[test]
+	fn test_degrees_by_var() {
+		type F = BinaryField8b;
+
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Var(0) * ArithExpr::Var(0));
+		assert_eq!(circuit.degrees_by_var(), vec![2]);
+
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Var(0) + ArithExpr::Var(1).pow(3));
+		assert_eq!(circuit.degrees_by_var(), vec![1, 3]);
+
+		// Variables that are not read have degree zero
+		let circuit = ArithCircuitPoly::<F>::with_n_vars(
+			4,
+			(ArithExpr::Var(0) - ArithExpr::Var(2)).pow(2) * ArithExpr::Var(2),
+		)
+		.unwrap();
+		assert_eq!(circuit.degrees_by_var(), vec![2, 0, 3, 0]);
+
+		let circuit = ArithCircuitPoly::<F>::with_n_vars(2, ArithExpr::Const(F::new(3))).unwrap();
+		assert_eq!(circuit.degrees_by_var(), vec![0, 0]);
+	}
+
+	#[test]
+	fn test_to_dot() {
+		type F = BinaryField8b;
+
+		// x0 * x1 + x0^3 + 5
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0) * ArithExpr::Var(1)
+				+ ArithExpr::Var(0).pow(3)
+				+ ArithExpr::Const(F::new(5)),
+		);
+		let dot = circuit.to_dot();
+		assert!(dot.starts_with("digraph ArithCircuitPoly {"));
+		assert!(dot.trim_end().ends_with('}'));
+
+		// Four steps, two variables and one constant
+		let n_nodes = dot.lines().filter(|line| line.contains("[label=")).count();
+		let n_edges = dot.lines().filter(|line| line.contains("->")).count();
+		assert_eq!(n_nodes, 7);
+		assert_eq!(n_edges, 7);
+		assert!(dot.contains("s0 [label=\"*\"]"));
+		assert!(dot.contains("s1 [label=\"^3\"]"));
+		assert!(dot.contains("[label=\"Const(5)\", shape=box]"));
+		assert!(dot.contains("s3 [peripheries=2]"));
+
+		// A circuit without steps still marks its top-level expression
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Var(0));
+		assert!(circuit.to_dot().contains("v0 [peripheries=2]"));
+	}
+
+	#[test]
+	fn test_simplify_boolean() {
+		type F = BinaryField8b;
 
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).pow(5));
+		let simplified = circuit.simplify_boolean(&[0]);
+		assert_eq!(simplified.expr, ArithExpr::Var(0));
+		assert_eq!(CompositionPoly::degree(&simplified), 1);
+		assert_eq!(simplified.steps.len(), 0);
+
+		// x0^2 * x1 + (x1 * x1)^3 with only x1 boolean, and an out-of-range index
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0).pow(2) * ArithExpr::Var(1)
+				+ (ArithExpr::Var(1) * ArithExpr::Var(1)).pow(3),
+		);
+		let simplified = circuit.simplify_boolean(&[1, 7]);
 		assert_eq!(
-			CompositionPoly::evaluate(
-				&circuit,
-				&[P::from_scalars(
-					felts!(BinaryField16b[0, 1, 2, 3, 122, 123, 124, 125]),
-				)]
-			)
-			.unwrap(),
-			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 200, 52, 51, 115])),
+			simplified.expr,
+			ArithExpr::Var(0).pow(2) * ArithExpr::Var(1) + ArithExpr::Var(1)
+		);
+		assert_eq!(CompositionPoly::degree(&simplified), 3);
+		assert!(simplified.steps.len() < circuit.steps.len());
+
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..8 {
+			let query = [<F as Field>::random(&mut rng), F::new(rng.gen::<u8>() & 1)];
+			assert_eq!(
+				CompositionPoly::evaluate(&simplified, &query).unwrap(),
+				CompositionPoly::evaluate(&circuit, &query).unwrap()
+			);
+		}
+	}
+
+	#[test]
+	fn test_to_string_expr() {
+		type F = BinaryField8b;
+
+		let x = ArithExpr::<F>::Var;
+		let circuit = ArithCircuitPoly::<F>::new(
+			(x(0) + x(1)) * x(2) - (x(0) - x(1)) + (x(0) * x(1)).pow(3) + x(2) * x(2).pow(2),
+		);
+		assert_eq!(
+			circuit.to_string_expr(&["a"]),
+			"(a + x1) * x2 - (a - x1) + (a * x1)^3 + x2 * x2^2"
 		);
+
+		let circuit = ArithCircuitPoly::<F>::new(x(0) - (x(1) * x(2) + x(0)) * x(1));
+		assert_eq!(circuit.to_string_expr(&[]), "x0 - (x1 * x2 + x0) * x1");
 	}
 
 	#[test]
-	fn test_mixed() {
+	fn test_inv() {
 		type F = BinaryField8b;
 		type P = PackedBinaryField8x16b;
 
-		// x0^2 * (x1 + 123)
-		let expr = ArithExpr::Var(0).pow(2) * (ArithExpr::Var(1) + ArithExpr::Const(F::new(123)));
-		let circuit = ArithCircuitPoly::<F>::new(expr);
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).inv());
+		assert!(!circuit.is_polynomial());
+		assert_eq!(CompositionPoly::degree(&circuit), usize::MAX);
 
-		let typed_circuit: &dyn CompositionPolyOS<P> = &circuit;
-		assert_eq!(typed_circuit.binary_tower_level(), F::TOWER_LEVEL);
-		assert_eq!(typed_circuit.degree(), 3);
-		assert_eq!(typed_circuit.n_vars(), 2);
+		let query = P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 0, 5, 6, 7]));
+		let expected = P::from_fn(|i| query.get(i).invert_or_zero());
+		assert_eq!(expected.get(0), BinaryField16b::ZERO);
+		assert_eq!(expected.get(4), BinaryField16b::ZERO);
+		assert_eq!(CompositionPoly::evaluate(&circuit, &[query]).unwrap(), expected);
 
-		// test evaluate
+		let mut evals = [P::zero(); 2];
+		CompositionPoly::batch_evaluate(&circuit, &[&[query, expected]], &mut evals).unwrap();
+		assert_eq!(evals, [expected, query]);
+
+		// Inverses of constants are folded, and keep the circuit polynomial
+		let circuit =
+			ArithCircuitPoly::<F>::new(ArithExpr::Const(F::new(3)).inv() * ArithExpr::Var(0));
+		assert!(circuit.is_polynomial());
+		assert_eq!(circuit.expr, ArithExpr::Const(F::new(3).invert_or_zero()) * ArithExpr::Var(0));
+
+		// (x0 + x1)^-1 round trips through the serialized forms
+		let circuit = ArithCircuitPoly::<F>::new((ArithExpr::Var(0) + ArithExpr::Var(1)).inv());
+		assert_eq!(circuit.to_sexpr(), "(inv (add (var 0) (var 1)))");
 		assert_eq!(
-			CompositionPoly::evaluate(
-				&circuit,
-				&[
-					P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
-					P::from_scalars(felts!(BinaryField16b[100, 101, 102, 103, 104, 105, 106, 107])),
-				]
-			)
-			.unwrap(),
-			P::from_scalars(felts!(BinaryField16b[0, 30, 59, 36, 151, 140, 170, 176])),
+			ArithCircuitPoly::<F>::from_sexpr(&circuit.to_sexpr(), 2)
+				.unwrap()
+				.expr,
+			circuit.expr
+		);
+		assert_eq!(circuit.to_string_expr(&[]), "inv(x0 + x1)");
+		let mut buf = Vec::new();
+		circuit.serialize(&mut buf).unwrap();
+		assert_eq!(
+			ArithCircuitPoly::<F>::deserialize(buf.as_slice())
+				.unwrap()
+				.expr,
+			circuit.expr
 		);
+		assert!(matches!(
+			circuit.evaluate_at_infinity::<F>(0, &[F::ONE]),
+			Err(Error::NotPolynomial)
+		));
 
-		// test batch evaluate
-		let query1 = &[
-			P::from_scalars(felts!(BinaryField16b[0, 0, 0, 0, 0, 0, 0, 0])),
-			P::from_scalars(felts!(BinaryField16b[0, 0, 0, 0, 0, 0, 0, 0])),
-		];
-		let query2 = &[
-			P::from_scalars(felts!(BinaryField16b[1, 1, 1, 1, 1, 1, 1, 1])),
-			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
-		];
-		let query3 = &[
-			P::from_scalars(felts!(BinaryField16b[0, 1, 2, 3, 4, 5, 6, 7])),
-			P::from_scalars(felts!(BinaryField16b[100, 101, 102, 103, 104, 105, 106, 107])),
-		];
-		let expected1 = P::from_scalars(felts!(BinaryField16b[0, 0, 0, 0, 0, 0, 0, 0]));
-		let expected2 =
-			P::from_scalars(felts!(BinaryField16b[123, 122, 121, 120, 127, 126, 125, 124]));
-		let expected3 = P::from_scalars(felts!(BinaryField16b[0, 30, 59, 36, 151, 140, 170, 176]));
+		// The derivative of 1/x is 1/x^2 in characteristic 2
+		let derivative = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).inv()).derivative(0);
+		let x = F::new(7);
+		assert_eq!(
+			CompositionPoly::evaluate(&derivative, &[x]).unwrap(),
+			x.square().invert_or_zero()
+		);
+	}
 
-		let mut batch_result = vec![P::zero(); 3];
-		CompositionPoly::batch_evaluate(
-			&circuit,
-			&[
-				&[query1[0], query2[0], query3[0]],
-				&[query1[1], query2[1], query3[1]],
-			],
-			&mut batch_result,
-		)
-		.unwrap();
-		assert_eq!(&batch_result, &[expected1, expected2, expected3]);
+	#[test]
+	fn test_is_symmetric() {
+		type F = BinaryField8b;
+
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Var(0) + ArithExpr::Var(1));
+		assert!(circuit.is_symmetric());
+
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0) + ArithExpr::Const(F::new(2)) * ArithExpr::Var(1),
+		);
+		assert!(!circuit.is_symmetric());
+
+		// x0^2 + x1 and x0 + x1^2 agree on the hypercube, so only the field probes tell them apart
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Var(0).pow(2) + ArithExpr::Var(1));
+		assert!(!circuit.is_symmetric());
+
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Var(0) * ArithExpr::Var(1) * ArithExpr::Var(2)
+				+ ArithExpr::Var(0)
+				+ ArithExpr::Var(1)
+				+ ArithExpr::Var(2),
+		);
+		assert!(circuit.is_symmetric());
+	}
+	#[test]
+	fn test_constant_folding() {
+		type F = BinaryField8b;
+
+		let circuit = ArithCircuitPoly::<F>::new(ArithExpr::Const(F::ZERO) * ArithExpr::Var(0));
+		assert_eq!(CompositionPoly::n_vars(&circuit), 1);
+		assert_eq!(CompositionPoly::degree(&circuit), 0);
+		assert_eq!(circuit.steps.len(), 0);
+		assert_eq!(circuit.retval, CircuitStepArgument::Const(F::ZERO));
+		assert_eq!(CompositionPoly::evaluate(&circuit, &[F::new(7)]).unwrap(), F::ZERO);
+
+		// (2 + 3) * 4 + x0 * 1 + 0
+		let circuit = ArithCircuitPoly::<F>::new(
+			(ArithExpr::Const(F::new(2)) + ArithExpr::Const(F::new(3)))
+				* ArithExpr::Const(F::new(4))
+				+ ArithExpr::Var(0) * ArithExpr::Const(F::ONE)
+				+ ArithExpr::Const(F::ZERO),
+		);
+		assert_eq!(
+			circuit.expr,
+			ArithExpr::Const((F::new(2) + F::new(3)) * F::new(4)) + ArithExpr::Var(0)
+		);
+		assert_eq!(circuit.steps.len(), 1);
+		assert_eq!(CompositionPoly::degree(&circuit), 1);
+
+		let circuit = ArithCircuitPoly::<F>::new(
+			ArithExpr::Const(F::new(3)).pow(2) - ArithExpr::Const(F::new(5)),
+		);
+		assert_eq!(circuit.expr, ArithExpr::Const(F::new(3).pow([2]) - F::new(5)));
 	}
 }
@@ -41,4 +41,16 @@ pub enum Error {
 	MathError(#[from] binius_math::Error),
 	#[error("{0}")]
 	HalError(#[from] binius_hal::Error),
+	#[error("composition type is not registered")]
+	UnregisteredComposition,
+	#[error("composition tag {tag} is not registered")]
+	UnknownCompositionTag { tag: u32 },
+	#[error("composition tag {tag} is already registered")]
+	DuplicateCompositionTag { tag: u32 },
+	#[error("composition type is already registered under tag {tag}")]
+	DuplicateCompositionType { tag: u32 },
+	#[error("lookup table length {len} is not a power of two")]
+	TableLengthNotPowerOfTwo { len: usize },
+	#[error("serialization error: {0}")]
+	Serialization(#[from] binius_utils::serialization::Error),
 }
@@ -1,16 +1,27 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use std::{fmt::Debug, sync::Arc};
+use std::{
+	fmt::Debug,
+	io::{Read, Write},
+	sync::Arc,
+};
 
+#[cfg(feature = "arrow")]
+use binius_field::serialize_canonical;
 use binius_field::{
 	as_packed_field::{PackScalar, PackedType},
-	underlier::UnderlierType,
-	ExtensionField, Field, PackedExtension, TowerField,
+	underlier::{UnderlierType, WithUnderlier},
+	ExtensionField, Field, PackedExtension, PackedField, TowerField,
 };
 use binius_math::{MultilinearExtension, MultilinearExtensionBorrowed, MultilinearPoly};
 use binius_utils::bail;
+use bytemuck::{zeroed_vec, Pod};
 
-use crate::{oracle::OracleId, polynomial::Error as PolynomialError};
+use crate::{
+	oracle::{MultilinearOracleSet, MultilinearPolyOracle, OracleId},
+	polynomial::Error as PolynomialError,
+	tower::{TowerFamily, TowerUnderlier},
+};
 
 pub type MultilinearWitness<'a, P> = Arc<dyn MultilinearPoly<P> + Send + Sync + 'a>;
 
@@ -47,6 +58,23 @@ pub enum Error {
 	HalError(#[from] binius_hal::Error),
 	#[error("Math error: {0}")]
 	MathError(#[from] binius_math::Error),
+	#[error("serialized witness for oracle id {id} does not match the oracle set")]
+	SerializedWitnessMismatch { id: OracleId },
+	#[error("I/O error: {0}")]
+	Io(#[from] std::io::Error),
+	#[error("oracle id {id} is not committed")]
+	NotCommitted { id: OracleId },
+	#[error("column of oracle id {id} is stored at unsupported tower level {tower_level}")]
+	UnsupportedTowerLevel { id: OracleId, tower_level: usize },
+	#[error("column of oracle id {id} has {actual} rows, expected {expected}")]
+	ColumnLengthMismatch {
+		id: OracleId,
+		expected: usize,
+		actual: usize,
+	},
+	#[cfg(feature = "arrow")]
+	#[error("Arrow error: {0}")]
+	Arrow(#[from] arrow::error::ArrowError),
 }
 
 impl<'a, U, FW> MultilinearExtensionIndex<'a, U, FW>
@@ -122,3 +150,463 @@ where
 		Ok(MultilinearExtension::from_values_slice(evals)?)
 	}
 }
+
+impl<U, FW> MultilinearExtensionIndex<'_, U, FW>
+where
+	U: UnderlierType + PackScalar<FW> + Pod,
+	FW: TowerField,
+{
+	/// Writes all witness entries to `w`.
+	///
+	/// Each entry is written as its oracle id, tower level, number of variables and byte length,
+	/// all as little-endian `u64`s, followed by the raw underlier bytes of its evaluations. The
+	/// entries must have an explicit backing multilinear extension.
+	pub fn serialize(&self, mut w: impl Write) -> Result<(), Error> {
+		let entries = self
+			.entries
+			.iter()
+			.enumerate()
+			.filter_map(|(id, entry)| Some((id, entry.as_ref()?)))
+			.collect::<Vec<_>>();
+
+		write_u64(&mut w, entries.len())?;
+		for (id, entry) in entries {
+			let evals = entry
+				.packed_evals()
+				.ok_or(Error::NoExplicitBackingMultilinearExtension { id })?;
+			let bytes: &[u8] = bytemuck::cast_slice(PackedType::<U, FW>::to_underliers_ref(evals));
+
+			write_u64(&mut w, id)?;
+			write_u64(&mut w, FW::TOWER_LEVEL - entry.log_extension_degree())?;
+			write_u64(&mut w, entry.n_vars())?;
+			write_u64(&mut w, bytes.len())?;
+			w.write_all(bytes)?;
+		}
+		Ok(())
+	}
+
+	/// Reads a witness written by [`Self::serialize`].
+	///
+	/// Every entry is checked against `oracle_set`: the oracle must exist and have the serialized
+	/// number of variables and tower level, and the byte length must match that shape.
+	pub fn deserialize<Tower>(
+		mut r: impl Read,
+		oracle_set: &MultilinearOracleSet<FW>,
+	) -> Result<Self, Error>
+	where
+		Tower: TowerFamily<B128 = FW>,
+		U: TowerUnderlier<Tower>,
+		FW: ExtensionField<Tower::B1>
+			+ ExtensionField<Tower::B8>
+			+ ExtensionField<Tower::B16>
+			+ ExtensionField<Tower::B32>
+			+ ExtensionField<Tower::B64>,
+	{
+		let mut index = Self::new();
+		for _ in 0..read_u64(&mut r)? {
+			let id = read_u64(&mut r)?;
+			let tower_level = read_u64(&mut r)?;
+			let n_vars = read_u64(&mut r)?;
+			let n_bytes = read_u64(&mut r)?;
+
+			if !oracle_set.is_valid_oracle_id(id)
+				|| oracle_set.n_vars(id) != n_vars
+				|| oracle_set.tower_level(id) != tower_level
+			{
+				bail!(Error::SerializedWitnessMismatch { id });
+			}
+
+			// The length is checked against the oracle shape before allocating, so that a corrupted
+			// header cannot request an arbitrarily large buffer.
+			let log_width = U::LOG_BITS - tower_level;
+			if n_bytes != size_of::<U>() << n_vars.saturating_sub(log_width) {
+				bail!(Error::SerializedWitnessMismatch { id });
+			}
+
+			let mut underliers = zeroed_vec::<U>(n_bytes / size_of::<U>());
+			r.read_exact(bytemuck::cast_slice_mut(&mut underliers))?;
+
+			let witness = match tower_level {
+				0 => witness_from_underliers::<U, Tower::B1, FW>(n_vars, underliers)?,
+				3 => witness_from_underliers::<U, Tower::B8, FW>(n_vars, underliers)?,
+				4 => witness_from_underliers::<U, Tower::B16, FW>(n_vars, underliers)?,
+				5 => witness_from_underliers::<U, Tower::B32, FW>(n_vars, underliers)?,
+				6 => witness_from_underliers::<U, Tower::B64, FW>(n_vars, underliers)?,
+				7 => witness_from_underliers::<U, Tower::B128, FW>(n_vars, underliers)?,
+				_ => bail!(Error::SerializedWitnessMismatch { id }),
+			};
+			index.update_multilin_poly([(id, witness)])?;
+		}
+		Ok(index)
+	}
+
+	/// Replaces the column of a committed oracle with its elementwise multiplicative inverse.
+	///
+	/// Zero values are left as zero. The inversion is batched over the packed storage of the
+	/// column, in the field the column is stored in. Witness entries are shared and immutable, so
+	/// the column is copied into a new buffer, which is inverted in place and then replaces the
+	/// entry.
+	pub fn invert_column<Tower>(
+		&mut self,
+		oracle_set: &MultilinearOracleSet<FW>,
+		id: OracleId,
+	) -> Result<(), Error>
+	where
+		Tower: TowerFamily<B128 = FW>,
+		U: TowerUnderlier<Tower>,
+		FW: ExtensionField<Tower::B1>
+			+ ExtensionField<Tower::B8>
+			+ ExtensionField<Tower::B16>
+			+ ExtensionField<Tower::B32>
+			+ ExtensionField<Tower::B64>,
+	{
+		if !oracle_set.is_valid_oracle_id(id)
+			|| !matches!(oracle_set.oracle(id), MultilinearPolyOracle::Committed { .. })
+		{
+			bail!(Error::NotCommitted { id });
+		}
+
+		let entry = self.get_multilin_poly(id)?;
+		let n_vars = entry.n_vars();
+		let underliers = entry
+			.packed_evals()
+			.map(|evals| PackedType::<U, FW>::to_underliers_ref(evals).to_vec())
+			.ok_or(Error::NoExplicitBackingMultilinearExtension { id })?;
+
+		let witness = match FW::TOWER_LEVEL - entry.log_extension_degree() {
+			0 => inverted_witness::<U, Tower::B1, FW>(n_vars, underliers)?,
+			3 => inverted_witness::<U, Tower::B8, FW>(n_vars, underliers)?,
+			4 => inverted_witness::<U, Tower::B16, FW>(n_vars, underliers)?,
+			5 => inverted_witness::<U, Tower::B32, FW>(n_vars, underliers)?,
+			6 => inverted_witness::<U, Tower::B64, FW>(n_vars, underliers)?,
+			7 => inverted_witness::<U, Tower::B128, FW>(n_vars, underliers)?,
+			tower_level => bail!(Error::UnsupportedTowerLevel { id, tower_level }),
+		};
+		self.update_multilin_poly([(id, witness)])
+	}
+}
+
+#[cfg(feature = "arrow")]
+impl<U, FW> MultilinearExtensionIndex<'_, U, FW>
+where
+	U: UnderlierType + PackScalar<FW>,
+	FW: TowerField,
+{
+	/// Exports the committed columns as an Arrow record batch, for inspection with data tools.
+	///
+	/// Each committed oracle of `oracle_set` becomes a column named after the oracle label, with
+	/// one row per hypercube vertex. The values are the canonical little-endian encodings of the
+	/// field elements, stored as fixed-size binary values of the oracle's tower level byte width.
+	/// All the committed oracles must have the same number of variables.
+	pub fn to_arrow(
+		&self,
+		oracle_set: &MultilinearOracleSet<FW>,
+	) -> Result<arrow::record_batch::RecordBatch, Error> {
+		use arrow::{
+			array::{ArrayRef, FixedSizeBinaryArray},
+			datatypes::{DataType, Field as ArrowField, Schema},
+			record_batch::RecordBatch,
+		};
+
+		let mut n_rows = None;
+		let mut fields = Vec::new();
+		let mut columns = Vec::new();
+		for id in oracle_set.committed_oracles() {
+			let poly = self.get_multilin_poly(id)?;
+			let rows = 1 << poly.n_vars();
+			let expected = *n_rows.get_or_insert(rows);
+			if rows != expected {
+				bail!(Error::ColumnLengthMismatch {
+					id,
+					expected,
+					actual: rows,
+				});
+			}
+
+			let byte_width = (1usize << oracle_set.tower_level(id)).div_ceil(8);
+			let values = (0..rows)
+				.map(|i| {
+					let mut bytes = [0u8; 16];
+					serialize_canonical(poly.evaluate_on_hypercube(i)?, &mut bytes[..])
+						.expect("tower field elements are at most 16 bytes");
+					Ok(bytes[..byte_width].to_vec())
+				})
+				.collect::<Result<Vec<_>, Error>>()?;
+
+			fields.push(ArrowField::new(
+				oracle_set.label(id),
+				DataType::FixedSizeBinary(byte_width as i32),
+				false,
+			));
+			columns.push(std::sync::Arc::new(FixedSizeBinaryArray::try_from_iter(
+				values.into_iter(),
+			)?) as ArrayRef);
+		}
+
+		Ok(RecordBatch::try_new(std::sync::Arc::new(Schema::new(fields)), columns)?)
+	}
+}
+
+pub(crate) fn witness_from_underliers<'a, U, FS, FW>(
+	n_vars: usize,
+	underliers: Vec<U>,
+) -> Result<MultilinearWitness<'a, PackedType<U, FW>>, Error>
+where
+	U: UnderlierType + PackScalar<FS> + PackScalar<FW>,
+	FS: TowerField,
+	FW: TowerField + ExtensionField<FS>,
+{
+	let values: Vec<PackedType<U, FS>> = underliers
+		.into_iter()
+		.map(PackedType::<U, FS>::from_underlier)
+		.collect();
+	Ok(MultilinearExtension::new(n_vars, values)?.specialize_arc_dyn())
+}
+
+fn inverted_witness<'a, U, FS, FW>(
+	n_vars: usize,
+	underliers: Vec<U>,
+) -> Result<MultilinearWitness<'a, PackedType<U, FW>>, Error>
+where
+	U: UnderlierType + PackScalar<FS> + PackScalar<FW>,
+	FS: TowerField,
+	FW: TowerField + ExtensionField<FS>,
+{
+	let mut values: Vec<PackedType<U, FS>> = underliers
+		.into_iter()
+		.map(PackedType::<U, FS>::from_underlier)
+		.collect();
+	PackedField::batch_invert_or_zero(&mut values);
+	Ok(MultilinearExtension::new(n_vars, values)?.specialize_arc_dyn())
+}
+
+fn write_u64(w: &mut impl Write, value: usize) -> Result<(), Error> {
+	w.write_all(&(value as u64).to_le_bytes())?;
+	Ok(())
+}
+
+fn read_u64(r: &mut impl Read) -> Result<usize, Error> {
+	let mut bytes = [0u8; 8];
+	r.read_exact(&mut bytes)?;
+	Ok(u64::from_le_bytes(bytes) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		arch::OptimalUnderlier, BinaryField128b, BinaryField1b, BinaryField32b, BinaryField4b,
+		PackedField,
+	};
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::tower::CanonicalTowerFamily;
+
+	type U = OptimalUnderlier;
+	type F = BinaryField128b;
+
+	fn random_witness<'a, FS>(
+		rng: &mut StdRng,
+		n_vars: usize,
+	) -> MultilinearWitness<'a, PackedType<U, F>>
+	where
+		FS: TowerField,
+		U: PackScalar<FS>,
+		F: ExtensionField<FS>,
+	{
+		let len = 1 << n_vars.saturating_sub(PackedType::<U, FS>::LOG_WIDTH);
+		let values: Vec<PackedType<U, FS>> = (0..len)
+			.map(|_| PackedType::<U, FS>::random(&mut *rng))
+			.collect();
+		MultilinearExtension::new(n_vars, values)
+			.unwrap()
+			.specialize_arc_dyn()
+	}
+
+	#[cfg(feature = "arrow")]
+	#[test]
+	fn test_to_arrow() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let bits = oracles.add_committed(6, BinaryField1b::TOWER_LEVEL);
+		let words = oracles.add_committed(6, BinaryField32b::TOWER_LEVEL);
+		let shifted = oracles
+			.add_shifted(words, 1, 6, crate::oracle::ShiftVariant::LogicalLeft)
+			.unwrap();
+		let short = oracles.add_committed(5, F::TOWER_LEVEL);
+
+		let mut index = MultilinearExtensionIndex::<U, F>::new();
+		index
+			.update_multilin_poly([
+				(bits, random_witness::<BinaryField1b>(&mut rng, 6)),
+				(words, random_witness::<BinaryField32b>(&mut rng, 6)),
+				(shifted, random_witness::<BinaryField32b>(&mut rng, 6)),
+			])
+			.unwrap();
+
+		let mut same_size = MultilinearOracleSet::<F>::new();
+		same_size.add_committed(6, BinaryField1b::TOWER_LEVEL);
+		same_size.add_committed(6, BinaryField32b::TOWER_LEVEL);
+		same_size
+			.add_shifted(words, 1, 6, crate::oracle::ShiftVariant::LogicalLeft)
+			.unwrap();
+
+		let batch = index.to_arrow(&same_size).unwrap();
+		assert_eq!(batch.num_columns(), 2);
+		assert_eq!(batch.num_rows(), 1 << 6);
+
+		let words_poly = index.get_multilin_poly(words).unwrap();
+		let column = batch
+			.column(1)
+			.as_any()
+			.downcast_ref::<arrow::array::FixedSizeBinaryArray>()
+			.unwrap();
+		assert_eq!(column.value_length(), 4);
+		let value = BinaryField32b::new(u32::from_le_bytes(column.value(3).try_into().unwrap()));
+		assert_eq!(F::from(value), words_poly.evaluate_on_hypercube(3).unwrap());
+
+		index
+			.update_multilin_poly([(short, random_witness::<F>(&mut rng, 5))])
+			.unwrap();
+		assert!(matches!(
+			index.to_arrow(&oracles),
+			Err(Error::ColumnLengthMismatch { id, expected: 64, actual: 32 }) if id == short
+		));
+	}
+
+	#[test]
+	fn test_serialize_round_trip() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let bits = oracles.add_committed(10, BinaryField1b::TOWER_LEVEL);
+		let words = oracles.add_committed(5, BinaryField32b::TOWER_LEVEL);
+		let sums = oracles.add_committed(4, F::TOWER_LEVEL);
+
+		let mut index = MultilinearExtensionIndex::<U, F>::new();
+		index
+			.update_multilin_poly([
+				(bits, random_witness::<BinaryField1b>(&mut rng, 10)),
+				(words, random_witness::<BinaryField32b>(&mut rng, 5)),
+				(sums, random_witness::<F>(&mut rng, 4)),
+			])
+			.unwrap();
+
+		let mut bytes = Vec::new();
+		index.serialize(&mut bytes).unwrap();
+		let loaded = MultilinearExtensionIndex::<U, F>::deserialize::<CanonicalTowerFamily>(
+			bytes.as_slice(),
+			&oracles,
+		)
+		.unwrap();
+
+		for id in [bits, words, sums] {
+			let expected = index.get_multilin_poly(id).unwrap();
+			let actual = loaded.get_multilin_poly(id).unwrap();
+			assert_eq!(actual.n_vars(), expected.n_vars());
+			assert_eq!(actual.log_extension_degree(), expected.log_extension_degree());
+			assert_eq!(actual.packed_evals(), expected.packed_evals());
+		}
+
+		let mut mismatched = MultilinearOracleSet::<F>::new();
+		mismatched.add_committed(10, BinaryField1b::TOWER_LEVEL);
+		mismatched.add_committed(5, F::TOWER_LEVEL);
+		mismatched.add_committed(4, F::TOWER_LEVEL);
+		assert!(matches!(
+			MultilinearExtensionIndex::<U, F>::deserialize::<CanonicalTowerFamily>(
+				bytes.as_slice(),
+				&mismatched,
+			),
+			Err(Error::SerializedWitnessMismatch { id }) if id == words
+		));
+	}
+
+	#[test]
+	fn test_deserialize_rejects_bad_lengths() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let words = oracles.add_committed(5, BinaryField32b::TOWER_LEVEL);
+
+		let mut index = MultilinearExtensionIndex::<U, F>::new();
+		index
+			.update_multilin_poly([(words, random_witness::<BinaryField32b>(&mut rng, 5))])
+			.unwrap();
+		let mut bytes = Vec::new();
+		index.serialize(&mut bytes).unwrap();
+
+		// The byte length is the last header field of the only entry
+		let n_bytes_offset = 4 * size_of::<u64>();
+		let mut oversized = bytes.clone();
+		oversized[n_bytes_offset..n_bytes_offset + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+		assert!(matches!(
+			MultilinearExtensionIndex::<U, F>::deserialize::<CanonicalTowerFamily>(
+				oversized.as_slice(),
+				&oracles,
+			),
+			Err(Error::SerializedWitnessMismatch { id }) if id == words
+		));
+
+		let truncated = &bytes[..bytes.len() - 1];
+		assert!(matches!(
+			MultilinearExtensionIndex::<U, F>::deserialize::<CanonicalTowerFamily>(
+				truncated, &oracles,
+			),
+			Err(Error::Io(_))
+		));
+	}
+
+	#[test]
+	fn test_invert_column() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let mut oracles = MultilinearOracleSet::<F>::new();
+		let words = oracles.add_committed(6, BinaryField32b::TOWER_LEVEL);
+		let shifted = oracles
+			.add_shifted(words, 1, 6, crate::oracle::ShiftVariant::LogicalLeft)
+			.unwrap();
+
+		// Zero out every third value so that both branches of the inversion are exercised.
+		let mut values = (0..1 << (6 - PackedType::<U, BinaryField32b>::LOG_WIDTH))
+			.map(|_| PackedType::<U, BinaryField32b>::random(&mut rng))
+			.collect::<Vec<_>>();
+		for i in (0..1 << 6).step_by(3) {
+			let width = PackedType::<U, BinaryField32b>::WIDTH;
+			values[i / width].set(i % width, BinaryField32b::ZERO);
+		}
+		let original = MultilinearExtension::new(6, values)
+			.unwrap()
+			.specialize_arc_dyn();
+
+		let mut index = MultilinearExtensionIndex::<U, F>::new();
+		index
+			.update_multilin_poly([(words, original.clone()), (shifted, original.clone())])
+			.unwrap();
+		index
+			.invert_column::<CanonicalTowerFamily>(&oracles, words)
+			.unwrap();
+
+		let inverted = index.get_multilin_poly(words).unwrap();
+		assert_eq!(inverted.log_extension_degree(), original.log_extension_degree());
+		for i in 0..1 << 6 {
+			let value = original.evaluate_on_hypercube(i).unwrap();
+			let product = value * inverted.evaluate_on_hypercube(i).unwrap();
+			if value == F::ZERO {
+				assert_eq!(product, F::ZERO);
+			} else {
+				assert_eq!(product, F::ONE);
+			}
+		}
+
+		assert!(matches!(
+			index.invert_column::<CanonicalTowerFamily>(&oracles, shifted),
+			Err(Error::NotCommitted { id }) if id == shifted
+		));
+
+		let nibbles = oracles.add_committed(6, BinaryField4b::TOWER_LEVEL);
+		index
+			.update_multilin_poly([(nibbles, random_witness::<BinaryField4b>(&mut rng, 6))])
+			.unwrap();
+		assert!(matches!(
+			index.invert_column::<CanonicalTowerFamily>(&oracles, nibbles),
+			Err(Error::UnsupportedTowerLevel { id, tower_level: 2 }) if id == nibbles
+		));
+	}
+}
@@ -15,7 +15,7 @@ use crate::{
 	},
 	arch::{
 		portable::{
-			packed::PackedPrimitiveType,
+			packed::packed_primitive_type,
 			packed_arithmetic::{alphas, impl_tower_constants},
 		},
 		PackedStrategy, PairwiseRecursiveStrategy, PairwiseStrategy, SimdStrategy,
@@ -29,11 +29,11 @@ use crate::{
 };
 
 // Define 128 bit packed field types
-pub type PackedAESBinaryField16x8b = PackedPrimitiveType<M128, AESTowerField8b>;
-pub type PackedAESBinaryField8x16b = PackedPrimitiveType<M128, AESTowerField16b>;
-pub type PackedAESBinaryField4x32b = PackedPrimitiveType<M128, AESTowerField32b>;
-pub type PackedAESBinaryField2x64b = PackedPrimitiveType<M128, AESTowerField64b>;
-pub type PackedAESBinaryField1x128b = PackedPrimitiveType<M128, AESTowerField128b>;
+packed_primitive_type!(PackedAESBinaryField16x8b = PackedPrimitiveType<M128, AESTowerField8b>);
+packed_primitive_type!(PackedAESBinaryField8x16b = PackedPrimitiveType<M128, AESTowerField16b>);
+packed_primitive_type!(PackedAESBinaryField4x32b = PackedPrimitiveType<M128, AESTowerField32b>);
+packed_primitive_type!(PackedAESBinaryField2x64b = PackedPrimitiveType<M128, AESTowerField64b>);
+packed_primitive_type!(PackedAESBinaryField1x128b = PackedPrimitiveType<M128, AESTowerField128b>);
 
 // Define contants
 // 0xD3 corresponds to 0x10 after isomorphism from BinaryField8b to AESField
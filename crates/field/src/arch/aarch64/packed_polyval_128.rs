@@ -17,14 +17,16 @@
 use core::{arch::aarch64::*, mem};
 use std::ops::Mul;
 
-use super::{super::portable::packed::PackedPrimitiveType, m128::M128};
+use super::{super::portable::packed::packed_primitive_type, m128::M128};
 use crate::{
 	arch::{PairwiseStrategy, ReuseMultiplyStrategy},
 	arithmetic_traits::{impl_square_with, impl_transformation_with_strategy, InvertOrZero},
 	BinaryField128bPolyval, PackedField,
 };
 
-pub type PackedBinaryPolyval1x128b = PackedPrimitiveType<M128, BinaryField128bPolyval>;
+packed_primitive_type!(
+	PackedBinaryPolyval1x128b = PackedPrimitiveType<M128, BinaryField128bPolyval>
+);
 
 // Define multiply
 impl Mul for PackedBinaryPolyval1x128b {
@@ -0,0 +1,161 @@
+// Copyright 2025 Irreducible Inc.
+
+//! Runtime selection of the SIMD instruction set used by packed arithmetic.
+//!
+//! Packed types whose backend is not fixed at compile time (see
+//! [`RuntimeDispatchStrategy`](super::RuntimeDispatchStrategy)) run their kernels through
+//! [`dispatch`], which recompiles the kernel for the widest instruction set detected on the
+//! running CPU. This lets a binary built for the baseline target still use AVX2 or AVX-512
+//! when they are available.
+
+use std::sync::{
+	atomic::{AtomicU8, Ordering},
+	Once,
+};
+
+/// Instruction set level a dispatched kernel is compiled for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SimdLevel {
+	Portable,
+	Avx2,
+	Avx512,
+}
+
+impl SimdLevel {
+	const fn from_u8(value: u8) -> Self {
+		match value {
+			2 => Self::Avx512,
+			1 => Self::Avx2,
+			_ => Self::Portable,
+		}
+	}
+}
+
+static DETECT: Once = Once::new();
+static LEVEL: AtomicU8 = AtomicU8::new(SimdLevel::Portable as u8);
+
+/// Returns the widest [`SimdLevel`] supported by the running CPU.
+///
+/// Detection runs once per process; later calls read the cached result.
+#[inline]
+pub fn simd_level() -> SimdLevel {
+	DETECT.call_once(|| LEVEL.store(detect() as u8, Ordering::Relaxed));
+	SimdLevel::from_u8(LEVEL.load(Ordering::Relaxed))
+}
+
+fn detect() -> SimdLevel {
+	cfg_if::cfg_if! {
+		if #[cfg(target_arch = "x86_64")] {
+			#[cfg(not(feature = "stable_only"))]
+			if is_x86_feature_detected!("avx512f") {
+				return SimdLevel::Avx512;
+			}
+			if is_x86_feature_detected!("avx2") {
+				return SimdLevel::Avx2;
+			}
+		}
+	}
+	SimdLevel::Portable
+}
+
+/// Runs `f` compiled for the widest instruction set available on the running CPU.
+#[inline]
+pub fn dispatch<R>(f: impl FnOnce() -> R) -> R {
+	dispatch_at(simd_level(), f)
+}
+
+/// Runs `f` compiled for `level`.
+///
+/// A `level` wider than the one reported by [`simd_level`] falls back to the widest supported
+/// one, so forcing a level never executes unsupported instructions.
+#[inline]
+pub fn dispatch_at<R>(level: SimdLevel, f: impl FnOnce() -> R) -> R {
+	cfg_if::cfg_if! {
+		if #[cfg(target_arch = "x86_64")] {
+			match level.min(simd_level()) {
+				#[cfg(not(feature = "stable_only"))]
+				// Safety: `simd_level` only reports AVX-512 if the CPU supports it.
+				SimdLevel::Avx512 => unsafe { x86_64::run_avx512(f) },
+				// Safety: `simd_level` only reports AVX2 if the CPU supports it.
+				SimdLevel::Avx2 => unsafe { x86_64::run_avx2(f) },
+				_ => f(),
+			}
+		} else {
+			let _ = level;
+			f()
+		}
+	}
+}
+
+#[cfg(target_arch = "x86_64")]
+mod x86_64 {
+	#[cfg(not(feature = "stable_only"))]
+	#[target_feature(enable = "avx512f")]
+	pub(super) unsafe fn run_avx512<R>(f: impl FnOnce() -> R) -> R {
+		f()
+	}
+
+	#[target_feature(enable = "avx2")]
+	pub(super) unsafe fn run_avx2<R>(f: impl FnOnce() -> R) -> R {
+		f()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::array;
+
+	use rand::{rngs::StdRng, SeedableRng};
+
+	use super::*;
+	use crate::{
+		arch::{portable::packed_scaled::ScaledPackedField, RuntimeDispatchStrategy},
+		arithmetic_traits::{TaggedInvertOrZero, TaggedMul},
+		PackedBinaryField16x8b, PackedBinaryField2x64b, PackedBinaryField4x32b, PackedField,
+	};
+
+	fn check_forced_portable_matches_dispatched<PT>()
+	where
+		PT: PackedField,
+	{
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..64 {
+			let a_inner: [PT; 4] = array::from_fn(|_| PT::random(&mut rng));
+			let b_inner: [PT; 4] = array::from_fn(|_| PT::random(&mut rng));
+			let a = ScaledPackedField::<PT, 4>::from_direct_packed_fn(|i| a_inner[i]);
+			let b = ScaledPackedField::<PT, 4>::from_direct_packed_fn(|i| b_inner[i]);
+
+			let portable_mul = dispatch_at(SimdLevel::Portable, || {
+				ScaledPackedField::from_direct_packed_fn(|i| a_inner[i] * b_inner[i])
+			});
+			let portable_inv = dispatch_at(SimdLevel::Portable, || {
+				ScaledPackedField::from_direct_packed_fn(|i| a_inner[i].invert_or_zero())
+			});
+
+			assert_eq!(TaggedMul::<RuntimeDispatchStrategy>::mul(a, b), portable_mul);
+			assert_eq!(a * b, portable_mul);
+			assert_eq!(
+				TaggedInvertOrZero::<RuntimeDispatchStrategy>::invert_or_zero(a),
+				portable_inv
+			);
+			assert_eq!(a.invert_or_zero(), portable_inv);
+
+			for level in [SimdLevel::Avx2, SimdLevel::Avx512] {
+				assert_eq!(dispatch_at(level, || a * b), portable_mul);
+			}
+		}
+	}
+
+	#[test]
+	fn test_simd_level_is_cached() {
+		assert_eq!(simd_level(), simd_level());
+		assert_eq!(simd_level(), detect());
+	}
+
+	#[test]
+	fn test_forced_portable_matches_dispatched() {
+		check_forced_portable_matches_dispatched::<PackedBinaryField16x8b>();
+		check_forced_portable_matches_dispatched::<PackedBinaryField4x32b>();
+		check_forced_portable_matches_dispatched::<PackedBinaryField2x64b>();
+	}
+}
@@ -4,6 +4,7 @@ use cfg_if::cfg_if;
 
 mod arch_optimal;
 mod binary_utils;
+pub mod dispatch;
 mod strategies;
 
 cfg_if! {
@@ -30,5 +31,6 @@ pub use arch_optimal::*;
 pub use portable::{
 	byte_sliced, packed_1, packed_16, packed_2, packed_32, packed_4, packed_64, packed_8,
 	packed_aes_16, packed_aes_32, packed_aes_64, packed_aes_8,
+	packed_arithmetic::UnderlierWithBitConstants,
 };
 pub use strategies::*;
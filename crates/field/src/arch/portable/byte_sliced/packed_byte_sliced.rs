@@ -2,6 +2,7 @@
 
 use std::{
 	array,
+	cmp::Ordering,
 	fmt::Debug,
 	iter::{zip, Product, Sum},
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
@@ -50,6 +51,18 @@ macro_rules! define_byte_sliced {
 			}
 		}
 
+		impl PartialOrd for $name {
+			fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+				Some(self.cmp(other))
+			}
+		}
+
+		impl Ord for $name {
+			fn cmp(&self, other: &Self) -> Ordering {
+				self.cmp_lanes(other)
+			}
+		}
+
 		impl PackedField for $name {
 			type Scalar = $scalar_type;
 
@@ -7,16 +7,22 @@
 #![allow(clippy::multiple_bound_locations)]
 
 use std::{
-	fmt::Debug,
+	cmp::Ordering,
+	fmt::{Debug, LowerHex, UpperHex, Write},
 	iter::{Product, Sum},
 	marker::PhantomData,
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
 };
 
-use binius_utils::{checked_arithmetics::checked_int_div, iter::IterExtensions};
+use binius_utils::{
+	bail,
+	iter::IterExtensions,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
 use bytemuck::{Pod, TransparentWrapper, Zeroable};
+use bytes::{Buf, BufMut};
 use rand::RngCore;
-use subtle::{Choice, ConstantTimeEq};
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use super::packed_arithmetic::UnderlierWithBitConstants;
 use crate::{
@@ -118,19 +124,71 @@ unsafe impl<U: UnderlierType, Scalar: BinaryField> WithUnderlier
 	}
 }
 
-impl<U: UnderlierWithBitOps, Scalar: BinaryField> Debug for PackedPrimitiveType<U, Scalar>
-where
-	Self: PackedField,
-{
+/// Formats a packed field as the name of its type alias followed by the lanes.
+pub(crate) fn fmt_packed_debug<P: PackedField>(
+	value: &P,
+	name: &str,
+	f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+	let values_str = value
+		.iter()
+		.map(|value| format!("{}", value))
+		.collect::<Vec<_>>()
+		.join(", ");
+
+	write!(f, "{}[{}]", name, values_str)
+}
+
+/// Declares a public type alias of [`PackedPrimitiveType`].
+///
+/// `Debug` is implemented here rather than generically, so that it prints the name of the alias.
+macro_rules! packed_primitive_type {
+	($(#[$attr:meta])* $name:ident = PackedPrimitiveType<$underlier:ty, $scalar:ty>) => {
+		$(#[$attr])*
+		pub type $name = $crate::arch::portable::packed::PackedPrimitiveType<$underlier, $scalar>;
+
+		impl std::fmt::Debug for $name {
+			fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+				$crate::arch::portable::packed::fmt_packed_debug(self, stringify!($name), f)
+			}
+		}
+	};
+}
+
+pub(crate) use packed_primitive_type;
+
+/// Formats the underlier as a hexadecimal number with `U::BITS / 4` digits, so lane 0 is printed
+/// last.
+fn fmt_underlier_hex<U: UnderlierType>(
+	value: &U,
+	upper: bool,
+	f: &mut std::fmt::Formatter<'_>,
+) -> std::fmt::Result {
+	// Underliers are stored little-endian, and sub-byte underliers occupy the low bits of a byte.
+	let digits = bytemuck::bytes_of(value)
+		.iter()
+		.rev()
+		.fold(String::new(), |mut digits, byte| {
+			let _ = if upper {
+				write!(digits, "{byte:02X}")
+			} else {
+				write!(digits, "{byte:02x}")
+			};
+			digits
+		});
+	let n_digits = U::BITS.div_ceil(4);
+	f.pad_integral(true, if upper { "0X" } else { "0x" }, &digits[digits.len() - n_digits..])
+}
+
+impl<U: UnderlierType, Scalar: BinaryField> LowerHex for PackedPrimitiveType<U, Scalar> {
 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-		let width = checked_int_div(U::BITS, Scalar::N_BITS);
-		let values_str = self
-			.iter()
-			.map(|value| format!("{}", value))
-			.collect::<Vec<_>>()
-			.join(",");
+		fmt_underlier_hex(&self.0, false, f)
+	}
+}
 
-		write!(f, "Packed{}x{}([{}])", width, Scalar::N_BITS, values_str)
+impl<U: UnderlierType, Scalar: BinaryField> UpperHex for PackedPrimitiveType<U, Scalar> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		fmt_underlier_hex(&self.0, true, f)
 	}
 }
 
@@ -147,6 +205,16 @@ impl<U: UnderlierType, Scalar: BinaryField> ConstantTimeEq for PackedPrimitiveTy
 	}
 }
 
+/// Selects between the underliers with a mask filled from `choice`, without branching on it.
+impl<U: UnderlierWithBitOps, Scalar: BinaryField> ConditionallySelectable
+	for PackedPrimitiveType<U, Scalar>
+{
+	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+		let mask = U::fill_with_bit(choice.unwrap_u8());
+		Self::from_underlier((a.0 & !mask) | (b.0 & mask))
+	}
+}
+
 impl<U: UnderlierWithBitOps, Scalar: BinaryField> Add for PackedPrimitiveType<U, Scalar> {
 	type Output = Self;
 
@@ -279,9 +347,62 @@ unsafe impl<U: UnderlierType + Zeroable, Scalar: BinaryField> Zeroable
 
 unsafe impl<U: UnderlierType + Pod, Scalar: BinaryField> Pod for PackedPrimitiveType<U, Scalar> {}
 
+/// Writes the bytes of the underlier in little-endian order, so that the first scalar comes first
+/// on every platform.
+impl<U: UnderlierType, Scalar: BinaryField> SerializeBytes for PackedPrimitiveType<U, Scalar> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		let bytes = bytemuck::bytes_of(&self.0);
+		if write_buf.remaining_mut() < bytes.len() {
+			bail!(SerializationError::WriteBufferFull);
+		}
+		if cfg!(target_endian = "little") {
+			write_buf.put_slice(bytes);
+		} else {
+			bytes.iter().rev().for_each(|&byte| write_buf.put_u8(byte));
+		}
+		Ok(())
+	}
+}
+
+/// Reads exactly `size_of::<U>()` underlier bytes in little-endian order.
+impl<U: UnderlierType + Pod, Scalar: BinaryField> DeserializeBytes
+	for PackedPrimitiveType<U, Scalar>
+{
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let mut underlier = U::zeroed();
+		let bytes = bytemuck::bytes_of_mut(&mut underlier);
+		if read_buf.remaining() < bytes.len() {
+			bail!(SerializationError::NotEnoughBytes);
+		}
+		read_buf.copy_to_slice(bytes);
+		if cfg!(target_endian = "big") {
+			bytes.reverse();
+		}
+		Ok(Self::from_underlier(underlier))
+	}
+}
+
+impl<U: UnderlierType, Scalar: BinaryField + Ord> PartialOrd for PackedPrimitiveType<U, Scalar>
+where
+	Self: PackedField<Scalar = Scalar>,
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<U: UnderlierType, Scalar: BinaryField + Ord> Ord for PackedPrimitiveType<U, Scalar>
+where
+	Self: PackedField<Scalar = Scalar>,
+{
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.cmp_lanes(other)
+	}
+}
+
 impl<U: UnderlierWithBitOps, Scalar> PackedField for PackedPrimitiveType<U, Scalar>
 where
-	Self: Broadcast<Scalar> + Square + InvertOrZero + Mul<Output = Self>,
+	Self: Broadcast<Scalar> + Square + InvertOrZero + Mul<Output = Self> + Debug,
 	U: UnderlierWithBitConstants + From<Scalar::Underlier> + Send + Sync + 'static,
 	Scalar: BinaryField + WithUnderlier<Underlier: UnderlierWithBitOps>,
 	Scalar::Underlier: NumCast<U>,
@@ -310,6 +431,14 @@ where
 		U::random(rng).into()
 	}
 
+	#[inline]
+	fn last_nonzero_lane(&self) -> Option<usize> {
+		if self.0 == U::ZERO {
+			return None;
+		}
+		Some((U::BITS - 1 - self.0.leading_zeros()) / Scalar::N_BITS)
+	}
+
 	#[inline]
 	fn iter(&self) -> impl Iterator<Item = Self::Scalar> + Send + '_ {
 		IterationMethods::<Scalar::Underlier, U>::ref_iter(&self.0)
@@ -370,6 +499,30 @@ where
 	fn invert_or_zero(self) -> Self {
 		<Self as InvertOrZero>::invert_or_zero(self)
 	}
+
+	#[inline]
+	fn popcount(&self) -> u32 {
+		// Count whole words so that the hardware popcount is used where available.
+		bytemuck::bytes_of(&self.0)
+			.chunks(8)
+			.map(|chunk| {
+				let mut word = [0u8; 8];
+				word[..chunk.len()].copy_from_slice(chunk);
+				u64::from_le_bytes(word).count_ones()
+			})
+			.sum()
+	}
+
+	#[inline]
+	fn prefix_xor(self) -> Self {
+		// Hillis-Steele scan: after step `i` every lane holds the sum of the `2^(i + 1)` lanes
+		// ending at it.
+		let mut value = self.0;
+		for i in 0..Self::LOG_WIDTH {
+			value ^= value << (Scalar::N_BITS << i);
+		}
+		value.into()
+	}
 }
 
 macro_rules! impl_broadcast {
@@ -409,6 +562,10 @@ macro_rules! impl_broadcast {
 
 pub(crate) use impl_broadcast;
 
+/// Implements arithmetic for packed `BinaryField1b` types directly on the underlier.
+///
+/// Multiplication in GF(2) is a bitwise AND, so these types bypass the tower multiplication
+/// strategies entirely. Squaring, inversion and multiplication by alpha are the identity.
 macro_rules! impl_ops_for_zero_height {
 	($name:ty) => {
 		impl std::ops::Mul for $name {
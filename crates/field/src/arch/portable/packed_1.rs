@@ -1,13 +1,15 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use super::packed::{impl_broadcast, impl_ops_for_zero_height, PackedPrimitiveType};
+use super::packed::{
+	impl_broadcast, impl_ops_for_zero_height, packed_primitive_type, PackedPrimitiveType,
+};
 use crate::{
 	arch::PairwiseStrategy, arithmetic_traits::impl_transformation_with_strategy, underlier::U1,
 	BinaryField1b,
 };
 
 // Define 1 bit packed field types
-pub type PackedBinaryField1x1b = PackedPrimitiveType<U1, BinaryField1b>;
+packed_primitive_type!(PackedBinaryField1x1b = PackedPrimitiveType<U1, BinaryField1b>);
 
 // Define broadcast
 impl_broadcast!(U1, BinaryField1b);
@@ -1,7 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use super::{
-	packed::{impl_broadcast, impl_ops_for_zero_height, PackedPrimitiveType},
+	packed::{
+		impl_broadcast, impl_ops_for_zero_height, packed_primitive_type, PackedPrimitiveType,
+	},
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
@@ -15,14 +17,14 @@ use crate::{
 };
 
 // Define 128 bit packed field types
-pub type PackedBinaryField128x1b = PackedPrimitiveType<u128, BinaryField1b>;
-pub type PackedBinaryField64x2b = PackedPrimitiveType<u128, BinaryField2b>;
-pub type PackedBinaryField32x4b = PackedPrimitiveType<u128, BinaryField4b>;
-pub type PackedBinaryField16x8b = PackedPrimitiveType<u128, BinaryField8b>;
-pub type PackedBinaryField8x16b = PackedPrimitiveType<u128, BinaryField16b>;
-pub type PackedBinaryField4x32b = PackedPrimitiveType<u128, BinaryField32b>;
-pub type PackedBinaryField2x64b = PackedPrimitiveType<u128, BinaryField64b>;
-pub type PackedBinaryField1x128b = PackedPrimitiveType<u128, BinaryField128b>;
+packed_primitive_type!(PackedBinaryField128x1b = PackedPrimitiveType<u128, BinaryField1b>);
+packed_primitive_type!(PackedBinaryField64x2b = PackedPrimitiveType<u128, BinaryField2b>);
+packed_primitive_type!(PackedBinaryField32x4b = PackedPrimitiveType<u128, BinaryField4b>);
+packed_primitive_type!(PackedBinaryField16x8b = PackedPrimitiveType<u128, BinaryField8b>);
+packed_primitive_type!(PackedBinaryField8x16b = PackedPrimitiveType<u128, BinaryField16b>);
+packed_primitive_type!(PackedBinaryField4x32b = PackedPrimitiveType<u128, BinaryField32b>);
+packed_primitive_type!(PackedBinaryField2x64b = PackedPrimitiveType<u128, BinaryField64b>);
+packed_primitive_type!(PackedBinaryField1x128b = PackedPrimitiveType<u128, BinaryField128b>);
 
 // Define broadcast
 impl_broadcast!(u128, BinaryField1b);
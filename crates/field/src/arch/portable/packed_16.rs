@@ -1,7 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use super::{
-	packed::{impl_broadcast, impl_ops_for_zero_height, PackedPrimitiveType},
+	packed::{
+		impl_broadcast, impl_ops_for_zero_height, packed_primitive_type, PackedPrimitiveType,
+	},
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
@@ -14,11 +16,11 @@ use crate::{
 };
 
 // Define 16 bit packed field types
-pub type PackedBinaryField16x1b = PackedPrimitiveType<u16, BinaryField1b>;
-pub type PackedBinaryField8x2b = PackedPrimitiveType<u16, BinaryField2b>;
-pub type PackedBinaryField4x4b = PackedPrimitiveType<u16, BinaryField4b>;
-pub type PackedBinaryField2x8b = PackedPrimitiveType<u16, BinaryField8b>;
-pub type PackedBinaryField1x16b = PackedPrimitiveType<u16, BinaryField16b>;
+packed_primitive_type!(PackedBinaryField16x1b = PackedPrimitiveType<u16, BinaryField1b>);
+packed_primitive_type!(PackedBinaryField8x2b = PackedPrimitiveType<u16, BinaryField2b>);
+packed_primitive_type!(PackedBinaryField4x4b = PackedPrimitiveType<u16, BinaryField4b>);
+packed_primitive_type!(PackedBinaryField2x8b = PackedPrimitiveType<u16, BinaryField8b>);
+packed_primitive_type!(PackedBinaryField1x16b = PackedPrimitiveType<u16, BinaryField16b>);
 
 // Define broadcast
 impl_broadcast!(u16, BinaryField1b);
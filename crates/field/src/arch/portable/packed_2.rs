@@ -1,7 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use super::{
-	packed::{impl_broadcast, impl_ops_for_zero_height, PackedPrimitiveType},
+	packed::{
+		impl_broadcast, impl_ops_for_zero_height, packed_primitive_type, PackedPrimitiveType,
+	},
 	packed_arithmetic::TowerConstants,
 	reuse_multiply_arithmetic::Alpha,
 };
@@ -16,8 +18,8 @@ use crate::{
 };
 
 // Define 2 bit packed field types
-pub type PackedBinaryField2x1b = PackedPrimitiveType<U2, BinaryField1b>;
-pub type PackedBinaryField1x2b = PackedPrimitiveType<U2, BinaryField2b>;
+packed_primitive_type!(PackedBinaryField2x1b = PackedPrimitiveType<U2, BinaryField1b>);
+packed_primitive_type!(PackedBinaryField1x2b = PackedPrimitiveType<U2, BinaryField2b>);
 
 // Define broadcast
 impl_broadcast!(U2, BinaryField1b);
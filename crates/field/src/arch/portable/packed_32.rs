@@ -3,7 +3,9 @@
 use cfg_if::cfg_if;
 
 use super::{
-	packed::{impl_broadcast, impl_ops_for_zero_height, PackedPrimitiveType},
+	packed::{
+		impl_broadcast, impl_ops_for_zero_height, packed_primitive_type, PackedPrimitiveType,
+	},
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
@@ -16,12 +18,12 @@ use crate::{
 };
 
 // Define 32 bit packed field types
-pub type PackedBinaryField32x1b = PackedPrimitiveType<u32, BinaryField1b>;
-pub type PackedBinaryField16x2b = PackedPrimitiveType<u32, BinaryField2b>;
-pub type PackedBinaryField8x4b = PackedPrimitiveType<u32, BinaryField4b>;
-pub type PackedBinaryField4x8b = PackedPrimitiveType<u32, BinaryField8b>;
-pub type PackedBinaryField2x16b = PackedPrimitiveType<u32, BinaryField16b>;
-pub type PackedBinaryField1x32b = PackedPrimitiveType<u32, BinaryField32b>;
+packed_primitive_type!(PackedBinaryField32x1b = PackedPrimitiveType<u32, BinaryField1b>);
+packed_primitive_type!(PackedBinaryField16x2b = PackedPrimitiveType<u32, BinaryField2b>);
+packed_primitive_type!(PackedBinaryField8x4b = PackedPrimitiveType<u32, BinaryField4b>);
+packed_primitive_type!(PackedBinaryField4x8b = PackedPrimitiveType<u32, BinaryField8b>);
+packed_primitive_type!(PackedBinaryField2x16b = PackedPrimitiveType<u32, BinaryField16b>);
+packed_primitive_type!(PackedBinaryField1x32b = PackedPrimitiveType<u32, BinaryField32b>);
 
 // Define broadcast
 impl_broadcast!(u32, BinaryField1b);
@@ -1,7 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use super::{
-	packed::{impl_broadcast, impl_ops_for_zero_height, PackedPrimitiveType},
+	packed::{
+		impl_broadcast, impl_ops_for_zero_height, packed_primitive_type, PackedPrimitiveType,
+	},
 	packed_arithmetic::TowerConstants,
 	reuse_multiply_arithmetic::Alpha,
 };
@@ -16,9 +18,9 @@ use crate::{
 };
 
 // Define 4 bit packed field types
-pub type PackedBinaryField4x1b = PackedPrimitiveType<U4, BinaryField1b>;
-pub type PackedBinaryField2x2b = PackedPrimitiveType<U4, BinaryField2b>;
-pub type PackedBinaryField1x4b = PackedPrimitiveType<U4, BinaryField4b>;
+packed_primitive_type!(PackedBinaryField4x1b = PackedPrimitiveType<U4, BinaryField1b>);
+packed_primitive_type!(PackedBinaryField2x2b = PackedPrimitiveType<U4, BinaryField2b>);
+packed_primitive_type!(PackedBinaryField1x4b = PackedPrimitiveType<U4, BinaryField4b>);
 
 // Define broadcast
 impl_broadcast!(U4, BinaryField1b);
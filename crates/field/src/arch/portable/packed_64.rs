@@ -3,7 +3,9 @@
 use cfg_if::cfg_if;
 
 use super::{
-	packed::{impl_broadcast, impl_ops_for_zero_height, PackedPrimitiveType},
+	packed::{
+		impl_broadcast, impl_ops_for_zero_height, packed_primitive_type, PackedPrimitiveType,
+	},
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
@@ -17,13 +19,13 @@ use crate::{
 };
 
 // Define 64 bit packed field types
-pub type PackedBinaryField64x1b = PackedPrimitiveType<u64, BinaryField1b>;
-pub type PackedBinaryField32x2b = PackedPrimitiveType<u64, BinaryField2b>;
-pub type PackedBinaryField16x4b = PackedPrimitiveType<u64, BinaryField4b>;
-pub type PackedBinaryField8x8b = PackedPrimitiveType<u64, BinaryField8b>;
-pub type PackedBinaryField4x16b = PackedPrimitiveType<u64, BinaryField16b>;
-pub type PackedBinaryField2x32b = PackedPrimitiveType<u64, BinaryField32b>;
-pub type PackedBinaryField1x64b = PackedPrimitiveType<u64, BinaryField64b>;
+packed_primitive_type!(PackedBinaryField64x1b = PackedPrimitiveType<u64, BinaryField1b>);
+packed_primitive_type!(PackedBinaryField32x2b = PackedPrimitiveType<u64, BinaryField2b>);
+packed_primitive_type!(PackedBinaryField16x4b = PackedPrimitiveType<u64, BinaryField4b>);
+packed_primitive_type!(PackedBinaryField8x8b = PackedPrimitiveType<u64, BinaryField8b>);
+packed_primitive_type!(PackedBinaryField4x16b = PackedPrimitiveType<u64, BinaryField16b>);
+packed_primitive_type!(PackedBinaryField2x32b = PackedPrimitiveType<u64, BinaryField32b>);
+packed_primitive_type!(PackedBinaryField1x64b = PackedPrimitiveType<u64, BinaryField64b>);
 
 // Define broadcast
 impl_broadcast!(u64, BinaryField1b);
@@ -1,7 +1,9 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use super::{
-	packed::{impl_broadcast, impl_ops_for_zero_height, PackedPrimitiveType},
+	packed::{
+		impl_broadcast, impl_ops_for_zero_height, packed_primitive_type, PackedPrimitiveType,
+	},
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
@@ -14,10 +16,10 @@ use crate::{
 };
 
 // Define 8 bit packed field types
-pub type PackedBinaryField8x1b = PackedPrimitiveType<u8, BinaryField1b>;
-pub type PackedBinaryField4x2b = PackedPrimitiveType<u8, BinaryField2b>;
-pub type PackedBinaryField2x4b = PackedPrimitiveType<u8, BinaryField4b>;
-pub type PackedBinaryField1x8b = PackedPrimitiveType<u8, BinaryField8b>;
+packed_primitive_type!(PackedBinaryField8x1b = PackedPrimitiveType<u8, BinaryField1b>);
+packed_primitive_type!(PackedBinaryField4x2b = PackedPrimitiveType<u8, BinaryField2b>);
+packed_primitive_type!(PackedBinaryField2x4b = PackedPrimitiveType<u8, BinaryField4b>);
+packed_primitive_type!(PackedBinaryField1x8b = PackedPrimitiveType<u8, BinaryField8b>);
 
 // Define broadcast
 impl_broadcast!(u8, BinaryField1b);
@@ -1,7 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use super::{
-	packed::{impl_broadcast, PackedPrimitiveType},
+	packed::{impl_broadcast, packed_primitive_type, PackedPrimitiveType},
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
@@ -16,11 +16,11 @@ use crate::{
 };
 
 // Define 128 bit packed AES field types
-pub type PackedAESBinaryField16x8b = PackedPrimitiveType<u128, AESTowerField8b>;
-pub type PackedAESBinaryField8x16b = PackedPrimitiveType<u128, AESTowerField16b>;
-pub type PackedAESBinaryField4x32b = PackedPrimitiveType<u128, AESTowerField32b>;
-pub type PackedAESBinaryField2x64b = PackedPrimitiveType<u128, AESTowerField64b>;
-pub type PackedAESBinaryField1x128b = PackedPrimitiveType<u128, AESTowerField128b>;
+packed_primitive_type!(PackedAESBinaryField16x8b = PackedPrimitiveType<u128, AESTowerField8b>);
+packed_primitive_type!(PackedAESBinaryField8x16b = PackedPrimitiveType<u128, AESTowerField16b>);
+packed_primitive_type!(PackedAESBinaryField4x32b = PackedPrimitiveType<u128, AESTowerField32b>);
+packed_primitive_type!(PackedAESBinaryField2x64b = PackedPrimitiveType<u128, AESTowerField64b>);
+packed_primitive_type!(PackedAESBinaryField1x128b = PackedPrimitiveType<u128, AESTowerField128b>);
 
 // Define broadcast
 impl_broadcast!(u128, AESTowerField8b);
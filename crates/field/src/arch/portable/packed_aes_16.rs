@@ -1,7 +1,7 @@
 // Copyright 2024-2025 Irreducible Inc.
 
 use super::{
-	packed::{impl_broadcast, PackedPrimitiveType},
+	packed::{impl_broadcast, packed_primitive_type, PackedPrimitiveType},
 	packed_arithmetic::impl_tower_constants,
 };
 use crate::{
@@ -14,8 +14,8 @@ use crate::{
 };
 
 // Define 16 bit packed field types
-pub type PackedAESBinaryField2x8b = PackedPrimitiveType<u16, AESTowerField8b>;
-pub type PackedAESBinaryField1x16b = PackedPrimitiveType<u16, AESTowerField16b>;
+packed_primitive_type!(PackedAESBinaryField2x8b = PackedPrimitiveType<u16, AESTowerField8b>);
+packed_primitive_type!(PackedAESBinaryField1x16b = PackedPrimitiveType<u16, AESTowerField16b>);
 
 // Define broadcast
 impl_broadcast!(u16, AESTowerField8b);
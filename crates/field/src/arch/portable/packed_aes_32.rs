@@ -3,7 +3,7 @@
 use cfg_if::cfg_if;
 
 use super::{
-	packed::{impl_broadcast, PackedPrimitiveType},
+	packed::{impl_broadcast, packed_primitive_type, PackedPrimitiveType},
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
@@ -16,9 +16,9 @@ use crate::{
 };
 
 // Define 32 bit packed field types
-pub type PackedAESBinaryField4x8b = PackedPrimitiveType<u32, AESTowerField8b>;
-pub type PackedAESBinaryField2x16b = PackedPrimitiveType<u32, AESTowerField16b>;
-pub type PackedAESBinaryField1x32b = PackedPrimitiveType<u32, AESTowerField32b>;
+packed_primitive_type!(PackedAESBinaryField4x8b = PackedPrimitiveType<u32, AESTowerField8b>);
+packed_primitive_type!(PackedAESBinaryField2x16b = PackedPrimitiveType<u32, AESTowerField16b>);
+packed_primitive_type!(PackedAESBinaryField1x32b = PackedPrimitiveType<u32, AESTowerField32b>);
 
 // Define broadcast
 impl_broadcast!(u32, AESTowerField8b);
@@ -3,7 +3,7 @@
 use cfg_if::cfg_if;
 
 use super::{
-	packed::{impl_broadcast, PackedPrimitiveType},
+	packed::{impl_broadcast, packed_primitive_type, PackedPrimitiveType},
 	packed_arithmetic::{alphas, impl_tower_constants},
 };
 use crate::{
@@ -16,10 +16,10 @@ use crate::{
 };
 
 // Define 64 bit packed field types
-pub type PackedAESBinaryField8x8b = PackedPrimitiveType<u64, AESTowerField8b>;
-pub type PackedAESBinaryField4x16b = PackedPrimitiveType<u64, AESTowerField16b>;
-pub type PackedAESBinaryField2x32b = PackedPrimitiveType<u64, AESTowerField32b>;
-pub type PackedAESBinaryField1x64b = PackedPrimitiveType<u64, AESTowerField64b>;
+packed_primitive_type!(PackedAESBinaryField8x8b = PackedPrimitiveType<u64, AESTowerField8b>);
+packed_primitive_type!(PackedAESBinaryField4x16b = PackedPrimitiveType<u64, AESTowerField16b>);
+packed_primitive_type!(PackedAESBinaryField2x32b = PackedPrimitiveType<u64, AESTowerField32b>);
+packed_primitive_type!(PackedAESBinaryField1x64b = PackedPrimitiveType<u64, AESTowerField64b>);
 
 // Define broadcast
 impl_broadcast!(u64, AESTowerField8b);
@@ -1,6 +1,6 @@
 // Copyright 2024-2025 Irreducible Inc.
 
-use super::packed::{impl_broadcast, PackedPrimitiveType};
+use super::packed::{impl_broadcast, packed_primitive_type, PackedPrimitiveType};
 use crate::{
 	arch::{PairwiseStrategy, PairwiseTableStrategy},
 	arithmetic_traits::{
@@ -11,7 +11,7 @@ use crate::{
 };
 
 // Define 16 bit packed field types
-pub type PackedAESBinaryField1x8b = PackedPrimitiveType<u8, AESTowerField8b>;
+packed_primitive_type!(PackedAESBinaryField1x8b = PackedPrimitiveType<u8, AESTowerField8b>);
 
 // Define broadcast
 impl_broadcast!(u8, AESTowerField8b);
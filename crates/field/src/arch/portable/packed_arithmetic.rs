@@ -5,8 +5,8 @@ use std::ops::Deref;
 use crate::{
 	arch::PackedStrategy,
 	arithmetic_traits::{
-		MulAlpha, TaggedInvertOrZero, TaggedMul, TaggedMulAlpha, TaggedPackedTransformationFactory,
-		TaggedSquare,
+		MulAlpha, MulCheckedSubfield, TaggedInvertOrZero, TaggedMul, TaggedMulAlpha,
+		TaggedPackedTransformationFactory, TaggedSquare,
 	},
 	binary_field::{BinaryField, TowerExtensionField},
 	linear_transformation::{FieldLinearTransformation, Transformation},
@@ -37,6 +37,25 @@ where
 
 		(c, d)
 	}
+
+	/// Transposes a square matrix of `F` elements stored one row per underlier.
+	///
+	/// Each underlier holds a row of `Self::BITS / F::N_BITS` elements, and `data` must have that
+	/// many rows. After the call, element `j` of row `i` is the former element `i` of row `j`. The
+	/// transpose is done recursively with [`Self::interleave`], which transposes the 2x2 block
+	/// matrices at each block size from one element up to half a row.
+	fn transpose_block<F: TowerField>(data: &mut [Self]) {
+		let log_width = Self::LOG_BITS - F::TOWER_LEVEL;
+		assert_eq!(data.len(), 1 << log_width, "the block must have one row per element of a row");
+
+		for log_block_len in 0..log_width {
+			let block_len = 1 << log_block_len;
+			for i in (0..data.len()).filter(|i| i & block_len == 0) {
+				(data[i], data[i + block_len]) =
+					data[i].interleave(data[i + block_len], F::TOWER_LEVEL + log_block_len);
+			}
+		}
+	}
 }
 
 /// Abstraction for a packed tower field of height greater than 0.
@@ -54,6 +73,17 @@ pub(crate) trait PackedTowerField: PackedField + WithUnderlier {
 
 	/// Reinterpret packed subfield as a current type
 	fn from_packed_subfield(value: Self::PackedDirectSubfield) -> Self;
+
+	/// Embed a subfield scalar into the extension and broadcast it to all lanes.
+	///
+	/// The embedded value is the subfield element in the low half of a lane and zero in the high
+	/// half, so it is assembled in the first lane and spread to the others.
+	#[inline]
+	fn broadcast_subfield(value: Self::DirectSubfield) -> Self {
+		let mut embedded = Self::PackedDirectSubfield::zero();
+		embedded.set(0, value);
+		Self::from_packed_subfield(embedded).spread(0, 0)
+	}
 }
 
 impl<F, P, U: UnderlierType> PackedTowerField for P
@@ -158,6 +188,30 @@ where
 	}
 }
 
+impl<PT> MulCheckedSubfield for PT
+where
+	PT: PackedTowerField,
+	PT::Underlier: UnderlierWithBitConstants,
+{
+	#[inline]
+	fn mul_checked_subfield(self, rhs: Self) -> Self {
+		let odd_mask = <PT::Underlier as UnderlierWithBitConstants>::INTERLEAVE_ODD_MASK
+			[PT::DirectSubfield::TOWER_LEVEL];
+
+		let rhs = rhs.to_underlier();
+		if rhs & odd_mask != PT::Underlier::ZERO {
+			return self * Self::from_underlier(rhs);
+		}
+
+		// rhs = <b_lo_0, 0, b_lo_1, 0, ...>, so the product is
+		// <a_lo_0 * b_lo_0, a_hi_0 * b_lo_0, a_lo_1 * b_lo_1, a_hi_1 * b_lo_1, ...>
+		let rhs_lo_dup = rhs | (rhs << PT::DirectSubfield::N_BITS);
+		Self::from_packed_subfield(
+			self.as_packed_subfield() * PT::PackedDirectSubfield::from_underlier(rhs_lo_dup),
+		)
+	}
+}
+
 /// Generate the mask with alphas in the odd packed element positions and zeros in even
 macro_rules! alphas {
 	($underlier:ty, $tower_level:literal) => {{
@@ -395,7 +449,8 @@ where
 mod tests {
 	use std::fmt::Debug;
 
-	use rand::thread_rng;
+	use proptest::{arbitrary::any, proptest};
+	use rand::{thread_rng, Rng};
 
 	use super::*;
 	use crate::{
@@ -409,7 +464,7 @@ mod tests {
 			define_square_tests, define_transformation_tests,
 		},
 		BinaryField16b, BinaryField1b, BinaryField2b, BinaryField32b, BinaryField4b,
-		BinaryField64b, BinaryField8b,
+		BinaryField64b, BinaryField8b, ExtensionField, Field,
 	};
 
 	const NUM_TESTS: u64 = 100;
@@ -499,6 +554,76 @@ mod tests {
 		test_packed_multiply_alpha::<PackedBinaryField1x128b>();
 	}
 
+	fn check_mul_checked_subfield<P>(a: u128, b: u128)
+	where
+		P: PackedTowerField<Underlier = u128> + MulCheckedSubfield,
+	{
+		let a = P::from_underlier(a);
+		let subfield_b =
+			P::from_underlier(b & u128::INTERLEAVE_EVEN_MASK[P::DirectSubfield::TOWER_LEVEL]);
+		let b = P::from_underlier(b);
+
+		assert_eq!(a.mul_checked_subfield(b), a * b);
+		assert_eq!(a.mul_checked_subfield(subfield_b), a * subfield_b);
+	}
+
+	fn check_broadcast_subfield<P>(value: P::DirectSubfield)
+	where
+		P: PackedTowerField,
+		P::Scalar: ExtensionField<P::DirectSubfield>,
+	{
+		let broadcast = P::broadcast_subfield(value);
+		assert!(broadcast.iter().all(|lane| lane == value.into()));
+	}
+
+	#[test]
+	fn test_broadcast_subfield() {
+		check_broadcast_subfield::<PackedBinaryField64x2b>(BinaryField1b::ONE);
+		check_broadcast_subfield::<PackedBinaryField8x16b>(BinaryField8b::new(0xa7));
+		check_broadcast_subfield::<PackedBinaryField4x32b>(BinaryField16b::new(0x1234));
+		check_broadcast_subfield::<PackedBinaryField2x64b>(BinaryField32b::new(0xdeadbeef));
+		check_broadcast_subfield::<PackedBinaryField1x128b>(BinaryField64b::new(u64::MAX));
+	}
+
+	proptest! {
+		#[test]
+		fn test_mul_checked_subfield(a in any::<u128>(), b in any::<u128>()) {
+			check_mul_checked_subfield::<PackedBinaryField64x2b>(a, b);
+			check_mul_checked_subfield::<PackedBinaryField32x4b>(a, b);
+			check_mul_checked_subfield::<PackedBinaryField16x8b>(a, b);
+			check_mul_checked_subfield::<PackedBinaryField8x16b>(a, b);
+			check_mul_checked_subfield::<PackedBinaryField4x32b>(a, b);
+			check_mul_checked_subfield::<PackedBinaryField2x64b>(a, b);
+			check_mul_checked_subfield::<PackedBinaryField1x128b>(a, b);
+		}
+	}
+
+	#[test]
+	fn test_transpose_block() {
+		let element = |row: usize, col: usize| (row * 8 + col) as u64;
+		let mut data: [u64; 8] =
+			std::array::from_fn(|row| (0..8).map(|col| element(row, col) << (8 * col)).sum());
+		u64::transpose_block::<BinaryField8b>(&mut data);
+		for (row, value) in data.into_iter().enumerate() {
+			for col in 0..8 {
+				assert_eq!((value >> (8 * col)) & 0xff, element(col, row), "row {row}, col {col}");
+			}
+		}
+
+		// A 128x128 bit matrix, and transposing twice is the identity.
+		let mut rng = thread_rng();
+		let original = std::array::from_fn::<u128, 128, _>(|_| rng.gen());
+		let mut data = original;
+		u128::transpose_block::<BinaryField1b>(&mut data);
+		for (row, value) in data.iter().enumerate() {
+			for (col, original_row) in original.iter().enumerate() {
+				assert_eq!((value >> col) & 1, (original_row >> row) & 1);
+			}
+		}
+		u128::transpose_block::<BinaryField1b>(&mut data);
+		assert_eq!(data, original);
+	}
+
 	define_multiply_tests!(TaggedMul<PackedStrategy>::mul, TaggedMul<PackedStrategy>);
 
 	define_square_tests!(TaggedSquare<PackedStrategy>::square, TaggedSquare<PackedStrategy>);
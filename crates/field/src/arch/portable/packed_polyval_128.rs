@@ -13,7 +13,7 @@ use std::{
 	ops::{BitXor, Mul},
 };
 
-use super::packed::{impl_broadcast, PackedPrimitiveType};
+use super::packed::{impl_broadcast, packed_primitive_type, PackedPrimitiveType};
 use crate::{
 	arch::{PairwiseStrategy, ReuseMultiplyStrategy},
 	arithmetic_traits::{impl_square_with, impl_transformation_with_strategy, InvertOrZero},
@@ -21,7 +21,9 @@ use crate::{
 	BinaryField128bPolyval,
 };
 
-pub type PackedBinaryPolyval1x128b = PackedPrimitiveType<u128, BinaryField128bPolyval>;
+packed_primitive_type!(
+	PackedBinaryPolyval1x128b = PackedPrimitiveType<u128, BinaryField128bPolyval>
+);
 
 // Define broadcast
 impl_broadcast!(u128, BinaryField128bPolyval);
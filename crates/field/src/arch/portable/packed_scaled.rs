@@ -2,17 +2,24 @@
 
 use std::{
 	array,
+	cmp::Ordering,
+	fmt::{LowerHex, UpperHex, Write},
 	iter::{Product, Sum},
 	ops::{Add, AddAssign, Deref, Mul, MulAssign, Sub, SubAssign},
 };
 
-use binius_utils::checked_arithmetics::checked_log_2;
+use binius_utils::{
+	checked_arithmetics::checked_log_2,
+	serialization::{DeserializeBytes, Error as SerializationError, SerializeBytes},
+};
 use bytemuck::{Pod, TransparentWrapper, Zeroable};
+use bytes::{Buf, BufMut};
 use rand::RngCore;
-use subtle::ConstantTimeEq;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use crate::{
-	arithmetic_traits::MulAlpha,
+	arch::{dispatch::dispatch, RuntimeDispatchStrategy},
+	arithmetic_traits::{MulAlpha, TaggedInvertOrZero, TaggedMul},
 	as_packed_field::PackScalar,
 	linear_transformation::{
 		FieldLinearTransformation, PackedTransformationFactory, Transformation,
@@ -69,12 +76,68 @@ unsafe impl<PT: Zeroable, const N: usize> Zeroable for ScaledPackedField<PT, N>
 
 unsafe impl<PT: Pod, const N: usize> Pod for ScaledPackedField<PT, N> {}
 
+/// Writes the inner packed values in order.
+impl<PT: SerializeBytes, const N: usize> SerializeBytes for ScaledPackedField<PT, N> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), SerializationError> {
+		self.0
+			.iter()
+			.try_for_each(|packed| packed.serialize(&mut write_buf))
+	}
+}
+
+impl<PT: DeserializeBytes + Default, const N: usize> DeserializeBytes for ScaledPackedField<PT, N> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, SerializationError> {
+		let mut packed: [PT; N] = array::from_fn(|_| PT::default());
+		for packed in &mut packed {
+			*packed = PT::deserialize(&mut read_buf)?;
+		}
+		Ok(Self(packed))
+	}
+}
+
 impl<PT: ConstantTimeEq, const N: usize> ConstantTimeEq for ScaledPackedField<PT, N> {
 	fn ct_eq(&self, other: &Self) -> subtle::Choice {
 		self.0.ct_eq(&other.0)
 	}
 }
 
+impl<PT: ConditionallySelectable, const N: usize> ConditionallySelectable
+	for ScaledPackedField<PT, N>
+{
+	fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+		Self(array::from_fn(|i| PT::conditional_select(&a.0[i], &b.0[i], choice)))
+	}
+}
+
+/// Formats the inner packed fields as one hexadecimal number, with the first one printed last.
+impl<PT: LowerHex, const N: usize> LowerHex for ScaledPackedField<PT, N> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let digits = self
+			.0
+			.iter()
+			.rev()
+			.fold(String::new(), |mut digits, packed| {
+				let _ = write!(digits, "{packed:x}");
+				digits
+			});
+		f.pad_integral(true, "0x", &digits)
+	}
+}
+
+impl<PT: UpperHex, const N: usize> UpperHex for ScaledPackedField<PT, N> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		let digits = self
+			.0
+			.iter()
+			.rev()
+			.fold(String::new(), |mut digits, packed| {
+				let _ = write!(digits, "{packed:X}");
+				digits
+			});
+		f.pad_integral(true, "0X", &digits)
+	}
+}
+
 impl<PT: Copy + Add<Output = PT>, const N: usize> Add for ScaledPackedField<PT, N>
 where
 	Self: Default,
@@ -125,8 +188,27 @@ where
 {
 	type Output = Self;
 
+	#[inline]
+	fn mul(self, rhs: Self) -> Self {
+		TaggedMul::<RuntimeDispatchStrategy>::mul(self, rhs)
+	}
+}
+
+impl<PT: Copy + Mul<Output = PT>, const N: usize> TaggedMul<RuntimeDispatchStrategy>
+	for ScaledPackedField<PT, N>
+{
+	#[inline]
 	fn mul(self, rhs: Self) -> Self {
-		Self::from_direct_packed_fn(|i| self.0[i] * rhs.0[i])
+		dispatch(|| Self::from_direct_packed_fn(|i| self.0[i] * rhs.0[i]))
+	}
+}
+
+impl<PT: PackedField, const N: usize> TaggedInvertOrZero<RuntimeDispatchStrategy>
+	for ScaledPackedField<PT, N>
+{
+	#[inline]
+	fn invert_or_zero(self) -> Self {
+		dispatch(|| Self(self.0.map(|v| v.invert_or_zero())))
 	}
 }
 
@@ -182,6 +264,24 @@ where
 	}
 }
 
+impl<PT: PackedField<Scalar: Ord>, const N: usize> PartialOrd for ScaledPackedField<PT, N>
+where
+	Self: PackedField<Scalar = PT::Scalar>,
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl<PT: PackedField<Scalar: Ord>, const N: usize> Ord for ScaledPackedField<PT, N>
+where
+	Self: PackedField<Scalar = PT::Scalar>,
+{
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.cmp_lanes(other)
+	}
+}
+
 impl<PT: PackedField, const N: usize> PackedField for ScaledPackedField<PT, N>
 where
 	[PT; N]: Default,
@@ -224,8 +324,9 @@ where
 		Self(self.0.map(|v| v.square()))
 	}
 
+	#[inline]
 	fn invert_or_zero(self) -> Self {
-		Self(self.0.map(|v| v.invert_or_zero()))
+		TaggedInvertOrZero::<RuntimeDispatchStrategy>::invert_or_zero(self)
 	}
 
 	fn interleave(self, other: Self, log_block_len: usize) -> (Self, Self) {
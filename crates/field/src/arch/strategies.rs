@@ -13,6 +13,9 @@ pub struct PairwiseStrategy;
 pub struct PairwiseTableStrategy;
 /// Similar to `PackedStrategy`, but uses SIMD operations supported by the platform.
 pub struct SimdStrategy;
+/// Runs the portable implementation recompiled for the widest SIMD instruction set detected
+/// at runtime (see [`dispatch`](super::dispatch)).
+pub struct RuntimeDispatchStrategy;
 /// Applicable only for multiply by alpha and square operations.
 /// Reuse multiplication operation for that.
 pub struct ReuseMultiplyStrategy;
@@ -96,3 +96,60 @@ where
 		Self::from_underlier(result)
 	}
 }
+
+#[cfg(test)]
+mod tests {
+	use proptest::{arbitrary::any, proptest};
+
+	use super::*;
+	use crate::arch::PackedStrategy;
+
+	fn check_mul_matches_portable<P>(a: P, b: P)
+	where
+		P: PackedField<Scalar = BinaryField8b>
+			+ TaggedMul<AESIsomorphicStrategy>
+			+ TaggedMul<PackedStrategy>,
+	{
+		let gfni = TaggedMul::<AESIsomorphicStrategy>::mul(a, b);
+		let portable = TaggedMul::<PackedStrategy>::mul(a, b);
+		assert_eq!(gfni, portable);
+
+		for i in 0..P::WIDTH {
+			assert_eq!(gfni.get(i), a.get(i) * b.get(i));
+		}
+	}
+
+	proptest! {
+		#[test]
+		fn test_mul_8b_matches_portable_128(a_val in any::<u128>(), b_val in any::<u128>()) {
+			use crate::arch::packed_128::PackedBinaryField16x8b;
+
+			check_mul_matches_portable(
+				PackedBinaryField16x8b::from_underlier(a_val.into()),
+				PackedBinaryField16x8b::from_underlier(b_val.into()),
+			);
+		}
+
+		#[cfg(target_feature = "avx2")]
+		#[test]
+		fn test_mul_8b_matches_portable_256(a_val in any::<[u128; 2]>(), b_val in any::<[u128; 2]>()) {
+			use crate::arch::packed_256::PackedBinaryField32x8b;
+
+			check_mul_matches_portable(
+				PackedBinaryField32x8b::from_underlier(a_val.into()),
+				PackedBinaryField32x8b::from_underlier(b_val.into()),
+			);
+		}
+
+		#[cfg(target_feature = "avx512f")]
+		#[test]
+		fn test_mul_8b_matches_portable_512(a_val in any::<[u128; 4]>(), b_val in any::<[u128; 4]>()) {
+			use crate::arch::packed_512::PackedBinaryField64x8b;
+
+			check_mul_matches_portable(
+				PackedBinaryField64x8b::from_underlier(a_val.into()),
+				PackedBinaryField64x8b::from_underlier(b_val.into()),
+			);
+		}
+	}
+}
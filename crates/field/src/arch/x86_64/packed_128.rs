@@ -6,7 +6,7 @@ use super::m128::M128;
 use crate::{
 	arch::{
 		portable::{
-			packed::{impl_ops_for_zero_height, PackedPrimitiveType},
+			packed::{impl_ops_for_zero_height, packed_primitive_type},
 			packed_arithmetic::{alphas, impl_tower_constants},
 		},
 		PackedStrategy, SimdStrategy,
@@ -20,14 +20,14 @@ use crate::{
 };
 
 // Define 128 bit packed field types
-pub type PackedBinaryField128x1b = PackedPrimitiveType<M128, BinaryField1b>;
-pub type PackedBinaryField64x2b = PackedPrimitiveType<M128, BinaryField2b>;
-pub type PackedBinaryField32x4b = PackedPrimitiveType<M128, BinaryField4b>;
-pub type PackedBinaryField16x8b = PackedPrimitiveType<M128, BinaryField8b>;
-pub type PackedBinaryField8x16b = PackedPrimitiveType<M128, BinaryField16b>;
-pub type PackedBinaryField4x32b = PackedPrimitiveType<M128, BinaryField32b>;
-pub type PackedBinaryField2x64b = PackedPrimitiveType<M128, BinaryField64b>;
-pub type PackedBinaryField1x128b = PackedPrimitiveType<M128, BinaryField128b>;
+packed_primitive_type!(PackedBinaryField128x1b = PackedPrimitiveType<M128, BinaryField1b>);
+packed_primitive_type!(PackedBinaryField64x2b = PackedPrimitiveType<M128, BinaryField2b>);
+packed_primitive_type!(PackedBinaryField32x4b = PackedPrimitiveType<M128, BinaryField4b>);
+packed_primitive_type!(PackedBinaryField16x8b = PackedPrimitiveType<M128, BinaryField8b>);
+packed_primitive_type!(PackedBinaryField8x16b = PackedPrimitiveType<M128, BinaryField16b>);
+packed_primitive_type!(PackedBinaryField4x32b = PackedPrimitiveType<M128, BinaryField32b>);
+packed_primitive_type!(PackedBinaryField2x64b = PackedPrimitiveType<M128, BinaryField64b>);
+packed_primitive_type!(PackedBinaryField1x128b = PackedPrimitiveType<M128, BinaryField128b>);
 
 // Define operations for zero height
 impl_ops_for_zero_height!(PackedBinaryField128x1b);
@@ -6,7 +6,7 @@ use super::m256::M256;
 use crate::{
 	arch::{
 		portable::{
-			packed::{impl_ops_for_zero_height, PackedPrimitiveType},
+			packed::{impl_ops_for_zero_height, packed_primitive_type},
 			packed_arithmetic::{alphas, impl_tower_constants},
 		},
 		PackedStrategy, SimdStrategy,
@@ -20,14 +20,14 @@ use crate::{
 };
 
 // Define 128 bit packed field types
-pub type PackedBinaryField256x1b = PackedPrimitiveType<M256, BinaryField1b>;
-pub type PackedBinaryField128x2b = PackedPrimitiveType<M256, BinaryField2b>;
-pub type PackedBinaryField64x4b = PackedPrimitiveType<M256, BinaryField4b>;
-pub type PackedBinaryField32x8b = PackedPrimitiveType<M256, BinaryField8b>;
-pub type PackedBinaryField16x16b = PackedPrimitiveType<M256, BinaryField16b>;
-pub type PackedBinaryField8x32b = PackedPrimitiveType<M256, BinaryField32b>;
-pub type PackedBinaryField4x64b = PackedPrimitiveType<M256, BinaryField64b>;
-pub type PackedBinaryField2x128b = PackedPrimitiveType<M256, BinaryField128b>;
+packed_primitive_type!(PackedBinaryField256x1b = PackedPrimitiveType<M256, BinaryField1b>);
+packed_primitive_type!(PackedBinaryField128x2b = PackedPrimitiveType<M256, BinaryField2b>);
+packed_primitive_type!(PackedBinaryField64x4b = PackedPrimitiveType<M256, BinaryField4b>);
+packed_primitive_type!(PackedBinaryField32x8b = PackedPrimitiveType<M256, BinaryField8b>);
+packed_primitive_type!(PackedBinaryField16x16b = PackedPrimitiveType<M256, BinaryField16b>);
+packed_primitive_type!(PackedBinaryField8x32b = PackedPrimitiveType<M256, BinaryField32b>);
+packed_primitive_type!(PackedBinaryField4x64b = PackedPrimitiveType<M256, BinaryField64b>);
+packed_primitive_type!(PackedBinaryField2x128b = PackedPrimitiveType<M256, BinaryField128b>);
 
 // Define operations for zero height
 impl_ops_for_zero_height!(PackedBinaryField256x1b);
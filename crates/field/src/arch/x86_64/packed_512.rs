@@ -4,7 +4,7 @@ use super::m512::M512;
 use crate::{
 	arch::{
 		portable::{
-			packed::{impl_ops_for_zero_height, PackedPrimitiveType},
+			packed::{impl_ops_for_zero_height, packed_primitive_type},
 			packed_arithmetic::{alphas, impl_tower_constants},
 		},
 		PackedStrategy, SimdStrategy,
@@ -18,14 +18,14 @@ use crate::{
 };
 
 // Define 128 bit packed field types
-pub type PackedBinaryField512x1b = PackedPrimitiveType<M512, BinaryField1b>;
-pub type PackedBinaryField256x2b = PackedPrimitiveType<M512, BinaryField2b>;
-pub type PackedBinaryField128x4b = PackedPrimitiveType<M512, BinaryField4b>;
-pub type PackedBinaryField64x8b = PackedPrimitiveType<M512, BinaryField8b>;
-pub type PackedBinaryField32x16b = PackedPrimitiveType<M512, BinaryField16b>;
-pub type PackedBinaryField16x32b = PackedPrimitiveType<M512, BinaryField32b>;
-pub type PackedBinaryField8x64b = PackedPrimitiveType<M512, BinaryField64b>;
-pub type PackedBinaryField4x128b = PackedPrimitiveType<M512, BinaryField128b>;
+packed_primitive_type!(PackedBinaryField512x1b = PackedPrimitiveType<M512, BinaryField1b>);
+packed_primitive_type!(PackedBinaryField256x2b = PackedPrimitiveType<M512, BinaryField2b>);
+packed_primitive_type!(PackedBinaryField128x4b = PackedPrimitiveType<M512, BinaryField4b>);
+packed_primitive_type!(PackedBinaryField64x8b = PackedPrimitiveType<M512, BinaryField8b>);
+packed_primitive_type!(PackedBinaryField32x16b = PackedPrimitiveType<M512, BinaryField16b>);
+packed_primitive_type!(PackedBinaryField16x32b = PackedPrimitiveType<M512, BinaryField32b>);
+packed_primitive_type!(PackedBinaryField8x64b = PackedPrimitiveType<M512, BinaryField64b>);
+packed_primitive_type!(PackedBinaryField4x128b = PackedPrimitiveType<M512, BinaryField128b>);
 
 // Define operations for zero height
 impl_ops_for_zero_height!(PackedBinaryField512x1b);
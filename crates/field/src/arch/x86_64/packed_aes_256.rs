@@ -7,7 +7,7 @@ use crate::{
 	aes_field::{
 		AESTowerField128b, AESTowerField16b, AESTowerField32b, AESTowerField64b, AESTowerField8b,
 	},
-	arch::{portable::packed::PackedPrimitiveType, SimdStrategy},
+	arch::{portable::packed::packed_primitive_type, SimdStrategy},
 	arithmetic_traits::{
 		impl_invert_with, impl_mul_alpha_with, impl_mul_with, impl_square_with,
 		impl_transformation_with_strategy,
@@ -15,11 +15,11 @@ use crate::{
 };
 
 // Define 128 bit packed field types
-pub type PackedAESBinaryField32x8b = PackedPrimitiveType<M256, AESTowerField8b>;
-pub type PackedAESBinaryField16x16b = PackedPrimitiveType<M256, AESTowerField16b>;
-pub type PackedAESBinaryField8x32b = PackedPrimitiveType<M256, AESTowerField32b>;
-pub type PackedAESBinaryField4x64b = PackedPrimitiveType<M256, AESTowerField64b>;
-pub type PackedAESBinaryField2x128b = PackedPrimitiveType<M256, AESTowerField128b>;
+packed_primitive_type!(PackedAESBinaryField32x8b = PackedPrimitiveType<M256, AESTowerField8b>);
+packed_primitive_type!(PackedAESBinaryField16x16b = PackedPrimitiveType<M256, AESTowerField16b>);
+packed_primitive_type!(PackedAESBinaryField8x32b = PackedPrimitiveType<M256, AESTowerField32b>);
+packed_primitive_type!(PackedAESBinaryField4x64b = PackedPrimitiveType<M256, AESTowerField64b>);
+packed_primitive_type!(PackedAESBinaryField2x128b = PackedPrimitiveType<M256, AESTowerField128b>);
 
 // Define multiplication
 cfg_if! {
@@ -2,7 +2,7 @@
 
 use std::ops::Mul;
 
-use super::{super::portable::packed::PackedPrimitiveType, m128::M128};
+use super::{super::portable::packed::packed_primitive_type, m128::M128};
 use crate::{
 	arch::{cfg_if, ReuseMultiplyStrategy},
 	arithmetic_traits::{impl_square_with, InvertOrZero},
@@ -10,7 +10,9 @@ use crate::{
 	BinaryField128bPolyval,
 };
 
-pub type PackedBinaryPolyval1x128b = PackedPrimitiveType<M128, BinaryField128bPolyval>;
+packed_primitive_type!(
+	PackedBinaryPolyval1x128b = PackedPrimitiveType<M128, BinaryField128bPolyval>
+);
 
 // Define multiply
 cfg_if! {
@@ -4,13 +4,15 @@ use cfg_if::cfg_if;
 
 use super::m256::M256;
 use crate::{
-	arch::{portable::packed::PackedPrimitiveType, PairwiseStrategy, ReuseMultiplyStrategy},
+	arch::{portable::packed::packed_primitive_type, PairwiseStrategy, ReuseMultiplyStrategy},
 	arithmetic_traits::{impl_invert_with, impl_square_with},
 	BinaryField128bPolyval,
 };
 
-/// Define packed type
-pub type PackedBinaryPolyval2x128b = PackedPrimitiveType<M256, BinaryField128bPolyval>;
+packed_primitive_type!(
+	/// Define packed type
+	PackedBinaryPolyval2x128b = PackedPrimitiveType<M256, BinaryField128bPolyval>
+);
 
 impl From<PackedBinaryPolyval2x128b> for [u128; 2] {
 	fn from(value: PackedBinaryPolyval2x128b) -> Self {
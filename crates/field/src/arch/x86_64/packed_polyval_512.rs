@@ -3,15 +3,17 @@
 use super::m512::M512;
 use crate::{
 	arch::{
-		cfg_if, portable::packed::PackedPrimitiveType, PairwiseStrategy, ReuseMultiplyStrategy,
+		cfg_if, portable::packed::packed_primitive_type, PairwiseStrategy, ReuseMultiplyStrategy,
 		SimdStrategy,
 	},
 	arithmetic_traits::{impl_invert_with, impl_square_with, impl_transformation_with_strategy},
 	BinaryField128bPolyval,
 };
 
-/// Define packed type
-pub type PackedBinaryPolyval4x128b = PackedPrimitiveType<M512, BinaryField128bPolyval>;
+packed_primitive_type!(
+	/// Define packed type
+	PackedBinaryPolyval4x128b = PackedPrimitiveType<M512, BinaryField128bPolyval>
+);
 
 impl From<PackedBinaryPolyval4x128b> for [u128; 4] {
 	fn from(value: PackedBinaryPolyval4x128b) -> Self {
@@ -31,6 +31,15 @@ pub trait Broadcast<Scalar> {
 	fn broadcast(scalar: Scalar) -> Self;
 }
 
+/// Multiplication that takes a cheaper path when the right-hand side lies in the direct subfield.
+pub trait MulCheckedSubfield {
+	/// Returns `self * rhs`.
+	///
+	/// If every lane of `rhs` lies in the direct subfield, the product is computed with a single
+	/// multiplication over the packed subfield instead of the full tower multiplication.
+	fn mul_checked_subfield(self, rhs: Self) -> Self;
+}
+
 /// Multiplication that is parameterized with some some strategy.
 pub trait TaggedMul<Strategy> {
 	fn mul(self, rhs: Self) -> Self;
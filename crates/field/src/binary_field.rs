@@ -71,6 +71,45 @@ where
 	fn mul_primitive(self, iota: usize) -> Result<Self, Error> {
 		Ok(self * <Self as ExtensionField<BinaryField1b>>::basis(1 << iota)?)
 	}
+
+	/// Returns the smallest tower level $\iota$ such that the element lies in the subfield
+	/// $T_{\iota}$.
+	///
+	/// In the canonical tower the subfield $T_{\iota}$ consists of the elements whose encoding
+	/// fits in the low $2^{\iota}$ bits, and the isomorphism to the canonical tower maps subfields
+	/// to subfields.
+	fn min_tower_level(self) -> usize {
+		let mut bytes = [0u8; 16];
+		Self::Canonical::from(self)
+			.serialize(&mut bytes[..])
+			.expect("tower field elements are at most 16 bytes");
+		let value = u128::from_le_bytes(bytes);
+		(0..Self::TOWER_LEVEL)
+			.find(|&level| value >> (1 << level) == 0)
+			.unwrap_or(Self::TOWER_LEVEL)
+	}
+
+	/// Decomposes the element into its `N_BITS` coordinates over [`BinaryField1b`], least
+	/// significant first.
+	///
+	/// The coordinates are with respect to the [`ExtensionField::basis`] of the field, which for
+	/// the canonical tower are the bits of the underlying value.
+	fn to_bits(&self) -> Vec<BinaryField1b> {
+		<Self as ExtensionField<BinaryField1b>>::iter_bases(self).collect()
+	}
+
+	/// Recomposes an element from its `N_BITS` coordinates over [`BinaryField1b`], the inverse of
+	/// [`Self::to_bits`].
+	///
+	/// ## Throws
+	///
+	/// * `Error::ExtensionDegreeMismatch` if `bits.len() != Self::N_BITS`
+	fn from_bits(bits: &[BinaryField1b]) -> Result<Self, Error> {
+		if bits.len() != Self::N_BITS {
+			return Err(Error::ExtensionDegreeMismatch);
+		}
+		<Self as ExtensionField<BinaryField1b>>::from_bases(bits)
+	}
 }
 
 pub(super) trait TowerExtensionField:
@@ -265,6 +304,11 @@ macro_rules! binary_field {
 			}
 		}
 
+		/// Compares the underlying integers with `subtle`, without data-dependent branches.
+		///
+		/// Prefer this over `==` when the values are secret. The guarantee covers the scalar
+		/// types only; the packed types delegate to the comparison of their SIMD underliers,
+		/// which is not audited for constant-time behavior.
 		impl ConstantTimeEq for $name {
 			fn ct_eq(&self, other: &Self) -> Choice {
 				self.0.ct_eq(&other.0)
@@ -302,6 +346,24 @@ macro_rules! binary_field {
 			}
 		}
 
+		/// Parses the hexadecimal representation written by [`Display`], with or without the
+		/// `0x` prefix.
+		impl std::str::FromStr for $name {
+			type Err = Error;
+
+			fn from_str(s: &str) -> Result<Self, Self::Err> {
+				let digits = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")).unwrap_or(s);
+				let value = u128::from_str_radix(digits, 16).map_err(|err| match err.kind() {
+					std::num::IntErrorKind::PosOverflow => Error::NotInField,
+					_ => Error::InvalidHexString,
+				})?;
+				if Self::N_BITS < 128 && value >> Self::N_BITS != 0 {
+					return Err(Error::NotInField);
+				}
+				Ok(Self::new(<$typ as $crate::underlier::NumCast<u128>>::num_cast_from(value)))
+			}
+		}
+
 		impl Debug for $name {
 			fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
 				let structure_name = std::any::type_name::<$name>().split("::").last().expect("exist");
@@ -725,6 +787,11 @@ macro_rules! serialize_deserialize {
 					::binius_utils::bail!(SerializationError::NotEnoughBytes);
 				}
 				let b: u8 = read_buf.get_u8();
+				if b >> $U != 0 {
+					::binius_utils::bail!(SerializationError::InvalidConstruction {
+						name: stringify!($bin_type),
+					});
+				}
 				Ok(Self(SmallU::<$U>::new(b)))
 			}
 		}
@@ -869,6 +936,7 @@ impl From<BinaryField4b> for u8 {
 pub(crate) mod tests {
 	use bytes::BytesMut;
 	use proptest::prelude::*;
+	use rand::{rngs::StdRng, SeedableRng};
 
 	use super::{
 		BinaryField16b as BF16, BinaryField1b as BF1, BinaryField2b as BF2, BinaryField4b as BF4,
@@ -1234,6 +1302,38 @@ pub(crate) mod tests {
 		}
 	}
 
+	#[test]
+	fn test_min_tower_level() {
+		assert_eq!(BinaryField128b::ZERO.min_tower_level(), 0);
+		assert_eq!(BinaryField128b::ONE.min_tower_level(), 0);
+		assert_eq!(BinaryField128b::from(BF2::from(2)).min_tower_level(), 1);
+		assert_eq!(BinaryField128b::from(BF8::new(0x53)).min_tower_level(), 3);
+		assert_eq!(BinaryField128b::new(1 << 16).min_tower_level(), 5);
+		assert_eq!(BinaryField128b::new(1 << 127).min_tower_level(), 7);
+		assert_eq!(BF4::from(7).min_tower_level(), 2);
+		assert_eq!(BF1::ONE.min_tower_level(), 0);
+	}
+
+	#[test]
+	fn test_bits_round_trip() {
+		let b8 = BF8::new(0b1010_0110);
+		let bits = b8.to_bits();
+		assert_eq!(bits, [0, 1, 1, 0, 0, 1, 0, 1].map(BF1::from));
+		assert_eq!(BF8::from_bits(&bits).unwrap(), b8);
+
+		for value in [0, 1, 0x789ABCDE, u32::MAX] {
+			let b32 = BinaryField32b::new(value);
+			let bits = b32.to_bits();
+			assert_eq!(bits.len(), 32);
+			assert_eq!(BinaryField32b::from_bits(&bits).unwrap(), b32);
+		}
+
+		assert!(matches!(
+			BinaryField32b::from_bits(&BF8::ONE.to_bits()),
+			Err(Error::ExtensionDegreeMismatch)
+		));
+	}
+
 	#[test]
 	fn test_serialization() {
 		let mut buffer = BytesMut::new();
@@ -1267,6 +1367,120 @@ pub(crate) mod tests {
 		assert_eq!(BinaryField128b::deserialize(&mut read_buffer).unwrap(), b128);
 	}
 
+	fn check_serialization_round_trip<F>(rng: &mut impl RngCore)
+	where
+		F: BinaryField + SerializeBytes + DeserializeBytes,
+	{
+		let value = <F as Field>::random(&mut *rng);
+		let mut bytes = Vec::new();
+		value.serialize(&mut bytes).unwrap();
+		assert_eq!(bytes.len(), F::N_BITS.div_ceil(8));
+		assert_eq!(F::deserialize(bytes.as_slice()).unwrap(), value);
+
+		assert!(matches!(
+			F::deserialize(&bytes[..bytes.len() - 1]),
+			Err(SerializationError::NotEnoughBytes)
+		));
+		let mut short_buf = vec![0u8; bytes.len() - 1];
+		assert!(matches!(
+			value.serialize(short_buf.as_mut_slice()),
+			Err(SerializationError::WriteBufferFull)
+		));
+	}
+
+	#[test]
+	fn test_serialization_round_trip_per_width() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			check_serialization_round_trip::<BinaryField1b>(&mut rng);
+			check_serialization_round_trip::<BinaryField2b>(&mut rng);
+			check_serialization_round_trip::<BinaryField4b>(&mut rng);
+			check_serialization_round_trip::<BinaryField8b>(&mut rng);
+			check_serialization_round_trip::<BinaryField16b>(&mut rng);
+			check_serialization_round_trip::<BinaryField32b>(&mut rng);
+			check_serialization_round_trip::<BinaryField64b>(&mut rng);
+			check_serialization_round_trip::<BinaryField128b>(&mut rng);
+		}
+
+		assert!(matches!(
+			BinaryField2b::deserialize(&[0x4u8][..]),
+			Err(SerializationError::InvalidConstruction { .. })
+		));
+	}
+
+	#[test]
+	fn test_stream_serialization() {
+		use binius_utils::serialization::{IoReader, IoWriter};
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let values = (0..1 << 16)
+			.map(|_| <BinaryField32b as Field>::random(&mut rng))
+			.collect::<Vec<_>>();
+
+		let mut writer = IoWriter::new(std::io::Cursor::new(Vec::new()));
+		values.serialize(&mut writer).unwrap();
+		let mut cursor = writer.finish().unwrap();
+		assert_eq!(cursor.get_ref().len(), 3 + 4 * values.len());
+
+		cursor.set_position(0);
+		let mut reader = IoReader::new(cursor);
+		assert_eq!(Vec::<BinaryField32b>::deserialize(&mut reader).unwrap(), values);
+	}
+
+	#[test]
+	fn test_hex_display_from_str() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let b8 = <BinaryField8b as Field>::random(&mut rng);
+			assert_eq!(b8.to_string().parse::<BinaryField8b>().unwrap(), b8);
+			let b32 = <BinaryField32b as Field>::random(&mut rng);
+			assert_eq!(b32.to_string().parse::<BinaryField32b>().unwrap(), b32);
+			let b128 = <BinaryField128b as Field>::random(&mut rng);
+			assert_eq!(b128.to_string().parse::<BinaryField128b>().unwrap(), b128);
+		}
+
+		assert_eq!(BinaryField8b::new(0x0a).to_string(), "0x0a");
+		assert_eq!("0x0a".parse::<BinaryField8b>().unwrap(), BinaryField8b::new(0x0a));
+		assert_eq!("ff".parse::<BinaryField8b>().unwrap(), BinaryField8b::new(0xff));
+		assert_eq!(
+			"0x789ABCDE".parse::<BinaryField32b>().unwrap(),
+			BinaryField32b::new(0x789abcde)
+		);
+		assert_eq!("1".parse::<BinaryField1b>().unwrap(), BinaryField1b::ONE);
+
+		assert!(matches!("0x100".parse::<BinaryField8b>(), Err(Error::NotInField)));
+		assert!(matches!("0x1_0000_0000".parse::<BinaryField32b>(), Err(Error::InvalidHexString)));
+		assert!(matches!("0x100000000".parse::<BinaryField32b>(), Err(Error::NotInField)));
+		assert!(matches!(
+			format!("0x1{}", "0".repeat(32)).parse::<BinaryField128b>(),
+			Err(Error::NotInField)
+		));
+		assert!(matches!("0x".parse::<BinaryField8b>(), Err(Error::InvalidHexString)));
+		assert!(matches!("2".parse::<BinaryField1b>(), Err(Error::NotInField)));
+	}
+
+	#[test]
+	fn test_ct_eq() {
+		fn check<F: Field + ConstantTimeEq>(rng: &mut StdRng) {
+			for _ in 0..64 {
+				let a = <F as Field>::random(&mut *rng);
+				let b = if rng.gen() {
+					a
+				} else {
+					<F as Field>::random(&mut *rng)
+				};
+				assert_eq!(bool::from(a.ct_eq(&b)), a == b);
+			}
+		}
+
+		let mut rng = StdRng::seed_from_u64(0);
+		check::<BinaryField1b>(&mut rng);
+		check::<BinaryField4b>(&mut rng);
+		check::<BinaryField8b>(&mut rng);
+		check::<BinaryField32b>(&mut rng);
+		check::<BinaryField128b>(&mut rng);
+	}
+
 	#[test]
 	fn test_gf2_new_unchecked() {
 		for i in 0..2 {
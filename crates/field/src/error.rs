@@ -15,4 +15,6 @@ pub enum Error {
 	/// in the binary field.
 	#[error("value is not in the field")]
 	NotInField,
+	#[error("the string is not a hexadecimal field element")]
+	InvalidHexString,
 }
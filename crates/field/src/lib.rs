@@ -11,7 +11,7 @@
 
 #![cfg_attr(
 	all(target_arch = "x86_64", not(feature = "stable_only")),
-	feature(stdarch_x86_avx512)
+	feature(avx512_target_feature, stdarch_x86_avx512)
 )]
 
 pub mod aes_field;
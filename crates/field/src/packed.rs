@@ -5,6 +5,7 @@
 //! Interfaces are derived from [`plonky2`](https://github.com/mir-protocol/plonky2).
 
 use std::{
+	cmp::Ordering,
 	fmt::Debug,
 	iter::{self, Product, Sum},
 	ops::{Add, AddAssign, Mul, MulAssign, Sub, SubAssign},
@@ -20,8 +21,9 @@ use super::{
 	Error,
 };
 use crate::{
-	arithmetic_traits::InvertOrZero, underlier::WithUnderlier, BinaryField, ExtensionField, Field,
-	PackedExtension,
+	arithmetic_traits::InvertOrZero,
+	underlier::{UnderlierWithBitOps, WithUnderlier},
+	BinaryField, ExtensionField, Field, PackedExtension,
 };
 
 /// A packed field represents a vector of underlying field elements.
@@ -129,6 +131,40 @@ pub trait PackedField:
 		slice.iter().flat_map(Self::iter)
 	}
 
+	/// Returns the inner product $\sum_i a_i b_i$ of two equal-length slices.
+	///
+	/// Binary tower multiplication reduces eagerly, so the default is a plain accumulation. It is
+	/// a separate method so that implementations can defer reductions or use wider SIMD.
+	fn sum_of_products(a: &[Self], b: &[Self]) -> Self {
+		debug_assert_eq!(a.len(), b.len(), "slices must have the same length");
+		a.iter()
+			.zip(b)
+			.fold(Self::zero(), |acc, (&a_i, &b_i)| acc + a_i * b_i)
+	}
+
+	/// Returns the number of set bits in the underlier.
+	///
+	/// This is a raw bit count over all lanes. It equals the number of nonzero lanes only for
+	/// 1-bit scalars; for wider scalars a nonzero lane may contribute several bits.
+	fn popcount(&self) -> u32 {
+		self.iter()
+			.map(|scalar| {
+				bytemuck::bytes_of(&scalar.to_underlier())
+					.iter()
+					.map(|byte| byte.count_ones())
+					.sum::<u32>()
+			})
+			.sum()
+	}
+
+	/// Lists the hexadecimal values of the scalar lanes, from lane 0 up, separated by commas.
+	fn debug_lanes(&self) -> String {
+		self.iter()
+			.map(|scalar| scalar.to_string())
+			.collect::<Vec<_>>()
+			.join(", ")
+	}
+
 	#[inline]
 	fn zero() -> Self {
 		Self::broadcast(Self::Scalar::ZERO)
@@ -148,9 +184,44 @@ pub trait PackedField:
 		result
 	}
 
+	/// Returns the packed value with lane `i` set to `scalar` and all other lanes zero.
+	///
+	/// ## Examples
+	///
+	/// ```
+	/// use binius_field::{BinaryField16b, PackedBinaryField8x16b, PackedField};
+	///
+	/// let unit = PackedBinaryField8x16b::unit(2, BinaryField16b::new(7));
+	/// assert_eq!(
+	///     unit,
+	///     PackedBinaryField8x16b::from_scalars([0, 0, 7, 0, 0, 0, 0, 0].map(BinaryField16b::new))
+	/// );
+	/// ```
+	///
+	/// ## Preconditions
+	///
+	/// * `i` must be less than `WIDTH`.
+	#[inline]
+	fn unit(i: usize, scalar: Self::Scalar) -> Self {
+		let mut result = Self::zero();
+		result.set(i, scalar);
+
+		result
+	}
+
 	fn random(rng: impl RngCore) -> Self;
 	fn broadcast(scalar: Self::Scalar) -> Self;
 
+	/// Returns the packed value with every lane set to the scalar at lane `i` of `self`.
+	///
+	/// ## Preconditions
+	///
+	/// * `i` must be less than `WIDTH`.
+	#[inline]
+	fn broadcast_lane(self, i: usize) -> Self {
+		Self::broadcast(self.get(i))
+	}
+
 	/// Construct a packed field element from a function that returns scalar values by index.
 	fn from_fn(f: impl FnMut(usize) -> Self::Scalar) -> Self;
 
@@ -187,6 +258,85 @@ pub trait PackedField:
 	/// Returns the packed inverse values or zeroes at indices where `self` is zero.
 	fn invert_or_zero(self) -> Self;
 
+	/// Raises every element to the power `exp` with a fixed-window ladder over 4-bit windows,
+	/// from the most significant window down.
+	///
+	/// This always runs 64 squarings and 16 multiplications, but the multiplied table entry is
+	/// looked up by the bits of `exp`, so it is not constant time with respect to `exp`.
+	fn pow_u64(self, exp: u64) -> Self {
+		const WINDOW_BITS: usize = 4;
+
+		let mut table = [Self::one(); 1 << WINDOW_BITS];
+		for i in 1..table.len() {
+			table[i] = table[i - 1] * self;
+		}
+
+		let mut res = Self::one();
+		for window in (0..u64::BITS as usize / WINDOW_BITS).rev() {
+			for _ in 0..WINDOW_BITS {
+				res = res.square();
+			}
+			let digit = (exp >> (window * WINDOW_BITS)) as usize & (table.len() - 1);
+			res *= table[digit];
+		}
+		res
+	}
+
+	/// Raises every element to the power `exp`, skipping the leading zero bits of `exp`.
+	///
+	/// **This operation is variable time with respect to `exp`.** Prefer [`Self::pow_u64`] when the
+	/// exponent is large or its bit length should not leak.
+	fn pow_u64_vartime(self, exp: u64) -> Self {
+		match exp {
+			0 => Self::one(),
+			1 => self,
+			_ => {
+				let mut res = self;
+				for i in (0..63 - exp.leading_zeros() as usize).rev() {
+					res = res.square();
+					if (exp >> i) & 1 == 1 {
+						res *= self;
+					}
+				}
+				res
+			}
+		}
+	}
+
+	/// Replaces every element of the slice with [`Self::invert_or_zero`] of it.
+	///
+	/// This uses Montgomery's trick: the running products of the elements are inverted with a
+	/// single call to [`Self::invert_or_zero`], and the individual inverses are recovered in a
+	/// backward pass, for a total of one inversion and `3 * elems.len()` multiplications. Zero
+	/// lanes are replaced by one in the running products, so that they don't zero out the
+	/// inverses of the other elements, and stay zero in the result.
+	fn batch_invert_or_zero(elems: &mut [Self]) {
+		// Holds, for every element, the running product of the masked elements before it and the
+		// mask whose lane `i` is one if lane `i` of the element is zero, and zero otherwise.
+		let mut scratch = Vec::with_capacity(elems.len());
+		let mut product = Self::one();
+		for elem in elems.iter_mut() {
+			let zero_lanes = Self::from_scalars(elem.iter().map(|scalar| {
+				if scalar == Self::Scalar::ZERO {
+					Self::Scalar::ONE
+				} else {
+					Self::Scalar::ZERO
+				}
+			}));
+			*elem += zero_lanes;
+			scratch.push((product, zero_lanes));
+			product *= *elem;
+		}
+
+		let mut suffix_inverse = product.invert_or_zero();
+		for (elem, (prefix_product, zero_lanes)) in elems.iter_mut().zip(scratch).rev() {
+			let masked = *elem;
+			// The inverse of the masked element is one in the zero lanes.
+			*elem = suffix_inverse * prefix_product - zero_lanes;
+			suffix_inverse *= masked;
+		}
+	}
+
 	/// Interleaves blocks of this packed vector with another packed vector.
 	///
 	/// The operation can be seen as stacking the two vectors, dividing them into 2x2 matrices of
@@ -259,6 +409,69 @@ pub trait PackedField:
 			self.iter().skip(block_idx * block_len).take(block_len).flat_map(|elem| iter::repeat_n(elem, repeat))
 		)
 	}
+
+	/// Collects the scalars at lanes `start`, `start + step`, `start + 2 * step`, ... that are
+	/// within the packing width.
+	///
+	/// This is a scalar loop. When `step` is a power of two the same lanes could be gathered with
+	/// [`Self::interleave`], which would be faster for wide packed types.
+	///
+	/// ## Preconditions
+	///
+	/// * `step` must be non-zero.
+	fn stride_lanes(self, start: usize, step: usize) -> Vec<Self::Scalar> {
+		assert!(step > 0);
+
+		(start..Self::WIDTH).step_by(step).map(|i| self.get(i)).collect()
+	}
+
+	/// Returns the running XOR of the lanes, i.e. the value whose lane `i` is the sum of the lanes
+	/// `0..=i` of `self`.
+	///
+	/// The default implementation accumulates lane by lane; packed types backed by an underlier
+	/// override it with a logarithmic number of shifts and XORs.
+	fn prefix_xor(self) -> Self {
+		let mut result = self;
+		let mut acc = Self::Scalar::ZERO;
+		for i in 0..Self::WIDTH {
+			acc += self.get(i);
+			result.set(i, acc);
+		}
+		result
+	}
+
+	/// Flips every bit of every lane.
+	///
+	/// This is a bitwise operation on the representation rather than a field operation. Since
+	/// addition of binary field elements is the XOR of their representations, it is the same as
+	/// adding the element with all bits set to every lane; for `BinaryField1b` lanes this is
+	/// `self + Self::one()`.
+	fn not(self) -> Self
+	where
+		<Self::Scalar as WithUnderlier>::Underlier: UnderlierWithBitOps,
+	{
+		self + Self::broadcast(Self::Scalar::from_underlier(UnderlierWithBitOps::ONES))
+	}
+
+	/// Compares two packed values lexicographically by their lanes, starting from lane 0.
+	///
+	/// This is the order implemented by [`Ord`] for the packed types. It is only meant to make
+	/// sorting deterministic and has no meaning with respect to the field structure.
+	fn cmp_lanes(&self, other: &Self) -> Ordering
+	where
+		Self::Scalar: Ord,
+	{
+		self.iter().cmp(other.iter())
+	}
+
+	/// Returns the index of the highest non-zero lane, or `None` if all the lanes are zero.
+	///
+	/// This is useful to find the trailing zeros of a column.
+	fn last_nonzero_lane(&self) -> Option<usize> {
+		(0..Self::WIDTH)
+			.rev()
+			.find(|&i| self.get(i) != Self::Scalar::ZERO)
+	}
 }
 
 /// Iterate over scalar values in a packed field slice.
@@ -445,6 +658,7 @@ impl<PT> PackedBinaryField for PT where PT: PackedField<Scalar: BinaryField> {}
 
 #[cfg(test)]
 mod tests {
+	use proptest::prelude::*;
 	use rand::{
 		distributions::{Distribution, Uniform},
 		rngs::StdRng,
@@ -655,4 +869,438 @@ mod tests {
 	fn test_iteration() {
 		run_for_all_packed_fields(PackedFieldIterationTest);
 	}
+
+	struct PackedFieldBroadcastLaneTest;
+
+	impl PackedFieldTest for PackedFieldBroadcastLaneTest {
+		fn run<P: PackedField>(&self) {
+			let mut rng = StdRng::seed_from_u64(0);
+			let value = P::random(&mut rng);
+
+			for i in 0..P::WIDTH {
+				let broadcast = value.broadcast_lane(i);
+				for j in 0..P::WIDTH {
+					assert_eq!(broadcast.get(j), value.get(i));
+				}
+			}
+		}
+	}
+
+	#[test]
+	fn test_broadcast_lane() {
+		run_for_all_packed_fields(PackedFieldBroadcastLaneTest);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_broadcast_lane_out_of_range() {
+		PackedBinaryField8x16b::one().broadcast_lane(8);
+	}
+
+	#[test]
+	fn test_stride_lanes() {
+		let packed =
+			PackedBinaryField8x16b::from_scalars([0, 1, 2, 3, 4, 5, 6, 7].map(BinaryField16b::new));
+
+		assert_eq!(packed.stride_lanes(0, 2), [0, 2, 4, 6].map(BinaryField16b::new));
+		assert_eq!(packed.stride_lanes(1, 2), [1, 3, 5, 7].map(BinaryField16b::new));
+		assert_eq!(packed.stride_lanes(2, 3), [2, 5].map(BinaryField16b::new));
+		assert_eq!(packed.stride_lanes(0, 1), packed.iter().collect::<Vec<_>>());
+		assert!(packed.stride_lanes(8, 1).is_empty());
+	}
+
+	#[test]
+	fn test_prefix_xor() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let packed = PackedBinaryField16x8b::random(&mut rng);
+			let expected = packed
+				.iter()
+				.scan(BinaryField8b::ZERO, |acc, lane| {
+					*acc += lane;
+					Some(*acc)
+				})
+				.collect::<Vec<_>>();
+
+			assert_eq!(packed.prefix_xor().iter().collect::<Vec<_>>(), expected);
+		}
+	}
+
+	#[test]
+	fn test_not() {
+		let mut rng = StdRng::seed_from_u64(0);
+		for _ in 0..16 {
+			let packed = PackedBinaryField16x8b::random(&mut rng);
+			let flipped = packed.not();
+			assert_eq!(flipped.not(), packed);
+			for (lane, flipped_lane) in packed.iter().zip(flipped.iter()) {
+				assert_eq!(flipped_lane.val(), !lane.val());
+			}
+
+			let bits = PackedBinaryField128x1b::random(&mut rng);
+			assert_eq!(bits.not().not(), bits);
+			assert_eq!(bits.not(), bits + PackedBinaryField128x1b::one());
+		}
+	}
+
+	fn check_batch_invert_or_zero<P: PackedField>(seed: u64, zero_mask: u64) {
+		let mut rng = StdRng::seed_from_u64(seed);
+		let mut elems = (0..7).map(|_| P::random(&mut rng)).collect::<Vec<_>>();
+		for (k, (j, i)) in (0..elems.len())
+			.flat_map(|j| (0..P::WIDTH).map(move |i| (j, i)))
+			.enumerate()
+		{
+			if (zero_mask >> (k % 64)) & 1 == 1 {
+				elems[j].set(i, P::Scalar::ZERO);
+			}
+		}
+		elems.push(P::zero());
+
+		let expected = elems
+			.iter()
+			.map(|elem| elem.invert_or_zero())
+			.collect::<Vec<_>>();
+		P::batch_invert_or_zero(&mut elems);
+		assert_eq!(elems, expected);
+	}
+
+	fn check_batch_invert_or_zero_interleaved_zeros<P: PackedField>() {
+		let mut rng = StdRng::seed_from_u64(0);
+		// Every third scalar of the flattened slice is zero, so the zero lanes shift position from
+		// one element to the next and runs of zeros straddle the element boundaries.
+		let mut elems = (0..9).map(|_| P::random(&mut rng)).collect::<Vec<_>>();
+		for (j, elem) in elems.iter_mut().enumerate() {
+			for i in 0..P::WIDTH {
+				if (j * P::WIDTH + i) % 3 == 0 {
+					elem.set(i, P::Scalar::ZERO);
+				} else if elem.get(i) == P::Scalar::ZERO {
+					elem.set(i, P::Scalar::ONE);
+				}
+			}
+		}
+
+		let expected = elems
+			.iter()
+			.map(|elem| elem.invert_or_zero())
+			.collect::<Vec<_>>();
+		P::batch_invert_or_zero(&mut elems);
+		assert_eq!(elems, expected);
+		for (j, elem) in elems.iter().enumerate() {
+			for i in 0..P::WIDTH {
+				assert_eq!(elem.get(i) == P::Scalar::ZERO, (j * P::WIDTH + i) % 3 == 0);
+			}
+		}
+	}
+
+	#[test]
+	fn test_batch_invert_or_zero_interleaved_zeros() {
+		check_batch_invert_or_zero_interleaved_zeros::<BinaryField8b>();
+		check_batch_invert_or_zero_interleaved_zeros::<PackedBinaryField8x16b>();
+		check_batch_invert_or_zero_interleaved_zeros::<PackedBinaryField4x32b>();
+		check_batch_invert_or_zero_interleaved_zeros::<PackedBinaryField2x64b>();
+		check_batch_invert_or_zero_interleaved_zeros::<PackedBinaryPolyval2x128b>();
+		check_batch_invert_or_zero_interleaved_zeros::<ByteSlicedAES32x16b>();
+	}
+
+	proptest! {
+		#[test]
+		fn test_batch_invert_or_zero(seed in any::<u64>(), zero_mask in any::<u64>()) {
+			check_batch_invert_or_zero::<BinaryField8b>(seed, zero_mask);
+			check_batch_invert_or_zero::<PackedBinaryField8x16b>(seed, zero_mask);
+			check_batch_invert_or_zero::<PackedBinaryField4x32b>(seed, zero_mask);
+			check_batch_invert_or_zero::<PackedBinaryField2x64b>(seed, zero_mask);
+			check_batch_invert_or_zero::<PackedBinaryField128x1b>(seed, zero_mask);
+			check_batch_invert_or_zero::<PackedBinaryPolyval2x128b>(seed, zero_mask);
+			check_batch_invert_or_zero::<ByteSlicedAES32x16b>(seed, zero_mask);
+		}
+	}
+
+	proptest! {
+		#[test]
+		fn test_sum_of_products(seed in any::<u64>(), len in 0usize..32) {
+			let mut rng = StdRng::seed_from_u64(seed);
+			let a = iter::repeat_with(|| PackedBinaryField8x16b::random(&mut rng))
+				.take(len)
+				.collect::<Vec<_>>();
+			let b = iter::repeat_with(|| PackedBinaryField8x16b::random(&mut rng))
+				.take(len)
+				.collect::<Vec<_>>();
+
+			let mut expected = PackedBinaryField8x16b::zero();
+			for i in 0..len {
+				expected += a[i] * b[i];
+			}
+			prop_assert_eq!(PackedBinaryField8x16b::sum_of_products(&a, &b), expected);
+		}
+	}
+
+	#[test]
+	fn test_batch_invert_or_zero_empty() {
+		PackedBinaryField8x16b::batch_invert_or_zero(&mut []);
+	}
+
+	fn check_sort_by_lanes<P: PackedField<Scalar: Ord> + Ord>(mut rng: impl RngCore) {
+		// Draw from a few values so that the vector has duplicates.
+		let choices = (0..4).map(|_| P::random(&mut rng)).collect::<Vec<_>>();
+		let dist = Uniform::from(0..choices.len());
+		let values = (0..32)
+			.map(|_| choices[dist.sample(&mut rng)])
+			.collect::<Vec<_>>();
+
+		let mut sorted = values.clone();
+		sorted.sort();
+		let mut expected = values.clone();
+		expected.sort_by(|a, b| {
+			a.iter()
+				.zip(b.iter())
+				.map(|(a, b)| a.cmp(&b))
+				.find(|ordering| ordering.is_ne())
+				.unwrap_or(Ordering::Equal)
+		});
+		assert_eq!(sorted, expected);
+
+		let mut indexed = values.iter().copied().enumerate().collect::<Vec<_>>();
+		indexed.sort_by(|(_, a), (_, b)| a.cmp_lanes(b));
+		assert!(indexed
+			.windows(2)
+			.all(|pair| pair[0].1 < pair[1].1 || (pair[0].1 == pair[1].1 && pair[0].0 < pair[1].0)));
+	}
+
+	#[test]
+	fn test_cmp_lanes() {
+		let a = PackedBinaryField4x32b::from_scalars([1, 5, 0, 0].map(BinaryField32b::new));
+		let b = PackedBinaryField4x32b::from_scalars([1, 2, 9, 9].map(BinaryField32b::new));
+		assert_eq!(a.cmp_lanes(&b), Ordering::Greater);
+		assert_eq!(b.cmp_lanes(&a), Ordering::Less);
+		assert_eq!(a.cmp_lanes(&a), Ordering::Equal);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		check_sort_by_lanes::<BinaryField8b>(&mut rng);
+		check_sort_by_lanes::<PackedBinaryField8x16b>(&mut rng);
+		check_sort_by_lanes::<PackedBinaryField4x128b>(&mut rng);
+		check_sort_by_lanes::<ByteSlicedAES32x16b>(&mut rng);
+	}
+
+	fn check_last_nonzero_lane<P: PackedField>(mut rng: impl RngCore) {
+		assert_eq!(P::zero().last_nonzero_lane(), None);
+		for i in 0..P::WIDTH {
+			let mut value = P::from_fn(|j| {
+				if j < i {
+					<P::Scalar as Field>::random(&mut rng)
+				} else {
+					P::Scalar::ZERO
+				}
+			});
+			value.set(i, P::Scalar::ONE);
+			assert_eq!(value.last_nonzero_lane(), Some(i));
+		}
+	}
+
+	#[test]
+	fn test_last_nonzero_lane() {
+		let value = PackedBinaryField16x8b::from_scalars(
+			[7, 0, 3, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0].map(BinaryField8b::new),
+		);
+		assert_eq!(value.last_nonzero_lane(), Some(5));
+
+		let mut rng = StdRng::seed_from_u64(0);
+		check_last_nonzero_lane::<BinaryField8b>(&mut rng);
+		check_last_nonzero_lane::<PackedBinaryField128x1b>(&mut rng);
+		check_last_nonzero_lane::<PackedBinaryField16x8b>(&mut rng);
+		check_last_nonzero_lane::<PackedBinaryField2x64b>(&mut rng);
+		check_last_nonzero_lane::<PackedBinaryField256x1b>(&mut rng);
+		check_last_nonzero_lane::<PackedBinaryField4x128b>(&mut rng);
+		check_last_nonzero_lane::<ByteSlicedAES32x16b>(&mut rng);
+	}
+
+	#[test]
+	fn test_unit() {
+		let value = BinaryField8b::new(0x53);
+		for i in 0..PackedBinaryField16x8b::WIDTH {
+			let unit = PackedBinaryField16x8b::unit(i, value);
+			for j in 0..PackedBinaryField16x8b::WIDTH {
+				let expected = if j == i { value } else { BinaryField8b::ZERO };
+				assert_eq!(unit.get(j), expected);
+			}
+		}
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_unit_out_of_range() {
+		PackedBinaryField16x8b::unit(PackedBinaryField16x8b::WIDTH, BinaryField8b::ONE);
+	}
+
+	fn check_packed_serialization<P>(rng: &mut StdRng)
+	where
+		P: PackedField
+			+ binius_utils::serialization::SerializeBytes
+			+ binius_utils::serialization::DeserializeBytes,
+	{
+		use binius_utils::serialization::Error as SerializationError;
+
+		for _ in 0..8 {
+			let value = P::random(&mut *rng);
+			let mut bytes = Vec::new();
+			value.serialize(&mut bytes).unwrap();
+			assert_eq!(bytes.len(), size_of::<P>());
+			assert_eq!(P::deserialize(bytes.as_slice()).unwrap(), value);
+
+			assert!(matches!(
+				P::deserialize(&bytes[..bytes.len() - 1]),
+				Err(SerializationError::NotEnoughBytes)
+			));
+			let mut short_buf = vec![0u8; bytes.len() - 1];
+			assert!(matches!(
+				value.serialize(short_buf.as_mut_slice()),
+				Err(SerializationError::WriteBufferFull)
+			));
+		}
+	}
+
+	#[test]
+	fn test_packed_serialization_round_trip() {
+		let mut rng = StdRng::seed_from_u64(0);
+		check_packed_serialization::<PackedBinaryField8x1b>(&mut rng);
+		check_packed_serialization::<PackedBinaryField4x32b>(&mut rng);
+		check_packed_serialization::<PackedBinaryField16x8b>(&mut rng);
+		check_packed_serialization::<PackedBinaryField1x128b>(&mut rng);
+		check_packed_serialization::<PackedBinaryField256x1b>(&mut rng);
+		check_packed_serialization::<PackedBinaryField2x128b>(&mut rng);
+		check_packed_serialization::<PackedBinaryField4x128b>(&mut rng);
+	}
+
+	#[test]
+	fn test_packed_serialization_little_endian() {
+		use binius_utils::serialization::{DeserializeBytes, SerializeBytes};
+
+		fn check<P: PackedField + SerializeBytes + DeserializeBytes>(value: P, expected: &[u8]) {
+			let mut bytes = Vec::new();
+			value.serialize(&mut bytes).unwrap();
+			assert_eq!(bytes, expected);
+			assert_eq!(P::deserialize(bytes.as_slice()).unwrap(), value);
+		}
+
+		// The scalars are written in order, each one least significant byte first.
+		let bytes = (0..64).collect::<Vec<u8>>();
+		check(
+			PackedBinaryField4x32b::from_fn(|i| {
+				BinaryField32b::new(u32::from_le_bytes(bytes[4 * i..4 * i + 4].try_into().unwrap()))
+			}),
+			&bytes[..16],
+		);
+		check(PackedBinaryField16x8b::from_fn(|i| BinaryField8b::new(bytes[i])), &bytes[..16]);
+		check(
+			PackedBinaryField4x128b::from_fn(|i| {
+				BinaryField128b::new(u128::from_le_bytes(
+					bytes[16 * i..16 * i + 16].try_into().unwrap(),
+				))
+			}),
+			&bytes,
+		);
+
+		// Sub-byte scalars fill each byte from its least significant bit.
+		check(
+			PackedBinaryField8x1b::from_fn(|i| BinaryField1b::from((0b1100_0101u8 >> i) & 1)),
+			&[0b1100_0101],
+		);
+	}
+
+	fn check_conditional_assign<P: PackedField + subtle::ConditionallySelectable>(
+		rng: &mut StdRng,
+	) {
+		let original = P::random(&mut *rng);
+		let other = P::random(&mut *rng);
+
+		let mut value = original;
+		value.conditional_assign(&other, subtle::Choice::from(0));
+		assert_eq!(value, original);
+		value.conditional_assign(&other, subtle::Choice::from(1));
+		assert_eq!(value, other);
+	}
+
+	#[test]
+	fn test_conditional_assign() {
+		let mut rng = StdRng::seed_from_u64(0);
+		check_conditional_assign::<PackedBinaryField8x1b>(&mut rng);
+		check_conditional_assign::<PackedBinaryField128x1b>(&mut rng);
+		check_conditional_assign::<PackedBinaryField16x8b>(&mut rng);
+		check_conditional_assign::<PackedBinaryField2x64b>(&mut rng);
+		check_conditional_assign::<PackedBinaryField4x128b>(&mut rng);
+	}
+
+	fn check_pow<P: PackedField>(rng: &mut impl RngCore) {
+		let x = P::random(&mut *rng);
+		assert_eq!(x.pow_u64(0), P::one());
+		assert_eq!(x.pow_u64(1), x);
+		assert_eq!(x.pow_u64_vartime(0), P::one());
+		assert_eq!(x.pow_u64_vartime(1), x);
+
+		for exp in [2, 3, 5, 13, 64, 255] {
+			let expected = (0..exp).fold(P::one(), |acc, _| acc * x);
+			assert_eq!(x.pow_u64(exp), expected);
+			assert_eq!(x.pow_u64_vartime(exp), expected);
+		}
+
+		for scalar_exp in [0xdead_beef_1234_5678, u64::MAX] {
+			assert_eq!(x.pow_u64(scalar_exp), x.pow_u64_vartime(scalar_exp));
+			assert_eq!(x.pow_u64(scalar_exp).get(0), Field::pow(&x.get(0), [scalar_exp]));
+		}
+	}
+
+	#[test]
+	fn test_pow() {
+		let mut rng = StdRng::seed_from_u64(0);
+		check_pow::<BinaryField8b>(&mut rng);
+		check_pow::<PackedBinaryField16x8b>(&mut rng);
+		check_pow::<PackedBinaryField4x32b>(&mut rng);
+		check_pow::<PackedBinaryField2x128b>(&mut rng);
+	}
+
+	#[test]
+	fn test_popcount() {
+		let value = 0x8000_0000_0000_0001_dead_beef_0f0f_00ffu128;
+		let expected = value.count_ones();
+		assert_eq!(PackedBinaryField128x1b::from_underlier(value.into()).popcount(), expected);
+		assert_eq!(PackedBinaryField16x8b::from_underlier(value.into()).popcount(), expected);
+		assert_eq!(PackedBinaryField1x128b::from_underlier(value.into()).popcount(), expected);
+		assert_eq!(BinaryField128b::new(value).popcount(), expected);
+
+		// For 1-bit lanes the bit count is the number of nonzero lanes.
+		let packed = PackedBinaryField8x1b::from_fn(|i| BinaryField1b::from((i % 3 == 0) as u8));
+		assert_eq!(
+			packed.popcount(),
+			packed.iter().filter(|&x| x != BinaryField1b::ZERO).count() as u32
+		);
+
+		let mut rng = StdRng::seed_from_u64(0);
+		let packed = PackedBinaryField4x128b::random(&mut rng);
+		let expected = packed.iter().map(|x| x.val().count_ones()).sum::<u32>();
+		assert_eq!(packed.popcount(), expected);
+	}
+
+	#[test]
+	fn test_hex_formatting() {
+		let packed = PackedBinaryField16x8b::from_fn(|i| BinaryField8b::new(i as u8 * 0x11));
+		assert_eq!(format!("{packed:x}"), "ffeeddccbbaa99887766554433221100");
+		assert_eq!(format!("{packed:X}"), "FFEEDDCCBBAA99887766554433221100");
+		assert_eq!(format!("{packed:#x}"), "0xffeeddccbbaa99887766554433221100");
+		assert_eq!(
+			packed.debug_lanes(),
+			"0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, \
+			0x88, 0x99, 0xaa, 0xbb, 0xcc, 0xdd, 0xee, 0xff"
+		);
+
+		let packed = PackedBinaryField8x1b::from_fn(|i| BinaryField1b::from((i % 3 == 0) as u8));
+		assert_eq!(format!("{packed:x}"), "49");
+		assert_eq!(packed.debug_lanes(), "0x1, 0x0, 0x0, 0x1, 0x0, 0x0, 0x1, 0x0");
+
+		let packed = PackedBinaryField4x128b::from_fn(|i| BinaryField128b::new(1 << (i * 8)));
+		let expected = (0..4)
+			.rev()
+			.map(|i| format!("{:032x}", 1u128 << (i * 8)))
+			.collect::<Vec<_>>()
+			.concat();
+		assert_eq!(format!("{packed:x}"), expected);
+	}
 }
@@ -4,6 +4,31 @@ pub use crate::arch::{
 	packed_1::*, packed_128::*, packed_16::*, packed_2::*, packed_256::*, packed_32::*,
 	packed_4::*, packed_512::*, packed_64::*, packed_8::*,
 };
+use crate::{BinaryField1b, Field, PackedField};
+
+/// Conversions between packed [`BinaryField1b`] values and boolean slices.
+pub trait PackedBits: PackedField<Scalar = BinaryField1b> {
+	/// Packs the booleans into the lowest lanes, leaving the remaining lanes zero.
+	///
+	/// ## Preconditions
+	///
+	/// * `bits` must have length at most `WIDTH`.
+	fn from_bools(bits: &[bool]) -> Self {
+		assert!(bits.len() <= Self::WIDTH, "at most WIDTH booleans can be packed");
+
+		Self::from_fn(|i| match bits.get(i) {
+			Some(true) => BinaryField1b::ONE,
+			_ => BinaryField1b::ZERO,
+		})
+	}
+
+	/// Returns the lanes as booleans.
+	fn to_bools(&self) -> Vec<bool> {
+		self.iter().map(|bit| bit == BinaryField1b::ONE).collect()
+	}
+}
+
+impl<P: PackedField<Scalar = BinaryField1b>> PackedBits for P {}
 
 /// Common code to test different multiply, square and invert implementations
 #[cfg(test)]
@@ -835,6 +860,42 @@ mod tests {
 		Field, PackedField, PackedFieldIndexable,
 	};
 
+	fn check_bools_round_trip<P: PackedBits>(bits: &[bool]) {
+		let packed = P::from_bools(bits);
+		let unpacked = packed.to_bools();
+		assert_eq!(unpacked.len(), P::WIDTH);
+		assert_eq!(&unpacked[..bits.len()], bits);
+		assert!(unpacked[bits.len()..].iter().all(|&bit| !bit));
+	}
+
+	#[test]
+	fn test_bools_round_trip() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let bits = repeat_with(|| rng.gen::<bool>())
+			.take(512)
+			.collect::<Vec<_>>();
+
+		check_bools_round_trip::<PackedBinaryField8x1b>(&bits[..8]);
+		check_bools_round_trip::<PackedBinaryField8x1b>(&bits[..5]);
+		check_bools_round_trip::<PackedBinaryField128x1b>(&bits[..128]);
+		check_bools_round_trip::<PackedBinaryField128x1b>(&bits[..100]);
+		check_bools_round_trip::<PackedBinaryField256x1b>(&bits[..200]);
+		check_bools_round_trip::<PackedBinaryField512x1b>(&bits);
+		check_bools_round_trip::<PackedBinaryField512x1b>(&[]);
+	}
+
+	#[test]
+	fn test_from_bools_packs_underlier_bits() {
+		let packed = PackedBinaryField8x1b::from_bools(&[true, false, true, true]);
+		assert_eq!(packed.to_underlier(), 0b1101);
+	}
+
+	#[test]
+	#[should_panic]
+	fn test_from_bools_too_long() {
+		PackedBinaryField8x1b::from_bools(&[false; 9]);
+	}
+
 	fn test_add_packed<P: PackedField + From<u128>>(a_val: u128, b_val: u128) {
 		let a = P::from(a_val);
 		let b = P::from(b_val);
@@ -8,16 +8,17 @@ use crate::{
 	underlier::{SmallU, WithUnderlier},
 	AESTowerField8b, BinaryField128b, BinaryField128bPolyval, BinaryField16b, BinaryField1b,
 	BinaryField2b, BinaryField32b, BinaryField4b, BinaryField64b, BinaryField8b, Field,
-	PackedBinaryField128x1b, PackedBinaryField128x2b, PackedBinaryField128x4b,
-	PackedBinaryField16x16b, PackedBinaryField16x32b, PackedBinaryField16x4b,
-	PackedBinaryField16x8b, PackedBinaryField1x128b, PackedBinaryField1x64b,
-	PackedBinaryField256x1b, PackedBinaryField256x2b, PackedBinaryField2x128b,
-	PackedBinaryField2x32b, PackedBinaryField2x64b, PackedBinaryField32x16b,
-	PackedBinaryField32x2b, PackedBinaryField32x4b, PackedBinaryField32x8b,
-	PackedBinaryField4x128b, PackedBinaryField4x16b, PackedBinaryField4x32b,
-	PackedBinaryField4x64b, PackedBinaryField512x1b, PackedBinaryField64x1b,
-	PackedBinaryField64x2b, PackedBinaryField64x4b, PackedBinaryField64x8b, PackedBinaryField8x16b,
-	PackedBinaryField8x32b, PackedBinaryField8x64b, PackedBinaryField8x8b, PackedField,
+	PackedAESBinaryField4x8b, PackedBinaryField128x1b, PackedBinaryField128x2b,
+	PackedBinaryField128x4b, PackedBinaryField16x16b, PackedBinaryField16x32b,
+	PackedBinaryField16x4b, PackedBinaryField16x8b, PackedBinaryField1x128b,
+	PackedBinaryField1x64b, PackedBinaryField256x1b, PackedBinaryField256x2b,
+	PackedBinaryField2x128b, PackedBinaryField2x32b, PackedBinaryField2x64b,
+	PackedBinaryField32x16b, PackedBinaryField32x2b, PackedBinaryField32x4b,
+	PackedBinaryField32x8b, PackedBinaryField4x128b, PackedBinaryField4x16b,
+	PackedBinaryField4x32b, PackedBinaryField4x64b, PackedBinaryField512x1b,
+	PackedBinaryField64x1b, PackedBinaryField64x2b, PackedBinaryField64x4b, PackedBinaryField64x8b,
+	PackedBinaryField8x16b, PackedBinaryField8x32b, PackedBinaryField8x64b, PackedBinaryField8x8b,
+	PackedField,
 };
 
 #[test]
@@ -35,12 +36,25 @@ fn test_field_text_debug() {
 				162259276829213363391578010288127
 			))
 		),
-		"Packed1x128([0x000007ffffffffffffffffffffffffff])"
+		"PackedBinaryField1x128b[0x000007ffffffffffffffffffffffffff]"
 	);
 	assert_eq!(
 		format!("{:?}", PackedBinaryField4x32b::broadcast(BinaryField32b::from_underlier(123))),
-		"Packed4x32([0x0000007b,0x0000007b,0x0000007b,0x0000007b])"
-	)
+		"PackedBinaryField4x32b[0x0000007b, 0x0000007b, 0x0000007b, 0x0000007b]"
+	);
+	assert_eq!(
+		format!(
+			"{:?}",
+			PackedBinaryField8x16b::from_scalars(
+				[0x0, 0x1, 0xa, 0xff, 0x100, 0x1234, 0xbeef, 0xffff].map(BinaryField16b::new)
+			)
+		),
+		"PackedBinaryField8x16b[0x0000, 0x0001, 0x000a, 0x00ff, 0x0100, 0x1234, 0xbeef, 0xffff]"
+	);
+	assert_eq!(
+		format!("{:?}", PackedAESBinaryField4x8b::broadcast(AESTowerField8b::from_underlier(127))),
+		"PackedAESBinaryField4x8b[0x7f, 0x7f, 0x7f, 0x7f]"
+	);
 }
 
 fn basic_spread<P>(packed: P, log_block_len: usize, block_idx: usize) -> P
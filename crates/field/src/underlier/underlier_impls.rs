@@ -23,6 +23,11 @@ macro_rules! impl_underlier_type {
 				debug_assert!(val == 0 || val == 1);
 				(val as Self).wrapping_neg()
 			}
+
+			#[inline(always)]
+			fn leading_zeros(&self) -> usize {
+				<$name>::leading_zeros(*self) as usize
+			}
 		}
 	};
 	() => {};
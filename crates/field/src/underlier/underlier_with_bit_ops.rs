@@ -66,6 +66,29 @@ pub trait UnderlierWithBitOps:
 		result
 	}
 
+	/// Returns the number of leading zero bits, counting from the most significant bit.
+	///
+	/// The default implementation is a binary search over shifts of the whole value; primitive
+	/// integers use the native instruction.
+	#[inline]
+	fn leading_zeros(&self) -> usize {
+		if *self == Self::ZERO {
+			return Self::BITS;
+		}
+
+		let mut value = *self;
+		let mut count = 0;
+		let mut shift = Self::BITS / 2;
+		while shift > 0 {
+			if value >> (Self::BITS - shift) == Self::ZERO {
+				count += shift;
+				value = value << shift;
+			}
+			shift /= 2;
+		}
+		count
+	}
+
 	/// Gets the subvalue from the given position.
 	/// Function panics in case when index is out of range.
 	///
@@ -304,6 +327,20 @@ mod tests {
 		assert_eq!(u32::broadcast_subvalue(255u8), 0xffffffff);
 	}
 
+	#[test]
+	fn test_leading_zeros() {
+		// Small integers use the default implementation
+		assert_eq!(U1::new(0).leading_zeros(), 1);
+		assert_eq!(U1::new(1).leading_zeros(), 0);
+		assert_eq!(U2::new(1).leading_zeros(), 1);
+		assert_eq!(U4::new(0).leading_zeros(), 4);
+		assert_eq!(U4::new(1).leading_zeros(), 3);
+		assert_eq!(U4::new(5).leading_zeros(), 1);
+
+		assert_eq!(UnderlierWithBitOps::leading_zeros(&0u32), 32);
+		assert_eq!(UnderlierWithBitOps::leading_zeros(&0x00ab0000u32), 8);
+	}
+
 	#[test]
 	fn test_get_subvalue() {
 		let value = 0xab12cd34u32;
@@ -0,0 +1,115 @@
+// Copyright 2025 Irreducible Inc.
+
+use binius_field::{serialize_canonical, PackedField, TowerField};
+use digest::{core_api::BlockSizeUser, Digest, Output};
+
+use crate::HashBuffer;
+
+/// A hash function over a stream of field elements.
+///
+/// Elements are absorbed in their canonical encoding, so the resulting digest does not depend on
+/// the tower basis the elements are represented in.
+pub trait FieldHasher {
+	type Digest;
+
+	/// Absorbs a single field element.
+	fn update_scalar<F: TowerField>(&mut self, scalar: F);
+
+	/// Absorbs all scalars of a packed field element, in order.
+	fn update_packed<P: PackedField<Scalar: TowerField>>(&mut self, packed: P);
+
+	/// Consumes the hasher and returns the digest of all absorbed elements.
+	fn finalize(self) -> Self::Digest;
+}
+
+/// Adapter that implements [`FieldHasher`] for any [`Digest`].
+#[derive(Debug, Clone, Default)]
+pub struct DigestFieldHasher<D> {
+	digest: D,
+}
+
+impl<D: Digest + BlockSizeUser> DigestFieldHasher<D> {
+	pub fn new() -> Self {
+		Self { digest: D::new() }
+	}
+}
+
+impl<D: Digest + BlockSizeUser> FieldHasher for DigestFieldHasher<D> {
+	type Digest = Output<D>;
+
+	fn update_scalar<F: TowerField>(&mut self, scalar: F) {
+		serialize_canonical(scalar, HashBuffer::new(&mut self.digest))
+			.expect("HashBuffer has infinite capacity");
+	}
+
+	fn update_packed<P: PackedField<Scalar: TowerField>>(&mut self, packed: P) {
+		let mut buffer = HashBuffer::new(&mut self.digest);
+		for scalar in packed.iter() {
+			serialize_canonical(scalar, &mut buffer).expect("HashBuffer has infinite capacity");
+		}
+	}
+
+	fn finalize(self) -> Self::Digest {
+		self.digest.finalize()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use binius_field::{
+		AESTowerField8b, BinaryField32b, BinaryField8b, PackedBinaryField4x32b, PackedField,
+	};
+	use groestl_crypto::Groestl256;
+	use rand::{rngs::StdRng, SeedableRng};
+	use sha2::Sha256;
+
+	use super::*;
+
+	fn hash_elements<H: FieldHasher>(
+		mut hasher: H,
+		packed: &[PackedBinaryField4x32b],
+	) -> H::Digest {
+		for &elem in packed {
+			hasher.update_packed(elem);
+		}
+		hasher.update_scalar(AESTowerField8b::new(0x53));
+		hasher.finalize()
+	}
+
+	#[test]
+	fn test_digest_field_hasher_is_deterministic() {
+		let mut rng = StdRng::seed_from_u64(0);
+		let packed = [
+			PackedBinaryField4x32b::random(&mut rng),
+			PackedBinaryField4x32b::random(&mut rng),
+		];
+
+		let groestl = hash_elements(DigestFieldHasher::<Groestl256>::new(), &packed);
+		assert_eq!(groestl, hash_elements(DigestFieldHasher::<Groestl256>::new(), &packed));
+
+		let sha256 = hash_elements(DigestFieldHasher::<Sha256>::new(), &packed);
+		assert_eq!(sha256, hash_elements(DigestFieldHasher::<Sha256>::new(), &packed));
+
+		// The digest is over the canonical encoding of the scalars.
+		let scalars = packed
+			.iter()
+			.flat_map(|elem| elem.iter())
+			.collect::<Vec<BinaryField32b>>();
+		let mut hasher = DigestFieldHasher::<Sha256>::new();
+		for &scalar in &scalars {
+			hasher.update_scalar(scalar);
+		}
+		hasher.update_scalar(BinaryField8b::from(AESTowerField8b::new(0x53)));
+		assert_eq!(hasher.finalize(), sha256);
+
+		let mut expected = Sha256::new();
+		{
+			let mut buffer = HashBuffer::new(&mut expected);
+			for &scalar in &scalars {
+				serialize_canonical(scalar, &mut buffer).unwrap();
+			}
+			serialize_canonical(AESTowerField8b::new(0x53), &mut buffer).unwrap();
+		}
+		assert_eq!(expected.finalize(), sha256);
+	}
+}
@@ -19,6 +19,7 @@
 )]
 
 pub mod compression;
+mod field_hasher;
 mod groestl;
 pub mod hasher;
 pub mod permutation;
@@ -28,6 +29,7 @@ mod vision;
 mod vision_constants;
 
 pub use compression::*;
+pub use field_hasher::*;
 pub use groestl::*;
 pub use hasher::*;
 pub use serialization::*;
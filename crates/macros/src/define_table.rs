@@ -0,0 +1,190 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use std::collections::HashSet;
+
+use quote::{format_ident, quote, ToTokens};
+use syn::{braced, bracketed, parse::Parse, spanned::Spanned, Token};
+
+#[derive(Debug)]
+pub(crate) struct TableItem {
+	krate: syn::Path,
+	vis: syn::Visibility,
+	name: syn::Ident,
+	columns: Vec<TableColumn>,
+}
+
+#[derive(Debug)]
+struct TableColumn {
+	name: syn::Ident,
+	field: syn::Type,
+	n_vars: TableShape,
+}
+
+/// Number of variables of a column, either a parameter of the constructor or a literal.
+#[derive(Debug)]
+enum TableShape {
+	Param(syn::Ident),
+	Literal(syn::LitInt),
+}
+
+impl ToTokens for TableShape {
+	fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+		match self {
+			Self::Param(ident) => ident.to_tokens(tokens),
+			Self::Literal(lit) => lit.to_tokens(tokens),
+		}
+	}
+}
+
+impl ToTokens for TableItem {
+	fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+		let Self {
+			krate,
+			vis,
+			name,
+			columns,
+		} = self;
+		let columns_name = format_ident!("{name}Columns");
+
+		let names = columns
+			.iter()
+			.map(|column| &column.name)
+			.collect::<Vec<_>>();
+		let fields = columns
+			.iter()
+			.map(|column| &column.field)
+			.collect::<Vec<_>>();
+		let n_vars = columns.iter().map(|column| &column.n_vars);
+
+		let mut params = Vec::new();
+		for column in columns {
+			if let TableShape::Param(param) = &column.n_vars {
+				if !params.contains(&param) {
+					params.push(param);
+				}
+			}
+		}
+
+		let doc_new =
+			format!("Adds the committed columns of the `{name}` table to the constraint system.");
+		let doc_columns = format!("Creates witness columns for all the oracles of `{name}`.");
+		let doc_columns_struct = format!(
+			"Witness columns of the `{name}` table, which are stored in the witness when dropped."
+		);
+
+		tokens.extend(quote! {
+			#vis struct #name {
+				#(#vis #names: binius_core::oracle::OracleId,)*
+			}
+
+			impl #name {
+				#[doc = #doc_new]
+				#vis fn new<U, F>(
+					builder: &mut #krate::builder::ConstraintSystemBuilder<U, F>,
+					#(#params: usize,)*
+				) -> Self
+				where
+					U: binius_field::underlier::UnderlierType + binius_field::as_packed_field::PackScalar<F>,
+					F: binius_field::TowerField,
+				{
+					Self {
+						#(#names: builder.add_committed(
+							stringify!(#names),
+							#n_vars,
+							<#fields as binius_field::TowerField>::TOWER_LEVEL,
+						),)*
+					}
+				}
+
+				#[doc = #doc_columns]
+				#vis fn columns<'arena, U, F>(
+					&self,
+					witness: &#krate::builder::witness::Builder<'arena, U, F>,
+				) -> #columns_name<'arena, U, F>
+				where
+					U: binius_field::as_packed_field::PackScalar<F>
+						#(+ binius_field::as_packed_field::PackScalar<#fields>)*,
+					F: binius_field::TowerField #(+ binius_field::ExtensionField<#fields>)*,
+				{
+					#columns_name {
+						#(#names: witness.new_column::<#fields>(self.#names),)*
+					}
+				}
+			}
+
+			#[doc = #doc_columns_struct]
+			#vis struct #columns_name<'arena, U, F>
+			where
+				U: binius_field::as_packed_field::PackScalar<F>
+					#(+ binius_field::as_packed_field::PackScalar<#fields>)*,
+				F: binius_field::TowerField #(+ binius_field::ExtensionField<#fields>)*,
+			{
+				#(#vis #names: #krate::builder::witness::EntryBuilder<'arena, U, F, #fields>,)*
+			}
+		});
+	}
+}
+
+impl Parse for TableItem {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		input.parse::<Token![crate]>()?;
+		input.parse::<Token![=]>()?;
+		let krate = input.parse::<syn::Path>()?;
+		input.parse::<Token![;]>()?;
+
+		let vis = input.parse::<syn::Visibility>()?;
+		input.parse::<Token![struct]>()?;
+		let name = input.parse::<syn::Ident>()?;
+
+		let content;
+		braced!(content in input);
+		let columns = content.parse_terminated(TableColumn::parse, Token![,])?;
+
+		let mut seen = HashSet::new();
+		for column in &columns {
+			if !seen.insert(column.name.to_string()) {
+				return Err(syn::Error::new(column.name.span(), "Duplicate column name"));
+			}
+		}
+
+		Ok(Self {
+			krate,
+			vis,
+			name,
+			columns: columns.into_iter().collect(),
+		})
+	}
+}
+
+impl Parse for TableColumn {
+	fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+		let name = input.parse::<syn::Ident>()?;
+		input.parse::<Token![:]>()?;
+		let field = input.parse::<syn::TypePath>()?.into();
+
+		let content;
+		bracketed!(content in input);
+		let shape = content.parse::<syn::Expr>()?;
+		let n_vars = match shape {
+			syn::Expr::Path(path) if path.path.get_ident().is_some() => {
+				TableShape::Param(path.path.get_ident().cloned().expect("checked above"))
+			}
+			syn::Expr::Lit(syn::ExprLit {
+				lit: syn::Lit::Int(lit),
+				..
+			}) => TableShape::Literal(lit),
+			shape => {
+				return Err(syn::Error::new(
+					shape.span(),
+					"The number of variables must be an identifier or an integer literal",
+				))
+			}
+		};
+
+		Ok(Self {
+			name,
+			field,
+			n_vars,
+		})
+	}
+}
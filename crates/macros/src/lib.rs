@@ -4,6 +4,7 @@ extern crate proc_macro;
 mod arith_circuit_poly;
 mod arith_expr;
 mod composition_poly;
+mod define_table;
 
 use std::collections::BTreeSet;
 
@@ -13,7 +14,7 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields};
 
 use crate::{
 	arith_circuit_poly::ArithCircuitPolyItem, arith_expr::ArithExprItem,
-	composition_poly::CompositionPolyItem,
+	composition_poly::CompositionPolyItem, define_table::TableItem,
 };
 
 /// Useful for concisely creating structs that implement CompositionPolyOS.
@@ -76,6 +77,50 @@ pub fn arith_circuit_poly(input: TokenStream) -> TokenStream {
 		.into()
 }
 
+/// Defines a table of committed oracles together with a typed API to fill their witness.
+///
+/// The declaration starts with `crate = path;`, the path of the crate providing the
+/// `builder` module with `ConstraintSystemBuilder` and the witness builder, usually
+/// `binius_circuits`. Each column is then declared as `name: Field[n_vars]`, where `n_vars` is
+/// either an integer literal or an identifier. Identifiers become `usize` parameters of the
+/// generated `new` constructor, which adds every column to a `ConstraintSystemBuilder`. The
+/// generated `columns` method creates all witness columns at once, returning a `{Name}Columns`
+/// struct with one `EntryBuilder` per column.
+///
+/// ```
+/// use binius_circuits::builder::ConstraintSystemBuilder;
+/// use binius_field::{arch::OptimalUnderlier, BinaryField128b, BinaryField1b, BinaryField32b};
+/// use binius_macros::define_table;
+///
+/// define_table! {
+///     crate = binius_circuits;
+///     struct Mul {
+///         a: BinaryField32b[log_size],
+///         b: BinaryField32b[log_size],
+///         flag: BinaryField1b[10],
+///     }
+/// }
+///
+/// let allocator = bumpalo::Bump::new();
+/// let mut builder =
+///     ConstraintSystemBuilder::<OptimalUnderlier, BinaryField128b>::new_with_witness(&allocator);
+/// let table = Mul::new(&mut builder, 8);
+/// if let Some(witness) = builder.witness() {
+///     let mut columns = table.columns(witness);
+///     columns.a.as_mut_slice::<u32>().fill(3);
+///     columns.b.as_mut_slice::<u32>().fill(5);
+/// }
+/// ```
+///
+/// Malformed declarations, such as duplicate columns or computed shapes, are rejected at compile
+/// time.
+#[proc_macro]
+pub fn define_table(input: TokenStream) -> TokenStream {
+	parse_macro_input!(input as TableItem)
+		.into_token_stream()
+		.into()
+}
+
 /// Implements `pub fn iter_oracles(&self) -> impl Iterator<Item = OracleId>`.
 ///
 /// Detects and includes fields with type `OracleId`, `[OracleId; N]`
@@ -0,0 +1,7 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+#[test]
+fn test_define_table_rejects_malformed_declarations() {
+	let cases = trybuild::TestCases::new();
+	cases.compile_fail("tests/ui/define_table_*.rs");
+}
@@ -0,0 +1,10 @@
+use binius_macros::define_table;
+
+define_table! {
+	crate = binius_circuits;
+	struct Bad {
+		a: BinaryField32b[log_size + 1],
+	}
+}
+
+fn main() {}
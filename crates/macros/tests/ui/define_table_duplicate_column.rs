@@ -0,0 +1,11 @@
+use binius_macros::define_table;
+
+define_table! {
+	crate = binius_circuits;
+	struct Bad {
+		a: BinaryField32b[log_size],
+		a: BinaryField32b[4],
+	}
+}
+
+fn main() {}
@@ -15,14 +15,21 @@ use super::error::Error;
 ///
 /// Arithmetic expressions are trees, where the leaves are either constants or variables, and the
 /// non-leaf nodes are arithmetic operations, such as addition, multiplication, etc. They are
-/// specific representations of multivariate polynomials.
+/// specific representations of multivariate polynomials, unless they contain an [`Inv`] node.
+///
+/// [`Inv`]: ArithExpr::Inv
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ArithExpr<F: Field> {
 	Const(F),
 	Var(usize),
 	Add(Box<ArithExpr<F>>, Box<ArithExpr<F>>),
+	Sub(Box<ArithExpr<F>>, Box<ArithExpr<F>>),
 	Mul(Box<ArithExpr<F>>, Box<ArithExpr<F>>),
 	Pow(Box<ArithExpr<F>>, u64),
+	/// The multiplicative inverse, or zero where the operand is zero.
+	///
+	/// This is not a polynomial operation, see [`ArithExpr::degree`].
+	Inv(Box<ArithExpr<F>>),
 }
 
 impl<F: Field + Display> Display for ArithExpr<F> {
@@ -31,33 +38,110 @@ impl<F: Field + Display> Display for ArithExpr<F> {
 			Self::Const(v) => write!(f, "{v}"),
 			Self::Var(i) => write!(f, "x{i}"),
 			Self::Add(x, y) => write!(f, "({} + {})", &**x, &**y),
+			Self::Sub(x, y) => write!(f, "({} - {})", &**x, &**y),
 			Self::Mul(x, y) => write!(f, "({} * {})", &**x, &**y),
 			Self::Pow(x, p) => write!(f, "({})^{p}", &**x),
+			Self::Inv(x) => write!(f, "inv({})", &**x),
 		}
 	}
 }
 
 impl<F: Field> ArithExpr<F> {
 	/// The number of variables the expression contains.
+	///
+	/// This saturates at `usize::MAX` for the variable with index `usize::MAX`.
 	pub fn n_vars(&self) -> usize {
 		match self {
 			ArithExpr::Const(_) => 0,
-			ArithExpr::Var(index) => *index + 1,
-			ArithExpr::Add(left, right) | ArithExpr::Mul(left, right) => {
-				max(left.n_vars(), right.n_vars())
-			}
-			ArithExpr::Pow(id, _) => id.n_vars(),
+			ArithExpr::Var(index) => index.saturating_add(1),
+			ArithExpr::Add(left, right)
+			| ArithExpr::Sub(left, right)
+			| ArithExpr::Mul(left, right) => max(left.n_vars(), right.n_vars()),
+			ArithExpr::Pow(id, _) | ArithExpr::Inv(id) => id.n_vars(),
 		}
 	}
 
 	/// The total degree of the polynomial the expression represents.
+	///
+	/// The inverse of a non-constant expression is not a polynomial and has no degree. It is
+	/// reported as `usize::MAX`, which propagates through the enclosing operations, so the degree
+	/// of any expression that depends on such an inverse is `usize::MAX` too.
 	pub fn degree(&self) -> usize {
+		self.degree_by(&|_| 1)
+	}
+
+	/// The degree of the expression when the variable with index `i` has degree `var_degree(i)`.
+	///
+	/// [`Self::degree`] gives every variable degree one. Giving one variable degree one and the
+	/// others degree zero bounds the degree of the expression in that variable.
+	pub fn degree_by(&self, var_degree: &impl Fn(usize) -> usize) -> usize {
 		match self {
 			ArithExpr::Const(_) => 0,
-			ArithExpr::Var(_) => 1,
-			ArithExpr::Add(left, right) => max(left.degree(), right.degree()),
-			ArithExpr::Mul(left, right) => left.degree() + right.degree(),
-			ArithExpr::Pow(base, exp) => base.degree() * *exp as usize,
+			ArithExpr::Var(index) => var_degree(*index),
+			ArithExpr::Add(left, right) | ArithExpr::Sub(left, right) => {
+				Self::add_degree(left.degree_by(var_degree), right.degree_by(var_degree))
+			}
+			ArithExpr::Mul(left, right) => {
+				Self::mul_degree(left.degree_by(var_degree), right.degree_by(var_degree))
+			}
+			ArithExpr::Pow(base, exp) => Self::pow_degree(base.degree_by(var_degree), *exp),
+			ArithExpr::Inv(inner) => Self::inv_degree(inner.degree_by(var_degree)),
+		}
+	}
+
+	/// The degree of a sum or a difference of operands of the given degrees.
+	///
+	/// This and the other `*_degree` functions are the rules [`Self::degree_by`] propagates
+	/// degrees with, for evaluators that lower the expression into a sequence of operations.
+	pub fn add_degree(left: usize, right: usize) -> usize {
+		max(left, right)
+	}
+
+	/// The degree of a product of operands of the given degrees.
+	pub fn mul_degree(left: usize, right: usize) -> usize {
+		left.saturating_add(right)
+	}
+
+	/// The degree of the `exp`-th power of an operand of the given degree.
+	pub fn pow_degree(base: usize, exp: u64) -> usize {
+		base.saturating_mul(exp as usize)
+	}
+
+	/// The degree of the inverse of an operand of the given degree: the inverse of a constant is
+	/// a constant, and anything else has no degree.
+	pub fn inv_degree(inner: usize) -> usize {
+		match inner {
+			0 => 0,
+			_ => usize::MAX,
+		}
+	}
+
+	/// Returns whether the expression is a polynomial, i.e. whether it has a finite degree.
+	///
+	/// See [`Self::degree`] for the expressions that are not polynomials.
+	pub fn is_polynomial(&self) -> bool {
+		self.degree() != usize::MAX
+	}
+
+	/// Returns, for each variable index below [`Self::n_vars`], whether the variable occurs in the
+	/// expression.
+	pub fn vars_usage(&self) -> Vec<bool> {
+		let mut usage = vec![false; self.n_vars()];
+		self.mark_vars_usage(&mut usage);
+		usage
+	}
+
+	fn mark_vars_usage(&self, usage: &mut [bool]) {
+		match self {
+			ArithExpr::Const(_) => {}
+			ArithExpr::Var(index) => usage[*index] = true,
+			ArithExpr::Add(left, right)
+			| ArithExpr::Sub(left, right)
+			| ArithExpr::Mul(left, right) => {
+				left.mark_vars_usage(usage);
+				right.mark_vars_usage(usage);
+			}
+			ArithExpr::Pow(base, _) | ArithExpr::Inv(base) => base.mark_vars_usage(usage),
 		}
 	}
 
@@ -65,6 +149,10 @@ impl<F: Field> ArithExpr<F> {
 		ArithExpr::Pow(Box::new(self), exp)
 	}
 
+	pub fn inv(self) -> Self {
+		ArithExpr::Inv(Box::new(self))
+	}
+
 	pub const fn zero() -> Self {
 		ArithExpr::Const(F::ZERO)
 	}
@@ -100,6 +188,11 @@ impl<F: Field> ArithExpr<F> {
 				let new_right = right.remap_vars(indices)?;
 				ArithExpr::Add(Box::new(new_left), Box::new(new_right))
 			}
+			ArithExpr::Sub(left, right) => {
+				let new_left = left.remap_vars(indices)?;
+				let new_right = right.remap_vars(indices)?;
+				ArithExpr::Sub(Box::new(new_left), Box::new(new_right))
+			}
 			ArithExpr::Mul(left, right) => {
 				let new_left = left.remap_vars(indices)?;
 				let new_right = right.remap_vars(indices)?;
@@ -109,6 +202,7 @@ impl<F: Field> ArithExpr<F> {
 				let new_base = base.remap_vars(indices)?;
 				ArithExpr::Pow(Box::new(new_base), exp)
 			}
+			ArithExpr::Inv(inner) => ArithExpr::Inv(Box::new(inner.remap_vars(indices)?)),
 		};
 		Ok(expr)
 	}
@@ -122,6 +216,11 @@ impl<F: Field> ArithExpr<F> {
 				let new_right = right.convert_field();
 				ArithExpr::Add(Box::new(new_left), Box::new(new_right))
 			}
+			ArithExpr::Sub(left, right) => {
+				let new_left = left.convert_field();
+				let new_right = right.convert_field();
+				ArithExpr::Sub(Box::new(new_left), Box::new(new_right))
+			}
 			ArithExpr::Mul(left, right) => {
 				let new_left = left.convert_field();
 				let new_right = right.convert_field();
@@ -131,6 +230,7 @@ impl<F: Field> ArithExpr<F> {
 				let new_base = base.convert_field();
 				ArithExpr::Pow(Box::new(new_base), *exp)
 			}
+			ArithExpr::Inv(inner) => ArithExpr::Inv(Box::new(inner.convert_field())),
 		}
 	}
 
@@ -145,6 +245,11 @@ impl<F: Field> ArithExpr<F> {
 				let new_right = right.try_convert_field()?;
 				ArithExpr::Add(Box::new(new_left), Box::new(new_right))
 			}
+			ArithExpr::Sub(left, right) => {
+				let new_left = left.try_convert_field()?;
+				let new_right = right.try_convert_field()?;
+				ArithExpr::Sub(Box::new(new_left), Box::new(new_right))
+			}
 			ArithExpr::Mul(left, right) => {
 				let new_left = left.try_convert_field()?;
 				let new_right = right.try_convert_field()?;
@@ -154,6 +259,7 @@ impl<F: Field> ArithExpr<F> {
 				let new_base = base.try_convert_field()?;
 				ArithExpr::Pow(Box::new(new_base), *exp)
 			}
+			ArithExpr::Inv(inner) => ArithExpr::Inv(Box::new(inner.try_convert_field()?)),
 		})
 	}
 }
@@ -194,7 +300,7 @@ where
 	type Output = Self;
 
 	fn sub(self, rhs: Self) -> Self {
-		ArithExpr::Add(Box::new(self), Box::new(rhs))
+		ArithExpr::Sub(Box::new(self), Box::new(rhs))
 	}
 }
 
@@ -258,6 +364,47 @@ mod tests {
 		assert_eq!(expr.degree(), 14);
 	}
 
+	#[test]
+	fn test_degree_with_inv() {
+		type F = BinaryField8b;
+
+		assert_eq!(ArithExpr::Const(F::new(3)).inv().degree(), 0);
+		assert_eq!(ArithExpr::<F>::Var(0).inv().degree(), usize::MAX);
+		assert_eq!(
+			(ArithExpr::<F>::Var(0).inv() * ArithExpr::Var(1))
+				.pow(2)
+				.degree(),
+			usize::MAX
+		);
+		assert_eq!(ArithExpr::<F>::Var(0).inv().pow(0).degree(), 0);
+	}
+
+	#[test]
+	fn test_degree_by() {
+		type F = BinaryField8b;
+
+		// x0^2 * x1 + x1^3
+		let expr = ArithExpr::<F>::Var(0).pow(2) * ArithExpr::Var(1) + ArithExpr::Var(1).pow(3);
+		assert_eq!(expr.degree_by(&|_| 1), expr.degree());
+		assert_eq!(expr.degree_by(&|var| (var == 0) as usize), 2);
+		assert_eq!(expr.degree_by(&|var| (var == 1) as usize), 3);
+		assert_eq!(expr.degree_by(&|var| var + 1), 6);
+
+		// An inverse only has a degree in the variables it does not depend on
+		let expr = ArithExpr::<F>::Var(0).inv() * ArithExpr::Var(1);
+		assert_eq!(expr.degree_by(&|var| (var == 0) as usize), usize::MAX);
+		assert_eq!(expr.degree_by(&|var| (var == 1) as usize), 1);
+	}
+
+	#[test]
+	fn test_is_polynomial() {
+		type F = BinaryField8b;
+
+		assert!(ArithExpr::Const(F::new(3)).inv().is_polynomial());
+		assert!((ArithExpr::<F>::Var(0) * ArithExpr::Var(1)).is_polynomial());
+		assert!(!(ArithExpr::<F>::Var(0).inv() + ArithExpr::Var(1)).is_polynomial());
+	}
+
 	#[test]
 	fn test_remap_vars_with_too_few_vars() {
 		type F = BinaryField8b;
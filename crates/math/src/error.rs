@@ -51,4 +51,22 @@ pub enum Error {
 	PiecewiseMultilinearIncompatibleEvals { actual: usize, expected: usize },
 	#[error("cannot fold a constant multilinear")]
 	ConstantFold,
+	#[error("malformed s-expression at byte {position}: {message}")]
+	MalformedSExpr { position: usize, message: String },
+	#[error("the expression contains an inverse and is not a polynomial")]
+	NotPolynomial,
+	#[error("degree {degree} in variable {var} exceeds the bound {max_degree}")]
+	DegreeBoundExceeded {
+		var: usize,
+		degree: usize,
+		max_degree: usize,
+	},
+	#[error("the number of variables {n_vars} must be even")]
+	OddNumberOfVariables { n_vars: usize },
+	#[error("query variable {index} has tower level {tower_level}, above the maximum of {max_tower_level}")]
+	QueryTowerLevelTooHigh {
+		index: usize,
+		tower_level: usize,
+		max_tower_level: usize,
+	},
 }
@@ -49,6 +49,36 @@ impl<P: PackedField> MultilinearExtension<P> {
 	}
 }
 
+impl<F: Field> MultilinearExtension<F> {
+	/// Creates a multilinear extension of arbitrary length scalar values.
+	///
+	/// The values are padded with zeros up to the next power of two.
+	pub fn from_values_padded(mut values: Vec<F>) -> Result<Self, Error> {
+		values.resize(values.len().next_power_of_two(), F::ZERO);
+		Self::from_values(values)
+	}
+
+	/// Evaluates the polynomial at a point given by one scalar per variable.
+	///
+	/// This is a simple, sequential evaluation which does not require expanding a
+	/// [`MultilinearQuery`](crate::MultilinearQuery).
+	pub fn evaluate_at(&self, point: &[F]) -> Result<F, Error> {
+		if point.len() != self.mu {
+			bail!(Error::IncorrectQuerySize { expected: self.mu });
+		}
+
+		let mut values = self.evals.clone();
+		for &coord in point {
+			let half = values.len() / 2;
+			for i in 0..half {
+				values[i] = values[2 * i] + (values[2 * i + 1] - values[2 * i]) * coord;
+			}
+			values.truncate(half);
+		}
+		Ok(values[0])
+	}
+}
+
 impl<P: PackedField, Data: Deref<Target = [P]>> MultilinearExtension<P, Data> {
 	pub fn from_values_generic(v: Data) -> Result<Self, Error> {
 		if !v.len().is_power_of_two() {
@@ -395,6 +425,30 @@ mod tests {
 		assert_eq!(poly1, poly2)
 	}
 
+	#[test]
+	fn test_from_values_padded_evaluate_at() {
+		let [a, b, c] = [F::new(1), F::new(2), F::new(3)];
+		let poly = MultilinearExtension::from_values_padded(vec![a, b, c]).unwrap();
+		assert_eq!(poly.n_vars(), 2);
+		assert_eq!(poly.evals(), &[a, b, c, F::ZERO]);
+
+		assert_eq!(poly.evaluate_at(&[F::ZERO, F::ONE]).unwrap(), c);
+		assert_eq!(poly.evaluate_at(&[F::ONE, F::ONE]).unwrap(), F::ZERO);
+
+		// p(x0, x1) = a (1 - x0)(1 - x1) + b x0 (1 - x1) + c (1 - x0) x1
+		let (x0, x1) = (F::new(0x1234), F::new(0xbeef));
+		let expected =
+			a * (F::ONE - x0) * (F::ONE - x1) + b * x0 * (F::ONE - x1) + c * (F::ONE - x0) * x1;
+		assert_eq!(poly.evaluate_at(&[x0, x1]).unwrap(), expected);
+		assert_eq!(
+			poly.evaluate_at(&[x0, x1]).unwrap(),
+			poly.evaluate::<F, F>(&MultilinearQuery::<F>::expand(&[x0, x1]))
+				.unwrap()
+		);
+
+		assert!(poly.evaluate_at(&[x0]).is_err());
+	}
+
 	#[test]
 	fn test_evaluate_on_hypercube() {
 		let mut values = vec![F::ZERO; 64];
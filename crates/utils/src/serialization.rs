@@ -9,6 +9,98 @@ pub enum Error {
 	WriteBufferFull,
 	#[error("Not enough data in read buffer to deserialize")]
 	NotEnoughBytes,
+	#[error("Unknown enum variant index {name}::{index}")]
+	UnknownEnumVariant { name: &'static str, index: u8 },
+	#[error("Deserialized data does not form a valid {name}")]
+	InvalidConstruction { name: &'static str },
+	#[error(
+		"{name} at position {index} references position {reference}, which does not precede it"
+	)]
+	NonPrecedingReference {
+		name: &'static str,
+		index: usize,
+		reference: u64,
+	},
+	#[error("Serialized data does not start with the artifact magic bytes")]
+	BadMagic,
+	#[error("Unsupported serialization format version {found}")]
+	UnsupportedVersion { found: u16 },
+	#[error("Expected a serialized {expected:?}, found a {found:?}")]
+	UnexpectedArtifactKind {
+		expected: ArtifactKind,
+		found: ArtifactKind,
+	},
+}
+
+/// The magic bytes starting every artifact header.
+pub const ARTIFACT_MAGIC: [u8; 4] = *b"BNUS";
+
+/// The kind of a serialized artifact, recorded in its header.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum ArtifactKind {
+	ConstraintSystem = 0,
+	ArithCircuitPoly = 1,
+	Witness = 2,
+	Proof = 3,
+}
+
+impl TryFrom<u8> for ArtifactKind {
+	type Error = Error;
+
+	fn try_from(index: u8) -> Result<Self, Error> {
+		match index {
+			0 => Ok(Self::ConstraintSystem),
+			1 => Ok(Self::ArithCircuitPoly),
+			2 => Ok(Self::Witness),
+			3 => Ok(Self::Proof),
+			_ => Err(Error::UnknownEnumVariant {
+				name: "ArtifactKind",
+				index,
+			}),
+		}
+	}
+}
+
+/// Writes an artifact header: the [`ARTIFACT_MAGIC`] bytes, the kind tag and the format version
+/// as a little-endian `u16`.
+pub fn write_header(mut buf: impl BufMut, kind: ArtifactKind, version: u16) -> Result<(), Error> {
+	if buf.remaining_mut() < ARTIFACT_MAGIC.len() + 1 + size_of::<u16>() {
+		return Err(Error::WriteBufferFull);
+	}
+	buf.put_slice(&ARTIFACT_MAGIC);
+	buf.put_u8(kind as u8);
+	buf.put_u16_le(version);
+	Ok(())
+}
+
+/// Reads an artifact header written by [`write_header`], returning the format version.
+///
+/// The header must be for an artifact of the given `kind`, with a version no greater than
+/// `max_version`, so that data from another artifact or from a newer writer fails fast.
+pub fn read_header(mut buf: impl Buf, kind: ArtifactKind, max_version: u16) -> Result<u16, Error> {
+	if buf.remaining() < ARTIFACT_MAGIC.len() + 1 + size_of::<u16>() {
+		return Err(Error::NotEnoughBytes);
+	}
+	let mut magic = [0u8; 4];
+	buf.copy_to_slice(&mut magic);
+	if magic != ARTIFACT_MAGIC {
+		return Err(Error::BadMagic);
+	}
+
+	let found = ArtifactKind::try_from(buf.get_u8())?;
+	if found != kind {
+		return Err(Error::UnexpectedArtifactKind {
+			expected: kind,
+			found,
+		});
+	}
+
+	let version = buf.get_u16_le();
+	if version > max_version {
+		return Err(Error::UnsupportedVersion { found: version });
+	}
+	Ok(version)
 }
 
 /// Represents type that can be serialized to a byte buffer.
@@ -45,6 +137,294 @@ impl<N: ArrayLength<u8>> DeserializeBytes for GenericArray<u8, N> {
 	}
 }
 
+impl SerializeBytes for u8 {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), Error> {
+		if write_buf.remaining_mut() < 1 {
+			return Err(Error::WriteBufferFull);
+		}
+		write_buf.put_u8(*self);
+		Ok(())
+	}
+}
+
+impl DeserializeBytes for u8 {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, Error> {
+		if read_buf.remaining() < 1 {
+			return Err(Error::NotEnoughBytes);
+		}
+		Ok(read_buf.get_u8())
+	}
+}
+
+/// Writes the length as a LEB128 varint, followed by the elements.
+impl<T: SerializeBytes> SerializeBytes for Vec<T> {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), Error> {
+		write_varint(&mut write_buf, self.len() as u64)?;
+		self.as_slice().serialize(write_buf)
+	}
+}
+
+impl<T: DeserializeBytes> DeserializeBytes for Vec<T> {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, Error> {
+		let len = read_varint(&mut read_buf)?;
+		let mut values = Vec::with_capacity(bounded_capacity::<T>(len, read_buf.remaining()));
+		for _ in 0..len {
+			values.push(T::deserialize(&mut read_buf)?);
+		}
+		Ok(values)
+	}
+}
+
+/// The capacity to preallocate for `len` elements read from `remaining` bytes.
+///
+/// A corrupted length must not cause a huge allocation, so the capacity is bounded by the number
+/// of elements whose in-memory size fits in the remaining bytes. Every element is serialized to at
+/// least one byte, so a valid length larger than that only grows the vector as it is read.
+fn bounded_capacity<T>(len: u64, remaining: usize) -> usize {
+	len.min((remaining / size_of::<T>().max(1)) as u64) as usize
+}
+
+/// Writes the elements without a length, which is known from the type.
+impl<T: SerializeBytes, const N: usize> SerializeBytes for [T; N] {
+	fn serialize(&self, write_buf: impl BufMut) -> Result<(), Error> {
+		self.as_slice().serialize(write_buf)
+	}
+}
+
+impl<T: DeserializeBytes, const N: usize> DeserializeBytes for [T; N] {
+	fn deserialize(mut read_buf: impl Buf) -> Result<Self, Error> {
+		let values = (0..N)
+			.map(|_| T::deserialize(&mut read_buf))
+			.collect::<Result<Vec<_>, _>>()?;
+		Ok(values
+			.try_into()
+			.unwrap_or_else(|_| unreachable!("exactly N values were deserialized")))
+	}
+}
+
+impl<T: SerializeBytes> SerializeBytes for [T] {
+	fn serialize(&self, mut write_buf: impl BufMut) -> Result<(), Error> {
+		self.iter()
+			.try_for_each(|value| value.serialize(&mut write_buf))
+	}
+}
+
+/// The maximum number of bytes of a LEB128 encoded `u64`.
+const MAX_VARINT_LEN: usize = 10;
+
+/// Writes `value` as an unsigned LEB128 varint, seven bits per byte starting with the least
+/// significant ones.
+pub fn write_varint(mut buf: impl BufMut, mut value: u64) -> Result<(), Error> {
+	loop {
+		if buf.remaining_mut() < 1 {
+			return Err(Error::WriteBufferFull);
+		}
+		if value < 0x80 {
+			buf.put_u8(value as u8);
+			return Ok(());
+		}
+		buf.put_u8(value as u8 | 0x80);
+		value >>= 7;
+	}
+}
+
+/// Reads an unsigned LEB128 varint written by [`write_varint`].
+///
+/// At most 10 bytes are read, the longest LEB128 encoding of a `u64`. Encodings that are longer or
+/// overflow a `u64` are rejected with [`Error::InvalidConstruction`].
+pub fn read_varint(mut buf: impl Buf) -> Result<u64, Error> {
+	let mut value = 0u64;
+	for i in 0..MAX_VARINT_LEN {
+		if buf.remaining() < 1 {
+			return Err(Error::NotEnoughBytes);
+		}
+		let byte = buf.get_u8();
+		let bits = u64::from(byte & 0x7f);
+		let shift = 7 * i as u32;
+		if bits << shift >> shift != bits {
+			return Err(Error::InvalidConstruction { name: "varint" });
+		}
+		value |= bits << shift;
+		if byte & 0x80 == 0 {
+			return Ok(value);
+		}
+	}
+	Err(Error::InvalidConstruction { name: "varint" })
+}
+
+/// Writes `value` as a ZigZag encoded LEB128 varint, so that small negative values are short.
+pub fn write_zigzag_varint(buf: impl BufMut, value: i64) -> Result<(), Error> {
+	write_varint(buf, ((value << 1) ^ (value >> 63)) as u64)
+}
+
+/// Reads a ZigZag encoded LEB128 varint written by [`write_zigzag_varint`].
+pub fn read_zigzag_varint(buf: impl Buf) -> Result<i64, Error> {
+	let value = read_varint(buf)?;
+	Ok((value >> 1) as i64 ^ -((value & 1) as i64))
+}
+
+/// The buffer size of [`IoWriter`] and [`IoReader`].
+#[cfg(feature = "std")]
+const IO_BUFFER_LEN: usize = 1 << 16;
+
+/// Adapts a [`std::io::Write`] to [`BufMut`], so that artifacts can be serialized as a stream.
+///
+/// The bytes are buffered and written in chunks. Since [`BufMut`] cannot report errors, the first
+/// I/O error is kept and the writer then reports no remaining capacity, which makes serializers
+/// fail with [`Error::WriteBufferFull`]. [`Self::finish`] must be called to flush the writer and
+/// get the I/O error, if any.
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct IoWriter<W: std::io::Write> {
+	inner: W,
+	buffer: Vec<u8>,
+	error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<W: std::io::Write> IoWriter<W> {
+	pub fn new(inner: W) -> Self {
+		Self {
+			inner,
+			buffer: Vec::with_capacity(IO_BUFFER_LEN),
+			error: None,
+		}
+	}
+
+	/// Flushes the buffered bytes and returns the inner writer, or the first I/O error.
+	pub fn finish(mut self) -> std::io::Result<W> {
+		self.flush_buffer();
+		if let Some(error) = self.error {
+			return Err(error);
+		}
+		self.inner.flush()?;
+		Ok(self.inner)
+	}
+
+	fn flush_buffer(&mut self) {
+		if self.error.is_none() {
+			self.error = self.inner.write_all(&self.buffer).err();
+		}
+		self.buffer.clear();
+	}
+}
+
+#[cfg(feature = "std")]
+unsafe impl<W: std::io::Write> BufMut for IoWriter<W> {
+	fn remaining_mut(&self) -> usize {
+		if self.error.is_some() {
+			0
+		} else {
+			isize::MAX as usize
+		}
+	}
+
+	unsafe fn advance_mut(&mut self, cnt: usize) {
+		let len = self.buffer.len() + cnt;
+		assert!(len <= self.buffer.capacity(), "advanced past the chunk");
+		self.buffer.set_len(len);
+		if self.buffer.len() == self.buffer.capacity() {
+			self.flush_buffer();
+		}
+	}
+
+	fn chunk_mut(&mut self) -> &mut bytes::buf::UninitSlice {
+		if self.buffer.len() == self.buffer.capacity() {
+			self.flush_buffer();
+		}
+		self.buffer.spare_capacity_mut().into()
+	}
+}
+
+/// Adapts a [`std::io::Read`] to [`Buf`], so that artifacts can be deserialized from a stream.
+///
+/// The reader is buffered so that, until the end of the stream, at least half of the buffer
+/// size is available. [`Buf::remaining`] only reports the buffered bytes, not the length of the
+/// stream, see [`Self::remaining`]. An I/O error ends the stream and is returned by
+/// [`Self::finish`].
+#[cfg(feature = "std")]
+#[derive(Debug)]
+pub struct IoReader<R: std::io::Read> {
+	inner: R,
+	buffer: Vec<u8>,
+	position: usize,
+	eof: bool,
+	error: Option<std::io::Error>,
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> IoReader<R> {
+	pub fn new(inner: R) -> Self {
+		let mut reader = Self {
+			inner,
+			buffer: Vec::with_capacity(IO_BUFFER_LEN),
+			position: 0,
+			eof: false,
+			error: None,
+		};
+		reader.fill_buffer();
+		reader
+	}
+
+	/// Returns the inner reader, or the I/O error that ended the stream.
+	pub fn finish(self) -> std::io::Result<R> {
+		match self.error {
+			Some(error) => Err(error),
+			None => Ok(self.inner),
+		}
+	}
+
+	fn fill_buffer(&mut self) {
+		self.buffer.drain(..self.position);
+		self.position = 0;
+		while !self.eof && self.buffer.len() < IO_BUFFER_LEN {
+			let len = self.buffer.len();
+			self.buffer.resize(IO_BUFFER_LEN, 0);
+			match self.inner.read(&mut self.buffer[len..]) {
+				Ok(0) => {
+					self.buffer.truncate(len);
+					self.eof = true;
+				}
+				Ok(n) => self.buffer.truncate(len + n),
+				Err(error) if error.kind() == std::io::ErrorKind::Interrupted => {
+					self.buffer.truncate(len);
+				}
+				Err(error) => {
+					self.buffer.truncate(len);
+					self.error = Some(error);
+					self.eof = true;
+				}
+			}
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl<R: std::io::Read> Buf for IoReader<R> {
+	/// Returns the number of buffered bytes.
+	///
+	/// Until the end of the stream, this is at least half of the buffer size, whatever the sizes
+	/// of the reads of the inner reader, and it is exact once the stream has been read to the
+	/// end. A deserializer checking for up to that many bytes before reading them thus fails
+	/// with [`Error::NotEnoughBytes`] exactly when the stream is too short, but the value must not
+	/// be taken as the size of the rest of the input.
+	fn remaining(&self) -> usize {
+		self.buffer.len() - self.position
+	}
+
+	fn chunk(&self) -> &[u8] {
+		&self.buffer[self.position..]
+	}
+
+	fn advance(&mut self, cnt: usize) {
+		assert!(cnt <= self.remaining(), "advanced past the buffered bytes");
+		self.position += cnt;
+		if self.remaining() < IO_BUFFER_LEN / 2 {
+			self.fill_buffer();
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use generic_array::typenum::U32;
@@ -65,4 +445,217 @@ mod tests {
 		let data_deserialized = GenericArray::<u8, U32>::deserialize(&mut buf.as_slice()).unwrap();
 		assert_eq!(data_deserialized, data);
 	}
+
+	#[test]
+	fn test_vec_serialize_deserialize() {
+		let mut buf = Vec::new();
+		Vec::<u8>::new().serialize(&mut buf).unwrap();
+		assert_eq!(buf, [0]);
+		assert_eq!(Vec::<u8>::deserialize(buf.as_slice()).unwrap(), Vec::<u8>::new());
+
+		let nested = vec![vec![], vec![1u8], (0..200).collect::<Vec<u8>>()];
+		let mut buf = Vec::new();
+		nested.serialize(&mut buf).unwrap();
+		// The 200 byte vector has a two byte varint prefix
+		assert_eq!(buf.len(), 1 + 1 + 2 + 2 + 200);
+		assert_eq!(Vec::<Vec<u8>>::deserialize(buf.as_slice()).unwrap(), nested);
+
+		let array = [[1u8, 2], [3, 4], [5, 6]];
+		let mut buf = Vec::new();
+		array.serialize(&mut buf).unwrap();
+		assert_eq!(buf, [1, 2, 3, 4, 5, 6]);
+		assert_eq!(<[[u8; 2]; 3]>::deserialize(buf.as_slice()).unwrap(), array);
+		assert!(matches!(<[u8; 7]>::deserialize(buf.as_slice()), Err(Error::NotEnoughBytes)));
+	}
+
+	#[test]
+	fn test_vec_corrupted_length() {
+		let mut buf = Vec::new();
+		vec![1u8, 2, 3].serialize(&mut buf).unwrap();
+
+		// A length larger than the stream runs out of bytes instead of allocating it
+		let mut corrupted = buf.clone();
+		corrupted[0] = 0x7f;
+		assert!(matches!(Vec::<u8>::deserialize(corrupted.as_slice()), Err(Error::NotEnoughBytes)));
+
+		let mut huge = vec![0xff; 9];
+		huge.push(0x01);
+		huge.extend_from_slice(&buf[1..]);
+		assert!(matches!(Vec::<u8>::deserialize(huge.as_slice()), Err(Error::NotEnoughBytes)));
+
+		// A truncated varint prefix
+		assert!(matches!(Vec::<u8>::deserialize(&[0x80u8][..]), Err(Error::NotEnoughBytes)));
+	}
+
+	#[test]
+	fn test_vec_huge_length_bounded_allocation() {
+		let mut buf = Vec::new();
+		write_varint(&mut buf, u64::MAX).unwrap();
+		buf.extend_from_slice(&[0; 64]);
+
+		// The preallocation holds at most as many elements as fit in the remaining bytes
+		assert_eq!(bounded_capacity::<u8>(u64::MAX, 64), 64);
+		assert_eq!(bounded_capacity::<u64>(u64::MAX, 64), 8);
+		assert_eq!(bounded_capacity::<[u64; 1024]>(u64::MAX, 64), 0);
+		assert_eq!(bounded_capacity::<()>(u64::MAX, 64), 64);
+		assert_eq!(bounded_capacity::<u64>(3, 64), 3);
+
+		assert!(matches!(Vec::<[u8; 8]>::deserialize(buf.as_slice()), Err(Error::NotEnoughBytes)));
+		assert!(matches!(
+			Vec::<[u8; 8192]>::deserialize(buf.as_slice()),
+			Err(Error::NotEnoughBytes)
+		));
+	}
+
+	#[test]
+	fn test_varint() {
+		for value in [0, 1, 0x7f, 0x80, 300, 1 << 35, u64::MAX - 1, u64::MAX] {
+			let mut buf = Vec::new();
+			write_varint(&mut buf, value).unwrap();
+			assert_eq!(buf.len(), (64 - value.leading_zeros() as usize).div_ceil(7).max(1));
+			assert_eq!(read_varint(buf.as_slice()).unwrap(), value);
+			assert!(matches!(read_varint(&buf[..buf.len() - 1]), Err(Error::NotEnoughBytes)));
+		}
+
+		let mut buf = Vec::new();
+		write_varint(&mut buf, 300).unwrap();
+		assert_eq!(buf, [0xac, 0x02]);
+		assert!(matches!(write_varint(&mut [0u8][..], 300), Err(Error::WriteBufferFull)));
+
+		// More than ten bytes, or a tenth byte with bits beyond the 64th
+		assert!(matches!(read_varint(&[0xffu8; 11][..]), Err(Error::InvalidConstruction { .. })));
+		let mut overflow = vec![0xffu8; 9];
+		overflow.push(0x02);
+		assert!(matches!(read_varint(overflow.as_slice()), Err(Error::InvalidConstruction { .. })));
+	}
+
+	#[test]
+	fn test_zigzag_varint() {
+		for (value, encoded) in [(0, 0), (-1, 1), (1, 2), (-2, 3), (i64::MAX, u64::MAX - 1)] {
+			let mut buf = Vec::new();
+			write_zigzag_varint(&mut buf, value).unwrap();
+			assert_eq!(read_varint(buf.as_slice()).unwrap(), encoded);
+			assert_eq!(read_zigzag_varint(buf.as_slice()).unwrap(), value);
+		}
+
+		let mut buf = Vec::new();
+		write_zigzag_varint(&mut buf, i64::MIN).unwrap();
+		assert_eq!(read_varint(buf.as_slice()).unwrap(), u64::MAX);
+		assert_eq!(read_zigzag_varint(buf.as_slice()).unwrap(), i64::MIN);
+	}
+
+	#[test]
+	fn test_artifact_header() {
+		let mut buf = Vec::new();
+		write_header(&mut buf, ArtifactKind::ArithCircuitPoly, 2).unwrap();
+		assert_eq!(buf, [b'B', b'N', b'U', b'S', 1, 2, 0]);
+		assert_eq!(read_header(buf.as_slice(), ArtifactKind::ArithCircuitPoly, 2).unwrap(), 2);
+		assert_eq!(read_header(buf.as_slice(), ArtifactKind::ArithCircuitPoly, 5).unwrap(), 2);
+
+		let mut bad_magic = buf.clone();
+		bad_magic[0] = b'X';
+		assert!(matches!(
+			read_header(bad_magic.as_slice(), ArtifactKind::ArithCircuitPoly, 2),
+			Err(Error::BadMagic)
+		));
+
+		let mut future = Vec::new();
+		write_header(&mut future, ArtifactKind::ArithCircuitPoly, 3).unwrap();
+		assert!(matches!(
+			read_header(future.as_slice(), ArtifactKind::ArithCircuitPoly, 2),
+			Err(Error::UnsupportedVersion { found: 3 })
+		));
+
+		assert!(matches!(
+			read_header(buf.as_slice(), ArtifactKind::Witness, 2),
+			Err(Error::UnexpectedArtifactKind {
+				expected: ArtifactKind::Witness,
+				found: ArtifactKind::ArithCircuitPoly,
+			})
+		));
+		let mut unknown_kind = buf.clone();
+		unknown_kind[4] = 9;
+		assert!(matches!(
+			read_header(unknown_kind.as_slice(), ArtifactKind::Witness, 2),
+			Err(Error::UnknownEnumVariant { index: 9, .. })
+		));
+		assert!(matches!(
+			read_header(&buf[..6], ArtifactKind::ArithCircuitPoly, 2),
+			Err(Error::NotEnoughBytes)
+		));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_io_adapters() {
+		let values = (0..3 * IO_BUFFER_LEN)
+			.map(|i| vec![i as u8; i % 5])
+			.collect::<Vec<_>>();
+
+		let mut writer = IoWriter::new(std::io::Cursor::new(Vec::new()));
+		values.serialize(&mut writer).unwrap();
+		let bytes = writer.finish().unwrap().into_inner();
+
+		let mut reader = IoReader::new(std::io::Cursor::new(bytes.clone()));
+		assert_eq!(Vec::<Vec<u8>>::deserialize(&mut reader).unwrap(), values);
+		assert_eq!(reader.remaining(), 0);
+		reader.finish().unwrap();
+
+		let truncated = IoReader::new(&bytes[..bytes.len() - 1]);
+		assert!(matches!(Vec::<Vec<u8>>::deserialize(truncated), Err(Error::NotEnoughBytes)));
+	}
+
+	#[cfg(feature = "std")]
+	#[test]
+	fn test_io_reader_short_reads() {
+		/// Returns at most `1 + n_reads % 7` bytes per read.
+		struct ShortReads<'a> {
+			bytes: &'a [u8],
+			n_reads: usize,
+		}
+
+		impl std::io::Read for ShortReads<'_> {
+			fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+				self.n_reads += 1;
+				let len = buf.len().min(1 + self.n_reads % 7).min(self.bytes.len());
+				buf[..len].copy_from_slice(&self.bytes[..len]);
+				self.bytes = &self.bytes[len..];
+				Ok(len)
+			}
+		}
+
+		let bytes = (0..3 * IO_BUFFER_LEN + 5)
+			.map(|i| i as u8)
+			.collect::<Vec<_>>();
+		let mut reader = IoReader::new(ShortReads {
+			bytes: &bytes,
+			n_reads: 0,
+		});
+
+		// The remaining bytes are the buffered ones, which stay above half of the buffer size
+		// until the end of the stream, and then count down to zero.
+		assert_eq!(reader.remaining(), IO_BUFFER_LEN);
+		let mut read = Vec::new();
+		while reader.has_remaining() {
+			let left = bytes.len() - read.len();
+			assert!(reader.remaining() <= left);
+			assert!(reader.remaining() >= left.min(IO_BUFFER_LEN / 2));
+
+			let chunk = reader.chunk();
+			let len = chunk.len().min(1000);
+			read.extend_from_slice(&chunk[..len]);
+			reader.advance(len);
+		}
+		assert_eq!(read, bytes);
+		reader.finish().unwrap();
+
+		let mut reader = IoReader::new(ShortReads {
+			bytes: &bytes,
+			n_reads: 0,
+		});
+		assert!(matches!(
+			<[u8; 3 * IO_BUFFER_LEN + 6]>::deserialize(&mut reader),
+			Err(Error::NotEnoughBytes)
+		));
+	}
 }
@@ -0,0 +1,162 @@
+// Copyright 2024-2025 Irreducible Inc.
+
+use anyhow::Result;
+use binius_circuits::{arithmetic, builder::ConstraintSystemBuilder};
+use binius_core::{
+	constraint_system::{
+		self,
+		channel::{Boundary, FlushDirection},
+	},
+	fiat_shamir::HasherChallenger,
+	tower::CanonicalTowerFamily,
+};
+use binius_field::{
+	arch::OptimalUnderlier, as_packed_field::PackedType, BinaryField128b, BinaryField1b,
+	BinaryField32b,
+};
+use binius_hal::make_portable_backend;
+use binius_hash::compress::Groestl256ByteCompression;
+use binius_macros::define_table;
+use binius_math::DefaultEvaluationDomainFactory;
+use binius_utils::{checked_arithmetics::log2_ceil_usize, rayon::adjust_thread_pool};
+use bytesize::ByteSize;
+use clap::{value_parser, Parser};
+use groestl_crypto::Groestl256;
+use tracing_profile::init_tracing;
+
+#[derive(Debug, Parser)]
+struct Args {
+	/// The number whose triangular number is computed.
+	#[arg(short, long, default_value_t = 1000, value_parser = value_parser!(u32).range(1..65536))]
+	n: u32,
+	/// The negative binary logarithm of the Reed–Solomon code rate.
+	#[arg(long, default_value_t = 1, value_parser = value_parser!(u32).range(1..))]
+	log_inv_rate: u32,
+}
+
+type U = OptimalUnderlier;
+type F = BinaryField128b;
+
+const SECURITY_BITS: usize = 100;
+
+// Each row holds a state (x, s), where s is the sum of 1..=x. The row pulls its state from the
+// channel and pushes the state (x - 1, s - x) of the next row.
+define_table! {
+	crate = binius_circuits;
+	struct SumN {
+		x: BinaryField1b[log_1b_rows],
+		s: BinaryField1b[log_1b_rows],
+	}
+}
+
+fn main() -> Result<()> {
+	adjust_thread_pool()
+		.as_ref()
+		.expect("failed to init thread pool");
+
+	let args = Args::parse();
+
+	let _guard = init_tracing().expect("failed to initialize tracing");
+
+	println!("Verifying the sum of the integers from 1 to {}", args.n);
+
+	let n = args.n;
+	let count = n as usize;
+	// The committed columns must fill at least one packed field element.
+	let log_32b_rows = log2_ceil_usize(count).max(PackedType::<U, BinaryField1b>::LOG_WIDTH - 5);
+
+	let allocator = bumpalo::Bump::new();
+	let mut builder = ConstraintSystemBuilder::<U, F>::new_with_witness(&allocator);
+
+	let trace_gen_scope = tracing::info_span!("generating trace").entered();
+	let table = SumN::new(&mut builder, log_32b_rows + 5);
+	if let Some(witness) = builder.witness() {
+		let mut columns = table.columns(witness);
+		let xs = &mut columns.x.as_mut_slice::<u32>()[..count];
+		let ss = &mut columns.s.as_mut_slice::<u32>()[..count];
+		let mut s = n * (n + 1) / 2;
+		for (x, (x_row, s_row)) in (1..=n).rev().zip(xs.iter_mut().zip(ss.iter_mut())) {
+			(*x_row, *s_row) = (x, s);
+			s -= x;
+		}
+	}
+
+	// The padding rows past `count` are not flushed, so they may underflow.
+	let one = arithmetic::u32::constant(&mut builder, "one", log_32b_rows, 1)?;
+	let x_next =
+		arithmetic::u32::sub(&mut builder, "x_next", table.x, one, arithmetic::Flags::Unchecked)?;
+	let s_next = arithmetic::u32::sub(
+		&mut builder,
+		"s_next",
+		table.s,
+		table.x,
+		arithmetic::Flags::Unchecked,
+	)?;
+
+	let x_packed = arithmetic::u32::packed(&mut builder, "x_packed", table.x)?;
+	let s_packed = arithmetic::u32::packed(&mut builder, "s_packed", table.s)?;
+	let x_next_packed = arithmetic::u32::packed(&mut builder, "x_next_packed", x_next)?;
+	let s_next_packed = arithmetic::u32::packed(&mut builder, "s_next_packed", s_next)?;
+
+	let channel = builder.add_channel();
+	builder.receive(channel, count, [x_packed, s_packed]);
+	builder.send(channel, count, [x_next_packed, s_next_packed]);
+	drop(trace_gen_scope);
+
+	let boundaries = vec![
+		Boundary {
+			channel_id: channel,
+			direction: FlushDirection::Push,
+			values: vec![
+				BinaryField32b::new(n).into(),
+				BinaryField32b::new(n * (n + 1) / 2).into(),
+			],
+			multiplicity: 1,
+		},
+		Boundary {
+			channel_id: channel,
+			direction: FlushDirection::Pull,
+			values: vec![BinaryField32b::new(0).into(), BinaryField32b::new(0).into()],
+			multiplicity: 1,
+		},
+	];
+
+	let witness = builder
+		.take_witness()
+		.expect("builder created with witness");
+	let constraint_system = builder.build()?;
+
+	constraint_system::validate::validate_witness(&constraint_system, &boundaries, &witness)?;
+
+	let domain_factory = DefaultEvaluationDomainFactory::default();
+	let backend = make_portable_backend();
+
+	let proof = constraint_system::prove::<
+		U,
+		CanonicalTowerFamily,
+		_,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+		_,
+	>(
+		&constraint_system,
+		args.log_inv_rate as usize,
+		SECURITY_BITS,
+		witness,
+		&domain_factory,
+		&backend,
+	)?;
+
+	println!("Proof size: {}", ByteSize::b(proof.get_proof_size() as u64));
+
+	constraint_system::verify::<
+		U,
+		CanonicalTowerFamily,
+		Groestl256,
+		Groestl256ByteCompression,
+		HasherChallenger<Groestl256>,
+	>(&constraint_system, args.log_inv_rate as usize, SECURITY_BITS, boundaries, proof)?;
+
+	Ok(())
+}